@@ -0,0 +1,408 @@
+//! Command-line access to ADLX, for inspecting GPUs and displays and changing 3D settings without
+//! writing code.
+//!
+//! Every command prints human-readable text by default, and JSON with `--json`. Streamed metrics
+//! are printed as one JSON object per line.
+
+use std::{path::PathBuf, thread, time::Duration};
+
+use adlx::{
+    helper::AdlxHelper, session::Session, Gpu, GpuInfo, GpuMetricsSample, System, ThreeDChill,
+    ThreeDFrameRateTargetControl, ThreeDWaitForVerticalRefresh, WaitForVerticalRefreshMode,
+};
+use anyhow::{Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Cli {
+    /// Print JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Serve a session recorded with `adlx::session::Recorder` instead of loading ADLX
+    #[arg(long, global = true, value_name = "FILE")]
+    replay: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List all GPUs
+    Gpus,
+    /// List all displays and the GPU they are connected to
+    Displays,
+    /// Dump all `IADLXGPU`, `IADLXGPU1` and `IADLXGPU2` properties of a GPU
+    Info(GpuArg),
+    /// Report which interfaces and features the installed driver supports
+    Capabilities,
+    /// Stream the current metrics of a GPU
+    Metrics {
+        #[command(flatten)]
+        gpu: GpuArg,
+        /// Time between samples in milliseconds
+        #[arg(long, default_value_t = 1000)]
+        interval_ms: u64,
+        /// Stop after this many samples instead of running until interrupted
+        #[arg(long)]
+        count: Option<usize>,
+    },
+    /// Get or set the VSync (wait for vertical refresh) mode
+    Vsync {
+        #[command(flatten)]
+        gpu: GpuArg,
+        /// Set the mode before printing it
+        #[arg(long, value_enum)]
+        set: Option<VsyncMode>,
+    },
+    /// Get or set Radeon Chill
+    Chill {
+        #[command(flatten)]
+        gpu: GpuArg,
+        #[command(flatten)]
+        toggle: Toggle,
+        #[arg(long)]
+        min_fps: Option<i32>,
+        #[arg(long)]
+        max_fps: Option<i32>,
+    },
+    /// Get or set Frame Rate Target Control
+    Frtc {
+        #[command(flatten)]
+        gpu: GpuArg,
+        #[command(flatten)]
+        toggle: Toggle,
+        #[arg(long)]
+        fps: Option<i32>,
+    },
+}
+
+#[derive(Args, Debug)]
+struct GpuArg {
+    /// Index of the GPU in the `gpus` list
+    #[arg(long, default_value_t = 0)]
+    gpu: u32,
+}
+
+#[derive(Args, Debug)]
+#[group(multiple = false)]
+struct Toggle {
+    #[arg(long)]
+    enable: bool,
+    #[arg(long)]
+    disable: bool,
+}
+
+impl Toggle {
+    fn value(&self) -> Option<bool> {
+        match (self.enable, self.disable) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum VsyncMode {
+    AlwaysOff,
+    OffUnlessAppSpecifies,
+    OnUnlessAppSpecifies,
+    AlwaysOn,
+}
+
+impl From<VsyncMode> for WaitForVerticalRefreshMode {
+    fn from(mode: VsyncMode) -> Self {
+        match mode {
+            VsyncMode::AlwaysOff => Self::AlwaysOff,
+            VsyncMode::OffUnlessAppSpecifies => Self::OffUnlessAppSpecifies,
+            VsyncMode::OnUnlessAppSpecifies => Self::OnUnlessAppSpecifies,
+            VsyncMode::AlwaysOn => Self::AlwaysOn,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct GpuEntry {
+    index: u32,
+    #[serde(flatten)]
+    info: GpuInfo,
+}
+
+#[derive(Serialize)]
+struct DisplayEntry {
+    index: u32,
+    gpu_unique_id: Option<i32>,
+    gpu_name: Option<String>,
+    free_sync_supported: Option<bool>,
+    free_sync_enabled: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct VsyncState {
+    supported: bool,
+    enabled: Option<bool>,
+    mode: Option<WaitForVerticalRefreshMode>,
+}
+
+impl VsyncState {
+    fn new(vsync: &ThreeDWaitForVerticalRefresh) -> Result<Self> {
+        Ok(Self {
+            supported: vsync.is_supported()?,
+            enabled: vsync.is_enabled().ok(),
+            mode: vsync.get_mode().ok(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct ChillState {
+    supported: bool,
+    enabled: Option<bool>,
+    min_fps: Option<i32>,
+    max_fps: Option<i32>,
+    fps_range: Option<[i32; 2]>,
+}
+
+impl ChillState {
+    fn new(chill: &ThreeDChill) -> Result<Self> {
+        Ok(Self {
+            supported: chill.is_supported()?,
+            enabled: chill.is_enabled().ok(),
+            min_fps: chill.get_min_fps().ok(),
+            max_fps: chill.get_max_fps().ok(),
+            fps_range: chill.get_fps_range().ok().map(|r| [r.minValue, r.maxValue]),
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct FrtcState {
+    supported: bool,
+    enabled: Option<bool>,
+    fps: Option<i32>,
+    fps_range: Option<[i32; 2]>,
+}
+
+impl FrtcState {
+    fn new(frtc: &ThreeDFrameRateTargetControl) -> Result<Self> {
+        Ok(Self {
+            supported: frtc.is_supported()?,
+            enabled: frtc.is_enabled().ok(),
+            fps: frtc.get_fps().ok(),
+            fps_range: frtc.get_fps_range().ok().map(|r| [r.minValue, r.maxValue]),
+        })
+    }
+}
+
+fn gpu(system: &System, arg: &GpuArg) -> Result<Gpu> {
+    let gpus = system.gpus()?;
+    anyhow::ensure!(
+        arg.gpu < gpus.size(),
+        "There is no GPU {}, found {} GPUs",
+        arg.gpu,
+        gpus.size()
+    );
+    Ok(gpus.at(arg.gpu)?)
+}
+
+/// Prints `value` as pretty JSON, or as indented `key: value` lines.
+fn print(json: bool, value: &impl Serialize) -> Result<()> {
+    let value = serde_json::to_value(value)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&value)?);
+    } else {
+        let mut text = String::new();
+        write_text(&mut text, &value, 0);
+        print!("{text}");
+    }
+    Ok(())
+}
+
+fn write_text(out: &mut String, value: &Value, depth: usize) {
+    let indent = "  ".repeat(depth);
+    match value {
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 && depth == 0 {
+                    out.push('\n');
+                }
+                match item {
+                    Value::Object(_) | Value::Array(_) => write_text(out, item, depth),
+                    _ => out.push_str(&format!("{indent}{}\n", scalar(item))),
+                }
+            }
+        }
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                match field {
+                    Value::Object(_) => {
+                        out.push_str(&format!("{indent}{key}:\n"));
+                        write_text(out, field, depth + 1);
+                    }
+                    _ => out.push_str(&format!("{indent}{key}: {}\n", scalar(field))),
+                }
+            }
+        }
+        _ => out.push_str(&format!("{indent}{}\n", scalar(value))),
+    }
+}
+
+/// Renders a value on a single line, without quotes around strings.
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_owned(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter().map(scalar).collect::<Vec<_>>().join(", "),
+        Value::Object(fields) => fields
+            .iter()
+            .map(|(key, field)| format!("{key}={}", scalar(field)))
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => value.to_string(),
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let helper = match &cli.replay {
+        Some(path) => AdlxHelper::replay(Session::load(path)?),
+        None => AdlxHelper::new().context("Failed to initialize ADLX")?,
+    };
+    let system = helper.system();
+
+    match &cli.command {
+        Command::Gpus => {
+            let gpus = system
+                .gpus()?
+                .try_iter()
+                .zip(0..)
+                .map(|(gpu, index)| {
+                    Ok(GpuEntry {
+                        index,
+                        info: gpu?.info()?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            print(cli.json, &gpus)?;
+        }
+        Command::Displays => {
+            let services = system.get_displays_services()?;
+            let displays = services
+                .get_displays()?
+                .try_iter()
+                .zip(0..)
+                .map(|(display, index)| {
+                    let display = display?;
+                    let gpu = display.get_gpu().ok();
+                    let free_sync = services.get_free_sync(&display).ok();
+                    Ok(DisplayEntry {
+                        index,
+                        gpu_unique_id: gpu.as_ref().and_then(|g| g.unique_id().ok()),
+                        gpu_name: gpu.as_ref().and_then(|g| g.name().ok()).map(str::to_owned),
+                        free_sync_supported: free_sync.as_ref().and_then(|f| f.is_supported().ok()),
+                        free_sync_enabled: free_sync.as_ref().and_then(|f| f.is_enabled().ok()),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            print(cli.json, &displays)?;
+        }
+        Command::Info(arg) => print(cli.json, &gpu(system, arg)?.info()?)?,
+        Command::Capabilities => print(cli.json, &system.capabilities())?,
+        Command::Metrics {
+            gpu: arg,
+            interval_ms,
+            count,
+        } => {
+            let gpu = gpu(system, arg)?;
+            let services = system.performance_monitoring_services()?;
+            for i in 0..count.unwrap_or(usize::MAX) {
+                if i > 0 {
+                    thread::sleep(Duration::from_millis(*interval_ms));
+                }
+                let sample: GpuMetricsSample = services.current_gpu_metrics(&gpu)?.sample()?;
+                let value = serde_json::to_value(sample)?;
+                if cli.json {
+                    println!("{value}");
+                } else {
+                    println!("{}", scalar(&value));
+                }
+            }
+        }
+        Command::Vsync { gpu: arg, set } => {
+            let services = system.get_3d_settings_services()?;
+            let mut vsync = services.get_wait_for_vertical_refresh(&gpu(system, arg)?)?;
+            if let Some(mode) = set {
+                vsync.set_mode((*mode).into())?;
+            }
+            print(cli.json, &VsyncState::new(&vsync)?)?;
+        }
+        Command::Chill {
+            gpu: arg,
+            toggle,
+            min_fps,
+            max_fps,
+        } => {
+            let services = system.get_3d_settings_services()?;
+            let mut chill = services.get_chill(&gpu(system, arg)?)?;
+            if let Some(enable) = toggle.value() {
+                chill.set_enabled(enable)?;
+            }
+            if let Some(min_fps) = min_fps {
+                chill.set_min_fps(*min_fps)?;
+            }
+            if let Some(max_fps) = max_fps {
+                chill.set_max_fps(*max_fps)?;
+            }
+            print(cli.json, &ChillState::new(&chill)?)?;
+        }
+        Command::Frtc {
+            gpu: arg,
+            toggle,
+            fps,
+        } => {
+            let services = system.get_3d_settings_services()?;
+            let mut frtc = services.get_frame_rate_target_control(&gpu(system, arg)?)?;
+            if let Some(enable) = toggle.value() {
+                frtc.set_enabled(enable)?;
+            }
+            if let Some(fps) = fps {
+                frtc.set_fps(*fps)?;
+            }
+            print(cli.json, &FrtcState::new(&frtc)?)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::{scalar, write_text, Cli};
+
+    #[test]
+    fn verifies_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn renders_text() {
+        let value = serde_json::json!({
+            "name": "AMD Radeon RX 7900 XTX",
+            "luid": { "low_part": 4660, "high_part": 0 },
+            "product_name": null,
+        });
+        let mut text = String::new();
+        write_text(&mut text, &value, 0);
+        assert_eq!(
+            text,
+            "name: AMD Radeon RX 7900 XTX\nluid:\n  low_part: 4660\n  high_part: 0\nproduct_name: -\n"
+        );
+        assert_eq!(scalar(&value["luid"]), "low_part=4660 high_part=0");
+    }
+}
@@ -0,0 +1,230 @@
+//! Dashboard state, independent of the terminal so that it can be driven by tests.
+
+use std::collections::VecDeque;
+
+use adlx::GpuMetricsSample;
+use anyhow::Result;
+
+use crate::source::{GpuDescriptor, IntervalRange, MetricsSource};
+
+/// Samples kept per GPU for the sparklines
+pub const HISTORY_LEN: usize = 256;
+
+/// Change of the sampling interval per key press, in milliseconds
+const INTERVAL_STEP_MS: i32 = 100;
+
+/// A key press that the dashboard reacts to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    NextGpu,
+    PreviousGpu,
+    IncreaseInterval,
+    DecreaseInterval,
+}
+
+pub struct App {
+    pub gpus: Vec<GpuDescriptor>,
+    /// Most recent samples per GPU, oldest first
+    pub history: Vec<VecDeque<GpuMetricsSample>>,
+    pub selected: usize,
+    pub interval_ms: i32,
+    pub interval_range: Option<IntervalRange>,
+    /// The last error, shown in the footer until the next successful update
+    pub status: Option<String>,
+    pub quit: bool,
+}
+
+impl App {
+    pub fn new(source: &impl MetricsSource) -> Result<Self> {
+        let gpus = source.gpus().to_vec();
+        Ok(Self {
+            history: vec![VecDeque::with_capacity(HISTORY_LEN); gpus.len()],
+            gpus,
+            selected: 0,
+            interval_ms: source.sampling_interval()?,
+            interval_range: source.sampling_interval_range().ok(),
+            status: None,
+            quit: false,
+        })
+    }
+
+    /// Appends the current sample of every GPU to its history.
+    pub fn tick(&mut self, source: &mut impl MetricsSource) {
+        self.status = None;
+        for (index, history) in self.history.iter_mut().enumerate() {
+            match source.sample(index) {
+                Ok(sample) => {
+                    if history.len() == HISTORY_LEN {
+                        history.pop_front();
+                    }
+                    history.push_back(sample);
+                }
+                Err(e) => self.status = Some(format!("{}: {e:#}", self.gpus[index].name)),
+            }
+        }
+    }
+
+    pub fn handle(&mut self, action: Action, source: &mut impl MetricsSource) {
+        let gpu_count = self.gpus.len().max(1);
+        match action {
+            Action::Quit => self.quit = true,
+            Action::NextGpu => self.selected = (self.selected + 1) % gpu_count,
+            Action::PreviousGpu => self.selected = (self.selected + gpu_count - 1) % gpu_count,
+            Action::IncreaseInterval => {
+                self.set_interval(self.interval_ms + INTERVAL_STEP_MS, source)
+            }
+            Action::DecreaseInterval => {
+                self.set_interval(self.interval_ms - INTERVAL_STEP_MS, source)
+            }
+        }
+    }
+
+    fn set_interval(&mut self, interval_ms: i32, source: &mut impl MetricsSource) {
+        let interval_ms = match self.interval_range {
+            Some(range) => {
+                let step = range.step.max(1);
+                let snapped = range.min + (interval_ms - range.min) / step * step;
+                snapped.clamp(range.min, range.max)
+            }
+            None => interval_ms.max(INTERVAL_STEP_MS),
+        };
+        if interval_ms == self.interval_ms {
+            return;
+        }
+        match source.set_sampling_interval(interval_ms) {
+            Ok(()) => {
+                self.interval_ms = interval_ms;
+                self.status = None;
+            }
+            Err(e) => self.status = Some(format!("Failed to set the sampling interval: {e:#}")),
+        }
+    }
+
+    pub fn selected_gpu(&self) -> Option<(&GpuDescriptor, &VecDeque<GpuMetricsSample>)> {
+        Some((self.gpus.get(self.selected)?, &self.history[self.selected]))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::ops::RangeInclusive;
+
+    use adlx::GpuMetricsSample;
+    use anyhow::{bail, Result};
+
+    use super::{Action, App};
+    use crate::source::{GpuDescriptor, IntervalRange, Metric, MetricsSource, SupportedMetric};
+
+    /// Serves increasing usage and temperatures for two GPUs, of which the second has no fan
+    /// speed and intake temperature sensors.
+    pub(crate) struct FakeSource {
+        gpus: Vec<GpuDescriptor>,
+        pub(crate) interval_ms: i32,
+        pub(crate) ticks: i64,
+    }
+
+    impl FakeSource {
+        pub(crate) fn new() -> Self {
+            let metric = |metric, range: RangeInclusive<i32>| SupportedMetric {
+                metric,
+                range: Some(range),
+            };
+            let dgpu = vec![
+                metric(Metric::Usage, 0..=100),
+                metric(Metric::ClockSpeed, 500..=2500),
+                metric(Metric::Temperature, 0..=100),
+                metric(Metric::IntakeTemperature, 0..=100),
+                metric(Metric::FanSpeed, 0..=3300),
+            ];
+            let igpu = vec![
+                metric(Metric::Usage, 0..=100),
+                metric(Metric::Temperature, 0..=100),
+            ];
+            Self {
+                gpus: vec![
+                    GpuDescriptor {
+                        name: "AMD Radeon RX 7900 XTX".to_owned(),
+                        metrics: dgpu,
+                    },
+                    GpuDescriptor {
+                        name: "AMD Radeon(TM) Graphics".to_owned(),
+                        metrics: igpu,
+                    },
+                ],
+                interval_ms: 500,
+                ticks: 0,
+            }
+        }
+    }
+
+    impl MetricsSource for FakeSource {
+        fn gpus(&self) -> &[GpuDescriptor] {
+            &self.gpus
+        }
+
+        fn sample(&mut self, index: usize) -> Result<GpuMetricsSample> {
+            self.ticks += 1;
+            let t = self.ticks as f64;
+            Ok(GpuMetricsSample {
+                time_stamp: self.ticks * i64::from(self.interval_ms),
+                usage: Some((t * 10.0) % 100.0),
+                clock_speed: (index == 0).then_some(2000),
+                temperature: Some(40.0 + t),
+                intake_temperature: (index == 0).then_some(30.0),
+                fan_speed: (index == 0).then_some(1650),
+                ..Default::default()
+            })
+        }
+
+        fn sampling_interval(&self) -> Result<i32> {
+            Ok(self.interval_ms)
+        }
+
+        fn sampling_interval_range(&self) -> Result<IntervalRange> {
+            Ok(IntervalRange {
+                min: 100,
+                max: 1000,
+                step: 100,
+            })
+        }
+
+        fn set_sampling_interval(&mut self, interval_ms: i32) -> Result<()> {
+            if !(100..=1000).contains(&interval_ms) {
+                bail!("Interval {interval_ms} out of range");
+            }
+            self.interval_ms = interval_ms;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn changes_interval_within_range() {
+        let mut source = FakeSource::new();
+        let mut app = App::new(&source).unwrap();
+
+        for _ in 0..10 {
+            app.handle(Action::IncreaseInterval, &mut source);
+        }
+        assert_eq!((app.interval_ms, source.interval_ms), (1000, 1000));
+
+        app.handle(Action::DecreaseInterval, &mut source);
+        assert_eq!((app.interval_ms, source.interval_ms), (900, 900));
+        assert_eq!(app.status, None);
+    }
+
+    #[test]
+    fn keeps_bounded_history() {
+        let mut source = FakeSource::new();
+        let mut app = App::new(&source).unwrap();
+        for _ in 0..super::HISTORY_LEN + 10 {
+            app.tick(&mut source);
+        }
+        assert!(app.history.iter().all(|h| h.len() == super::HISTORY_LEN));
+
+        app.handle(Action::PreviousGpu, &mut source);
+        assert_eq!(app.selected, 1);
+        app.handle(Action::NextGpu, &mut source);
+        assert_eq!(app.selected, 0);
+    }
+}
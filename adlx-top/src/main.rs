@@ -0,0 +1,89 @@
+//! A terminal dashboard that shows live gauges and sparklines of every metric that
+//! `PerformanceMonitoringServices` supports on each GPU, similar to `radeontop`.
+
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use adlx::{helper::AdlxHelper, session::Session};
+use anyhow::{Context, Result};
+use clap::Parser;
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    DefaultTerminal,
+};
+
+mod app;
+mod source;
+mod ui;
+
+use app::{Action, App};
+use source::{AdlxSource, MetricsSource};
+
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Cli {
+    /// Sampling interval in milliseconds, the current ADLX setting by default
+    #[arg(long)]
+    interval_ms: Option<i32>,
+
+    /// Serve a session recorded with `adlx::session::Recorder` instead of loading ADLX
+    #[arg(long, value_name = "FILE")]
+    replay: Option<PathBuf>,
+}
+
+fn action(key: KeyCode) -> Option<Action> {
+    Some(match key {
+        KeyCode::Char('q') | KeyCode::Esc => Action::Quit,
+        KeyCode::Tab | KeyCode::Right => Action::NextGpu,
+        KeyCode::BackTab | KeyCode::Left => Action::PreviousGpu,
+        KeyCode::Char('+' | '=') => Action::IncreaseInterval,
+        KeyCode::Char('-') => Action::DecreaseInterval,
+        _ => return None,
+    })
+}
+
+fn run(terminal: &mut DefaultTerminal, source: &mut impl MetricsSource) -> Result<()> {
+    let mut app = App::new(source)?;
+    app.tick(source);
+    let mut last_tick = Instant::now();
+
+    while !app.quit {
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+
+        let interval = Duration::from_millis(app.interval_ms.max(1) as u64);
+        let timeout = interval.saturating_sub(last_tick.elapsed());
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    if let Some(action) = action(key.code) {
+                        app.handle(action, source);
+                    }
+                }
+            }
+        }
+        if last_tick.elapsed() >= interval {
+            app.tick(source);
+            last_tick = Instant::now();
+        }
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let helper = match &cli.replay {
+        Some(path) => AdlxHelper::replay(Session::load(path)?),
+        None => AdlxHelper::new().context("Failed to initialize ADLX")?,
+    };
+    let mut source = AdlxSource::new(helper.system())?;
+    if let Some(interval_ms) = cli.interval_ms {
+        source.set_sampling_interval(interval_ms)?;
+    }
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, &mut source);
+    ratatui::restore();
+    result
+}
@@ -0,0 +1,243 @@
+//! Where the dashboard gets its samples from: [`AdlxSource`] on a real system, or a fake source in
+//! tests.
+
+use std::ops::RangeInclusive;
+
+use adlx::{Gpu, GpuMetricsSample, GpuMetricsSupport, PerformanceMonitoringServices, System};
+use anyhow::Result;
+
+/// A metric shown as a gauge and sparkline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Metric {
+    Usage,
+    ClockSpeed,
+    VramClockSpeed,
+    Power,
+    TotalBoardPower,
+    Temperature,
+    HotspotTemperature,
+    IntakeTemperature,
+    FanSpeed,
+    Vram,
+    Voltage,
+}
+
+type IsSupported = fn(&GpuMetricsSupport) -> adlx::Result<bool>;
+type Range = fn(&GpuMetricsSupport) -> adlx::Result<RangeInclusive<i32>>;
+
+impl Metric {
+    pub const ALL: [Self; 11] = [
+        Self::Usage,
+        Self::ClockSpeed,
+        Self::VramClockSpeed,
+        Self::Power,
+        Self::TotalBoardPower,
+        Self::Temperature,
+        Self::HotspotTemperature,
+        Self::IntakeTemperature,
+        Self::FanSpeed,
+        Self::Vram,
+        Self::Voltage,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Usage => "Usage",
+            Self::ClockSpeed => "GPU clock",
+            Self::VramClockSpeed => "VRAM clock",
+            Self::Power => "GPU power",
+            Self::TotalBoardPower => "Board power",
+            Self::Temperature => "Edge temp",
+            Self::HotspotTemperature => "Hotspot temp",
+            Self::IntakeTemperature => "Intake temp",
+            Self::FanSpeed => "Fan speed",
+            Self::Vram => "VRAM",
+            Self::Voltage => "Voltage",
+        }
+    }
+
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::Usage => "%",
+            Self::ClockSpeed | Self::VramClockSpeed => "MHz",
+            Self::Power | Self::TotalBoardPower => "W",
+            Self::Temperature | Self::HotspotTemperature | Self::IntakeTemperature => "°C",
+            Self::FanSpeed => "RPM",
+            Self::Vram => "MB",
+            Self::Voltage => "mV",
+        }
+    }
+
+    pub fn value(self, sample: &GpuMetricsSample) -> Option<f64> {
+        match self {
+            Self::Usage => sample.usage,
+            Self::ClockSpeed => sample.clock_speed.map(f64::from),
+            Self::VramClockSpeed => sample.vram_clock_speed.map(f64::from),
+            Self::Power => sample.power,
+            Self::TotalBoardPower => sample.total_board_power,
+            Self::Temperature => sample.temperature,
+            Self::HotspotTemperature => sample.hotspot_temperature,
+            Self::IntakeTemperature => sample.intake_temperature,
+            Self::FanSpeed => sample.fan_speed.map(f64::from),
+            Self::Vram => sample.vram.map(f64::from),
+            Self::Voltage => sample.voltage.map(f64::from),
+        }
+    }
+
+    fn support(self) -> (IsSupported, Range) {
+        match self {
+            Self::Usage => (
+                GpuMetricsSupport::is_supported_gpu_usage,
+                GpuMetricsSupport::gpu_usage_range,
+            ),
+            Self::ClockSpeed => (
+                GpuMetricsSupport::is_supported_gpu_clock_speed,
+                GpuMetricsSupport::gpu_clock_speed_range,
+            ),
+            Self::VramClockSpeed => (
+                GpuMetricsSupport::is_supported_gpu_vram_clock_speed,
+                GpuMetricsSupport::gpu_vram_clock_speed_range,
+            ),
+            Self::Power => (
+                GpuMetricsSupport::is_supported_gpu_power,
+                GpuMetricsSupport::gpu_power_range,
+            ),
+            Self::TotalBoardPower => (
+                GpuMetricsSupport::is_supported_gpu_total_board_power,
+                GpuMetricsSupport::gpu_total_board_power_range,
+            ),
+            Self::Temperature => (
+                GpuMetricsSupport::is_supported_gpu_temperature,
+                GpuMetricsSupport::gpu_temperature_range,
+            ),
+            Self::HotspotTemperature => (
+                GpuMetricsSupport::is_supported_gpu_hotspot_temperature,
+                GpuMetricsSupport::gpu_hotspot_temperature_range,
+            ),
+            Self::IntakeTemperature => (
+                GpuMetricsSupport::is_supported_gpu_intake_temperature,
+                GpuMetricsSupport::gpu_intake_temperature_range,
+            ),
+            Self::FanSpeed => (
+                GpuMetricsSupport::is_supported_gpu_fan_speed,
+                GpuMetricsSupport::gpu_fan_speed_range,
+            ),
+            Self::Vram => (
+                GpuMetricsSupport::is_supported_gpu_vram,
+                GpuMetricsSupport::gpu_vran_range,
+            ),
+            Self::Voltage => (
+                GpuMetricsSupport::is_supported_gpu_voltage,
+                GpuMetricsSupport::gpu_voltage_range,
+            ),
+        }
+    }
+}
+
+/// A metric that the GPU supports, with the range of its gauge if ADLX reports one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupportedMetric {
+    pub metric: Metric,
+    pub range: Option<RangeInclusive<i32>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GpuDescriptor {
+    pub name: String,
+    /// Only the metrics that [`GpuMetricsSupport`] reports as supported, in [`Metric::ALL`] order
+    pub metrics: Vec<SupportedMetric>,
+}
+
+impl GpuDescriptor {
+    pub fn new(name: String, support: &GpuMetricsSupport) -> Self {
+        let metrics = Metric::ALL
+            .into_iter()
+            .filter_map(|metric| {
+                let (is_supported, range) = metric.support();
+                is_supported(support).ok()?.then(|| SupportedMetric {
+                    metric,
+                    range: range(support).ok(),
+                })
+            })
+            .collect();
+        Self { name, metrics }
+    }
+}
+
+/// Sampling interval bounds in milliseconds, from
+/// [`PerformanceMonitoringServices::sampling_interval_range()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntervalRange {
+    pub min: i32,
+    pub max: i32,
+    pub step: i32,
+}
+
+/// Provides the GPUs, their samples and the sampling interval to the dashboard.
+pub trait MetricsSource {
+    fn gpus(&self) -> &[GpuDescriptor];
+    /// The current metrics of the GPU at `index` in [`Self::gpus()`]
+    fn sample(&mut self, index: usize) -> Result<GpuMetricsSample>;
+    fn sampling_interval(&self) -> Result<i32>;
+    fn sampling_interval_range(&self) -> Result<IntervalRange>;
+    fn set_sampling_interval(&mut self, interval_ms: i32) -> Result<()>;
+}
+
+/// Reads metrics through [`PerformanceMonitoringServices`].
+pub struct AdlxSource {
+    services: PerformanceMonitoringServices,
+    gpus: Vec<Gpu>,
+    descriptors: Vec<GpuDescriptor>,
+}
+
+impl AdlxSource {
+    pub fn new(system: &System) -> Result<Self> {
+        let services = system.performance_monitoring_services()?;
+        let gpus = system
+            .gpus()?
+            .try_iter()
+            .collect::<adlx::Result<Vec<_>>>()?;
+        let descriptors = gpus
+            .iter()
+            .map(|gpu| {
+                let support = services.supported_gpu_metrics(gpu)?;
+                Ok(GpuDescriptor::new(gpu.name()?.to_owned(), &support))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            services,
+            gpus,
+            descriptors,
+        })
+    }
+}
+
+impl MetricsSource for AdlxSource {
+    fn gpus(&self) -> &[GpuDescriptor] {
+        &self.descriptors
+    }
+
+    fn sample(&mut self, index: usize) -> Result<GpuMetricsSample> {
+        Ok(self
+            .services
+            .current_gpu_metrics(&self.gpus[index])?
+            .sample()?)
+    }
+
+    fn sampling_interval(&self) -> Result<i32> {
+        Ok(self.services.sampling_interval()?)
+    }
+
+    fn sampling_interval_range(&self) -> Result<IntervalRange> {
+        let range = self.services.sampling_interval_range()?;
+        Ok(IntervalRange {
+            min: range.minValue,
+            max: range.maxValue,
+            step: range.step,
+        })
+    }
+
+    fn set_sampling_interval(&mut self, interval_ms: i32) -> Result<()> {
+        Ok(self.services.set_sampling_interval(interval_ms)?)
+    }
+}
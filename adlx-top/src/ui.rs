@@ -0,0 +1,161 @@
+//! Rendering of [`App`] into a terminal frame.
+
+use std::collections::VecDeque;
+
+use adlx::GpuMetricsSample;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Gauge, Paragraph, Sparkline, Tabs},
+    Frame,
+};
+
+use crate::{app::App, source::SupportedMetric};
+
+/// Height of a single metric row: a gauge next to a sparkline, both with a border
+const ROW_HEIGHT: u16 = 3;
+
+pub fn draw(frame: &mut Frame<'_>, app: &App) {
+    let [tabs, body, footer] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let titles = app.gpus.iter().map(|gpu| gpu.name.as_str());
+    frame.render_widget(
+        Tabs::new(titles)
+            .select(app.selected)
+            .block(Block::default().borders(Borders::ALL).title(" GPUs "))
+            .highlight_style(Style::default().fg(Color::Red)),
+        tabs,
+    );
+
+    match app.selected_gpu() {
+        Some((gpu, history)) if !gpu.metrics.is_empty() => {
+            let rows = Layout::vertical(gpu.metrics.iter().map(|_| Constraint::Length(ROW_HEIGHT)))
+                .split(body);
+            for (metric, area) in gpu.metrics.iter().zip(rows.iter()) {
+                draw_metric(frame, *area, metric, history);
+            }
+        }
+        Some(_) => frame.render_widget(
+            Paragraph::new("This GPU does not support any metrics"),
+            body,
+        ),
+        None => frame.render_widget(Paragraph::new("No GPUs found"), body),
+    }
+
+    let status = match &app.status {
+        Some(status) => Line::styled(status.as_str(), Style::default().fg(Color::Red)),
+        None => Line::from(format!(
+            "Interval {} ms (+/-)  GPU (Tab/←/→)  Quit (q)",
+            app.interval_ms
+        )),
+    };
+    frame.render_widget(Paragraph::new(status), footer);
+}
+
+fn draw_metric(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    supported: &SupportedMetric,
+    history: &VecDeque<GpuMetricsSample>,
+) {
+    let metric = supported.metric;
+    let [gauge_area, sparkline_area] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(area);
+
+    let current = history.back().and_then(|s| metric.value(s));
+    let (min, max) = supported.range.as_ref().map_or_else(
+        || {
+            // Without a range, scale to the highest value seen so far
+            let max = history
+                .iter()
+                .filter_map(|s| metric.value(s))
+                .fold(0.0, f64::max);
+            (0.0, max)
+        },
+        |range| (f64::from(*range.start()), f64::from(*range.end())),
+    );
+    let ratio = match current {
+        Some(value) if max > min => ((value - min) / (max - min)).clamp(0.0, 1.0),
+        _ => 0.0,
+    };
+    let label = match current {
+        Some(value) => format!("{value:.0} {}", metric.unit()),
+        None => "-".to_owned(),
+    };
+    frame.render_widget(
+        Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title(metric.label()))
+            .gauge_style(Style::default().fg(Color::Red))
+            .ratio(ratio)
+            .label(label),
+        gauge_area,
+    );
+
+    // Only the samples that fit in the sparkline, offset so that `min` is at the bottom
+    let width = usize::from(sparkline_area.width.saturating_sub(2));
+    let data = history
+        .iter()
+        .skip(history.len().saturating_sub(width))
+        .map(|s| {
+            metric
+                .value(s)
+                .map_or(0, |v| (v - min).max(0.0).round() as u64)
+        })
+        .collect::<Vec<_>>();
+    frame.render_widget(
+        Sparkline::default()
+            .block(Block::default().borders(Borders::ALL))
+            .style(Style::default().fg(Color::Red))
+            .data(&data)
+            .max((max - min).max(1.0).round() as u64),
+        sparkline_area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use crate::app::{tests::FakeSource, Action, App};
+
+    fn render(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+        terminal.draw(|frame| super::draw(frame, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(usize::from(buffer.area.width))
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn renders_supported_metrics() {
+        let mut source = FakeSource::new();
+        let mut app = App::new(&source).unwrap();
+        for _ in 0..5 {
+            app.tick(&mut source);
+        }
+
+        let screen = render(&app);
+        assert!(screen.contains("AMD Radeon RX 7900 XTX"));
+        assert!(screen.contains("Fan speed"));
+        assert!(screen.contains("1650 RPM"));
+        assert!(screen.contains("Interval 500 ms"));
+        assert!(!screen.contains("Hotspot temp"));
+
+        app.handle(Action::NextGpu, &mut source);
+        app.handle(Action::IncreaseInterval, &mut source);
+        let screen = render(&app);
+        assert!(screen.contains("Edge temp"));
+        assert!(!screen.contains("Fan speed"));
+        assert!(screen.contains("Interval 600 ms"));
+    }
+}
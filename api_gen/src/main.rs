@@ -1,8 +1,42 @@
-use std::path::Path;
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
 
+mod wrappers;
+
+/// Reads `ADLX_VER_MAJOR` and `ADLX_VER_MINOR` from `ADLXDefines.h`, to name the module that the
+/// bindings of this SDK are written to.
+fn sdk_version(include_dir: &Path) -> (u32, u32) {
+    let defines_path = include_dir.join("ADLXDefines.h");
+    let defines = std::fs::read_to_string(&defines_path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", defines_path.display()));
+    let define = |name: &str| {
+        defines
+            .lines()
+            .find_map(|line| {
+                let mut words = line.split_whitespace();
+                (words.next() == Some("#define") && words.next() == Some(name))
+                    .then(|| words.next()?.trim_end_matches(['u', 'U']).parse().ok())
+                    .flatten()
+            })
+            .unwrap_or_else(|| panic!("{name} not found in {}", defines_path.display()))
+    };
+    (define("ADLX_VER_MAJOR"), define("ADLX_VER_MINOR"))
+}
+
+/// Generates `src/ffi/sdk_<major>_<minor>.rs` from the ADLX SDK checkout passed as the first
+/// argument, `vendor/ADLX` by default, and the wrappers for these bindings in
+/// `src/generated/sdk_<major>_<minor>.rs`.
 fn main() {
     let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
 
+    let sdk_dir = std::env::args_os()
+        .nth(1)
+        .map_or_else(|| crate_root.join("../vendor/ADLX"), PathBuf::from);
+    let include_dir = sdk_dir.join("SDK/Include/");
+    let (major, minor) = sdk_version(&include_dir);
+
     let header_path = crate_root.join("wrapper.h");
 
     let msrv = bindgen::RustTarget::stable(74, 0).unwrap();
@@ -10,15 +44,28 @@ fn main() {
     let bindings = bindgen::Builder::default()
         .header(header_path.to_string_lossy())
         .rust_target(msrv)
-        .clang_arg(format!(
-            "-I{}",
-            crate_root.join("../vendor/ADLX/SDK/Include/").display()
-        ))
+        .clang_arg(format!("-I{}", include_dir.display()))
         .allowlist_item("I?ADLX\\w+")
         .generate()
         .expect("failed to generate adlx bindings");
 
+    let ffi_path = crate_root.join(format!("../src/ffi/sdk_{major}_{minor}.rs"));
     bindings
-        .write_to_file(crate_root.join("../src/ffi.rs"))
+        .write_to_file(&ffi_path)
         .expect("Couldn't write bindings!");
+
+    let bindings = syn::parse_file(&std::fs::read_to_string(&ffi_path).unwrap())
+        .expect("failed to parse the generated bindings");
+    let wrappers = wrappers::generate(&bindings, &crate_root.join("../src"), (major, minor));
+    let wrappers_path = crate_root.join(format!("../src/generated/sdk_{major}_{minor}.rs"));
+    std::fs::create_dir_all(wrappers_path.parent().unwrap()).unwrap();
+    std::fs::write(&wrappers_path, wrappers).expect("Couldn't write wrappers!");
+
+    // Format like the hand-written sources, so that `cargo fmt --check` passes
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .arg(&wrappers_path)
+        .status()
+        .expect("failed to run rustfmt");
+    assert!(status.success(), "rustfmt failed on the generated wrappers");
 }
@@ -0,0 +1,1414 @@
+//! Generates safe wrappers for the ADLX interfaces that are not wrapped by hand in `src/`, and the
+//! methods that hand-written wrappers leave out.
+//!
+//! The bindings are parsed back with `syn` to find every `IADLX*` interface and its vtable. A
+//! function in a vtable becomes a method when all its arguments map to Rust types: plain values
+//! and interfaces are passed in, and trailing `*mut` arguments are returned. Other functions, like
+//! those taking listeners or wide strings, are left for hand-written wrappers. Interfaces that no
+//! wrapper hands out, like those of `IADLXDisplayServices1`, are not generated.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Ident, Literal, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+
+/// The base interface, which is wrapped by `InterfaceImpl`
+const BASE_INTERFACE: &str = "IADLXInterface";
+/// The untyped list interface, whose typed lists are wrapped by `AdlxList<T>`
+const BASE_LIST: &str = "IADLXList";
+/// The ADLX version that introduced each interface that was added after 1.0, which becomes its
+/// `Interface::MIN_VERSION`. Interfaces that are not listed were part of ADLX 1.0.
+const MIN_VERSIONS: &[(&str, (u16, u16))] = &[
+    ("IADLX3DAntiLag1", (1, 2)),
+    ("IADLXSystemMetricsSupport1", (1, 2)),
+    ("IADLXSystemMetrics1", (1, 2)),
+    ("IADLXDisplayVariBright1", (1, 2)),
+    ("IADLXDisplayBlanking", (1, 2)),
+    ("IADLXDisplayConnectivityExperience", (1, 2)),
+    ("IADLXDisplayDynamicRefreshRateControl", (1, 3)),
+    ("IADLXDisplayFreeSyncColorAccuracy", (1, 3)),
+];
+
+/// An `IADLX*` interface declared in the bindings.
+struct FfiInterface {
+    name: String,
+    vtable: String,
+    functions: Vec<(String, syn::TypeBareFn)>,
+}
+
+/// What the hand-written sources in `src/` already provide.
+#[derive(Default)]
+struct HandWritten {
+    /// Rust type of every wrapped interface, by the name of its `Interface::Impl`
+    wrappers: BTreeMap<String, TokenStream>,
+    /// Vtables that are used by hand-written wrappers or `list_item!` calls
+    vtables: BTreeSet<String>,
+    /// Rust enums generated by `adlx_enum!`, by the name of their C enum
+    enums: BTreeMap<String, Ident>,
+    /// Public items, which generated wrappers must not be named after
+    names: BTreeSet<String>,
+    /// Identifiers in the inherent `impl` blocks of every hand-written type, which include the
+    /// vtable functions that it wraps
+    used: BTreeMap<String, BTreeSet<String>>,
+    /// C enums declared in the bindings, which must not be exposed without an `adlx_enum!`
+    ffi_enums: BTreeSet<String>,
+    /// `ADLX_*` structs that implement `FfiValue`, which `adlx_interface!` getters and setters
+    /// can take and return
+    ffi_values: BTreeSet<String>,
+}
+
+/// Returns the Rust source of the wrappers for `bindings`, skipping all interfaces that are
+/// wrapped in the sources of `src_dir`.
+pub fn generate(bindings: &syn::File, src_dir: &Path, sdk_version: (u32, u32)) -> String {
+    let interfaces = ffi_interfaces(bindings);
+    let mut hand_written = hand_written(src_dir);
+    hand_written.ffi_enums = ffi_enums(bindings);
+    let hand_written_types = hand_written
+        .wrappers
+        .values()
+        .map(ToString::to_string)
+        .chain(hand_written.enums.values().map(ToString::to_string))
+        .collect::<BTreeSet<_>>();
+    let by_name = interfaces
+        .iter()
+        .map(|i| (i.name.as_str(), i))
+        .collect::<BTreeMap<_, _>>();
+    for (name, _) in MIN_VERSIONS {
+        assert!(
+            by_name.contains_key(name),
+            "MIN_VERSIONS lists {name}, which is not in the bindings"
+        );
+    }
+
+    let generated = interfaces
+        .iter()
+        .filter(|i| {
+            !hand_written.vtables.contains(&i.vtable)
+                && !i.name.ends_with("Listener")
+                && parent(i, &by_name).map(|p| p.name.as_str()) != Some(BASE_LIST)
+        })
+        .collect::<Vec<_>>();
+
+    let roots = hand_written.wrappers.keys().cloned().collect::<Vec<_>>();
+    for interface in &generated {
+        let name = rust_name(&interface.name);
+        assert!(
+            !hand_written.names.contains(&name),
+            "Generated wrapper {name} for {} collides with a hand-written item",
+            interface.name
+        );
+        let ident = format_ident!("{name}");
+        hand_written
+            .wrappers
+            .insert(interface.name.clone(), quote!(#ident));
+    }
+
+    // Typed lists of which the element has a (generated) wrapper
+    let lists = interfaces
+        .iter()
+        .filter(|i| {
+            !hand_written.vtables.contains(&i.vtable)
+                && parent(i, &by_name).map(|p| p.name.as_str()) == Some(BASE_LIST)
+        })
+        .filter_map(|list| {
+            let item = list.name.strip_suffix("List")?;
+            let item_wrapper = hand_written.wrappers.get(item)?.clone();
+            Some((list, item_wrapper))
+        })
+        .collect::<Vec<_>>();
+    for (list, item) in &lists {
+        hand_written
+            .wrappers
+            .insert(list.name.clone(), quote!(AdlxList<#item>));
+    }
+
+    // Wrappers that no method hands out could never be obtained, so they are left out
+    let reachable = reachable(&interfaces, &by_name, roots, &hand_written);
+    hand_written
+        .wrappers
+        .retain(|name, _| reachable.contains(name));
+    let generated = generated
+        .into_iter()
+        .filter(|i| reachable.contains(&i.name))
+        .collect::<Vec<_>>();
+    let lists = lists
+        .into_iter()
+        .filter(|(list, _)| reachable.contains(&list.name))
+        .collect::<Vec<_>>();
+
+    let mut items = Vec::new();
+    let mut macro_calls = MacroCalls::default();
+    for interface in &generated {
+        items.push(wrapper(
+            interface,
+            &by_name,
+            &hand_written,
+            &mut macro_calls,
+        ));
+    }
+    for (list, item) in &lists {
+        items.push(list_alias(list, item));
+    }
+    // Functions that hand-written wrappers do not call themselves, like the getters of the
+    // settings that `IADLX3DSettingsServices` hands out
+    for interface in &interfaces {
+        if generated.iter().any(|g| g.name == interface.name)
+            || interface.name == BASE_LIST
+            || interface.name.ends_with("Listener")
+            || parent(interface, &by_name).map(|p| p.name.as_str()) == Some(BASE_LIST)
+        {
+            continue;
+        }
+        if let Some(extension) = extension(interface, &by_name, &hand_written, &mut macro_calls) {
+            items.push(extension);
+        }
+    }
+
+    let (major, minor) = sdk_version;
+    let header = format!(
+        "//! Wrappers generated by `api_gen` from the ADLX SDK {major}.{minor} bindings, for the \
+         interfaces\n//! that are not wrapped by hand, and the methods that hand-written wrappers \
+         leave out. Do not\n//! edit this file, run `cargo run -p api_gen` instead.\n\n"
+    );
+    let items = quote!(#(#items)*);
+
+    // Only import what is used, to not trip `unused_imports`
+    let mut used = BTreeSet::new();
+    collect_idents(items.clone(), &mut used);
+    for (tokens, _) in &macro_calls.calls {
+        collect_idents(tokens.clone(), &mut used);
+    }
+    // Method definitions call `Interface::vtable()`, which needs the trait in scope
+    if used.contains("vtable") {
+        used.insert("Interface".to_owned());
+    }
+    let import = |names: &[&str]| {
+        names
+            .iter()
+            .filter(|name| used.contains(**name))
+            .map(|name| format_ident!("{name}"))
+            .collect::<Vec<_>>()
+    };
+    let std_imports = [
+        ("CStr", quote!(ffi::CStr)),
+        ("MaybeUninit", quote!(mem::MaybeUninit)),
+    ]
+    .into_iter()
+    .filter(|(name, _)| used.contains(*name))
+    .map(|(_, path)| path);
+    let interface_imports = import(&["adlx_interface", "Interface"]);
+    let list_imports = import(&["list_item", "AdlxList"]);
+    let version_imports = import(&["check_interface", "thread_runtime_version", "AdlxVersion"]);
+    let hand_written_imports = hand_written_types
+        .iter()
+        .filter(|name| used.contains(*name) && !["InterfaceImpl", "List"].contains(&name.as_str()))
+        .map(|name| format_ident!("{name}"));
+
+    let file = quote! {
+        use std::{#(#std_imports),*};
+
+        use crate::{
+            ffi,
+            interface::{#(#interface_imports),*},
+            list::{#(#list_imports),*},
+            result::{Error, Result},
+            version::{#(#version_imports),*},
+            #(#hand_written_imports),*
+        };
+
+        #items
+    };
+    let file = syn::parse2(file).expect("generated wrappers are not valid Rust");
+    header + &macro_calls.render(&separate_items(&prettyplease::unparse(&file)))
+}
+
+/// Returns the interfaces that can be obtained starting from the `roots`: those returned by a
+/// method of a reachable interface, the ancestors and descendants of reachable interfaces, which
+/// `Interface::cast()` converts between, and the items of reachable lists.
+fn reachable(
+    interfaces: &[FfiInterface],
+    by_name: &BTreeMap<&str, &FfiInterface>,
+    roots: Vec<String>,
+    hand_written: &HandWritten,
+) -> BTreeSet<String> {
+    let mut reachable = roots.into_iter().collect::<BTreeSet<_>>();
+    loop {
+        let mut found = Vec::new();
+        for interface in interfaces {
+            let parent = parent(interface, by_name);
+            if !reachable.contains(&interface.name) {
+                // Descendants, except for the typed lists that derive from `IADLXList`
+                if let Some(parent) = parent.filter(|p| p.name != BASE_LIST) {
+                    if reachable.contains(&parent.name) {
+                        found.push(interface.name.clone());
+                    }
+                }
+                continue;
+            }
+            if let Some(parent) = parent {
+                found.push(parent.name.clone());
+                if parent.name == BASE_LIST {
+                    found.extend(interface.name.strip_suffix("List").map(str::to_owned));
+                }
+            }
+            for (function, signature) in &interface.functions {
+                if method(&interface.name, function, signature, hand_written).is_none() {
+                    continue;
+                }
+                let (inputs, outputs) = interface_arguments(signature);
+                if inputs.iter().all(|input| reachable.contains(input)) {
+                    found.extend(outputs);
+                }
+            }
+        }
+        let count = reachable.len();
+        reachable.extend(found);
+        if reachable.len() == count {
+            return reachable;
+        }
+    }
+}
+
+/// The interfaces that a vtable function takes, and those that it returns.
+fn interface_arguments(signature: &syn::TypeBareFn) -> (Vec<String>, Vec<String>) {
+    let mut inputs = Vec::new();
+    let mut outputs = Vec::new();
+    for arg in signature.inputs.iter().skip(1) {
+        let syn::Type::Ptr(ptr) = &arg.ty else {
+            continue;
+        };
+        match &*ptr.elem {
+            syn::Type::Ptr(inner) => outputs.extend(last_ident(&inner.elem)),
+            elem => inputs.extend(last_ident(elem)),
+        }
+    }
+    let is_interface = |name: &String| name.starts_with("IADLX");
+    inputs.retain(is_interface);
+    outputs.retain(is_interface);
+    (inputs, outputs)
+}
+
+/// Inserts the blank lines that `prettyplease` leaves out between items, which `rustfmt` keeps,
+/// and puts the `list_item!` calls that it splits back on one line.
+fn separate_items(source: &str) -> String {
+    let starts_item = |line: &str| {
+        [
+            "///",
+            "#[",
+            "use ",
+            "pub ",
+            "impl ",
+            "unsafe impl ",
+            "fn ",
+            "adlx_interface!",
+            "list_item!",
+        ]
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+    };
+    let mut lines = Vec::<String>::new();
+    let mut macro_call = None::<String>;
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        if let Some(call) = &mut macro_call {
+            if !call.ends_with('(') && trimmed != ");" {
+                call.push(' ');
+            }
+            call.push_str(trimmed);
+            if call.ends_with(");") {
+                lines.push(macro_call.take().unwrap());
+            }
+            continue;
+        }
+        if trimmed.starts_with("list_item!") {
+            let call = trimmed.replacen(" : ", ": ", 1);
+            if call.ends_with(");") {
+                lines.push(call);
+            } else {
+                macro_call = Some(call);
+            }
+            continue;
+        }
+        lines.push(line.to_owned());
+    }
+
+    let mut separated = String::with_capacity(source.len());
+    let mut previous = "";
+    for line in &lines {
+        let trimmed = line.trim_start();
+        if starts_item(trimmed) && (previous == "}" || previous.ends_with(';')) {
+            separated.push('\n');
+        }
+        separated.push_str(line);
+        separated.push('\n');
+        previous = trimmed;
+    }
+    separated
+}
+
+fn collect_idents(tokens: TokenStream, idents: &mut BTreeSet<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the names of the C enums in `bindings`, which bindgen declares as an integer alias
+/// like `pub type ADLX_GPU_TYPE = ::std::os::raw::c_int;` followed by a constant per value.
+/// Structs like `ADLX_IntRange` are passed as they are.
+fn ffi_enums(bindings: &syn::File) -> BTreeSet<String> {
+    let aliases = bindings
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Type(t) if matches!(last_ident(&t.ty)?.as_str(), "c_int" | "c_uint") => {
+                Some(t.ident.to_string())
+            }
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+    bindings
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Const(c) => last_ident(&c.ty).filter(|ty| aliases.contains(ty)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collects every interface struct with a `pVtbl` field, and the functions in its vtable.
+fn ffi_interfaces(bindings: &syn::File) -> Vec<FfiInterface> {
+    let structs = bindings
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(s) => Some((s.ident.to_string(), s)),
+            _ => None,
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    bindings
+        .items
+        .iter()
+        .filter_map(|item| {
+            let syn::Item::Struct(s) = item else {
+                return None;
+            };
+            let name = s.ident.to_string();
+            if !name.starts_with("IADLX") || name.ends_with("Vtbl") {
+                return None;
+            }
+            let [field] = s.fields.iter().collect::<Vec<_>>()[..] else {
+                return None;
+            };
+            if field.ident.as_ref()? != "pVtbl" {
+                return None;
+            }
+            let syn::Type::Ptr(ptr) = &field.ty else {
+                return None;
+            };
+            let vtable = last_ident(&ptr.elem)?;
+            let functions = structs
+                .get(&vtable)?
+                .fields
+                .iter()
+                .filter_map(|f| Some((f.ident.as_ref()?.to_string(), optional_fn(&f.ty)?)))
+                .collect::<Vec<_>>();
+
+            // Only interfaces that derive from `IADLXInterface` are reference counted
+            let base = ["Acquire", "Release", "QueryInterface"];
+            (functions.len() >= base.len()
+                && functions.iter().zip(base).all(|((name, _), b)| name == b))
+            .then_some(FfiInterface {
+                name,
+                vtable,
+                functions,
+            })
+        })
+        .collect()
+}
+
+/// Extracts `fn(..)` from `Option<unsafe extern "C" fn(..)>`.
+fn optional_fn(ty: &syn::Type) -> Option<syn::TypeBareFn> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(syn::Type::BareFn(f)) if segment.ident == "Option" => {
+            Some(f.clone())
+        }
+        _ => None,
+    }
+}
+
+fn last_ident(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(path) => Some(path.path.segments.last()?.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// The interface that `interface` extends, by the functions that its vtable starts with.
+///
+/// Unrelated interfaces can start with the same functions, like `IsSupported` and `IsEnabled`, so
+/// the parent must also be an older version of the same interface (`IADLXGPU1` of `IADLXGPU2`),
+/// or share its suffix (`IADLXChangedEvent` of `IADLXDisplayGamutChangedEvent`).
+fn parent<'a>(
+    interface: &FfiInterface,
+    interfaces: &BTreeMap<&str, &'a FfiInterface>,
+) -> Option<&'a FfiInterface> {
+    let version_base = |name: &str| {
+        name.trim_end_matches(|c: char| c.is_ascii_digit())
+            .to_owned()
+    };
+    interfaces
+        .values()
+        .filter(|p| {
+            p.name != BASE_INTERFACE
+                && p.functions.len() < interface.functions.len()
+                && p.functions
+                    .iter()
+                    .zip(&interface.functions)
+                    .all(|((a, _), (b, _))| a == b)
+                && (version_base(&p.name) == version_base(&interface.name)
+                    || interface.name.ends_with(&p.name["IADLX".len()..]))
+        })
+        .max_by_key(|p| p.functions.len())
+        .copied()
+}
+
+/// Scans the hand-written modules in `src_dir`, without descending into submodules.
+fn hand_written(src_dir: &Path) -> HandWritten {
+    let mut hand_written = HandWritten::default();
+    let mut paths = std::fs::read_dir(src_dir)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", src_dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .filter(|path| {
+            !["ffi.rs", "generated.rs"].contains(&&*path.file_name().unwrap().to_string_lossy())
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    for path in paths {
+        let source = std::fs::read_to_string(&path).unwrap();
+        let file = syn::parse_file(&source)
+            .unwrap_or_else(|e| panic!("failed to parse {}: {e}", path.display()));
+        for item in &file.items {
+            scan_item(item, &mut hand_written);
+        }
+    }
+    hand_written
+}
+
+fn scan_item(item: &syn::Item, hand_written: &mut HandWritten) {
+    let is_pub = |vis: &syn::Visibility| matches!(vis, syn::Visibility::Public(_));
+    match item {
+        syn::Item::Struct(s) if is_pub(&s.vis) => {
+            hand_written.names.insert(s.ident.to_string());
+        }
+        syn::Item::Enum(e) if is_pub(&e.vis) => {
+            hand_written.names.insert(e.ident.to_string());
+        }
+        syn::Item::Type(t) if is_pub(&t.vis) => {
+            hand_written.names.insert(t.ident.to_string());
+        }
+        syn::Item::Trait(t) if is_pub(&t.vis) => {
+            hand_written.names.insert(t.ident.to_string());
+        }
+        syn::Item::Impl(i) => {
+            // Generic wrappers like `AdlxList<T>` are described by `list_item!` instead
+            let syn::Type::Path(self_ty) = &*i.self_ty else {
+                return;
+            };
+            let self_ident = &self_ty.path.segments.last().unwrap().ident;
+            if !i.generics.params.is_empty() {
+                return;
+            }
+            let Some((_, trait_path, _)) = &i.trait_ else {
+                let items = &i.items;
+                collect_idents(
+                    quote!(#(#items)*),
+                    hand_written.used.entry(self_ident.to_string()).or_default(),
+                );
+                return;
+            };
+            if trait_path.segments.last().unwrap().ident != "Interface" {
+                return;
+            }
+            for impl_item in &i.items {
+                let syn::ImplItem::Type(ty) = impl_item else {
+                    continue;
+                };
+                let Some(ffi_name) = last_ident(&ty.ty) else {
+                    continue;
+                };
+                if ty.ident == "Impl" {
+                    hand_written.wrappers.insert(ffi_name, quote!(#self_ident));
+                } else if ty.ident == "Vtable" {
+                    hand_written.vtables.insert(ffi_name);
+                }
+            }
+        }
+        syn::Item::Macro(m) => {
+            let tokens = m.mac.tokens.clone().into_iter().collect::<Vec<_>>();
+            if m.mac.path.is_ident("list_item") {
+                // `Item => IADLXItemList(IADLXItemListVtbl), At_ItemList, Add_Back_ItemList`
+                let [TokenTree::Ident(item), _, _, TokenTree::Ident(list), TokenTree::Group(vtable), ..] =
+                    &tokens[..]
+                else {
+                    panic!("unexpected list_item! syntax");
+                };
+                hand_written
+                    .wrappers
+                    .insert(list.to_string(), quote!(AdlxList<#item>));
+                hand_written.vtables.insert(vtable.stream().to_string());
+            } else if m.mac.path.is_ident("adlx_enum") {
+                // `pub enum Name(ADLX_NAME) { .. }`, after any attributes
+                let position = tokens
+                    .iter()
+                    .position(|t| matches!(t, TokenTree::Ident(i) if i == "enum"))
+                    .expect("unexpected adlx_enum! syntax");
+                let (TokenTree::Ident(name), TokenTree::Group(ffi_name)) =
+                    (&tokens[position + 1], &tokens[position + 2])
+                else {
+                    panic!("unexpected adlx_enum! syntax");
+                };
+                hand_written
+                    .enums
+                    .insert(ffi_name.stream().to_string(), name.clone());
+                hand_written.names.insert(name.to_string());
+            } else if m.mac.path.is_ident("ffi_value_as_is") {
+                // `u32, ffi::ADLX_IntRange`
+                let mut idents = BTreeSet::new();
+                collect_idents(m.mac.tokens.clone(), &mut idents);
+                hand_written
+                    .ffi_values
+                    .extend(idents.into_iter().filter(|i| i.starts_with("ADLX_")));
+            } else if m.mac.path.is_ident("adlx_interface") {
+                // `impl Name { .. }`, which adds methods to an interface declared elsewhere
+                if let [TokenTree::Ident(keyword), TokenTree::Ident(name), ..] = &tokens[..] {
+                    if keyword == "impl" {
+                        collect_idents(
+                            m.mac.tokens.clone(),
+                            hand_written.used.entry(name.to_string()).or_default(),
+                        );
+                        return;
+                    }
+                }
+                // `pub struct Name: IADLXName(IADLXNameVtbl) { .. }`, after any attributes
+                let position = tokens
+                    .iter()
+                    .position(|t| matches!(t, TokenTree::Ident(i) if i == "struct"))
+                    .expect("unexpected adlx_interface! syntax");
+                let (TokenTree::Ident(name), TokenTree::Ident(ffi_name), TokenTree::Group(vtable)) = (
+                    &tokens[position + 1],
+                    &tokens[position + 3],
+                    &tokens[position + 4],
+                ) else {
+                    panic!("unexpected adlx_interface! syntax");
+                };
+                hand_written
+                    .wrappers
+                    .insert(ffi_name.to_string(), quote!(#name));
+                hand_written.vtables.insert(vtable.stream().to_string());
+                hand_written.names.insert(name.to_string());
+                collect_idents(
+                    m.mac.tokens.clone(),
+                    hand_written.used.entry(name.to_string()).or_default(),
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `IADLX3DAntiLag1` becomes `ThreeDAntiLag1`, and `IADLXDisplayHDCP` becomes `DisplayHdcp`.
+fn rust_name(ffi_name: &str) -> String {
+    let name = ffi_name.strip_prefix("IADLX").unwrap_or(ffi_name);
+    match name.strip_prefix("3D") {
+        Some(rest) => format!("ThreeD{rest}"),
+        None => name.to_owned(),
+    }
+    // Otherwise the plural is split as `GP` and `Us`
+    .replace("GPUs", "Gpus")
+    .to_upper_camel_case()
+}
+
+/// The snake-case name of a method or argument, which must not be a keyword or shadow `result`.
+///
+/// `3D` is kept together, like in the hand-written `System::get_3d_settings_services()`.
+fn snake_ident(name: &str) -> Ident {
+    let name = name.replace("3D", "_3D_").to_snake_case();
+    match syn::parse_str::<Ident>(&name) {
+        Ok(_) if name != "result" && name != "self" => Ident::new(&name, Span::call_site()),
+        _ => format_ident!("{name}_"),
+    }
+}
+
+/// Strips the Hungarian `p`/`pp` prefix of pointer arguments, like `ppChill`.
+fn argument_name(name: &str) -> &str {
+    for prefix in ["pp", "p"] {
+        if let Some(rest) = name.strip_prefix(prefix) {
+            if rest.starts_with(|c: char| c.is_ascii_uppercase()) {
+                return rest;
+            }
+        }
+    }
+    name
+}
+
+/// Escapes a name the way Doxygen does in the file names of the ADLX manual: every uppercase
+/// letter becomes `_` and its lowercase.
+fn doxygen_escape(name: &str) -> String {
+    name.chars()
+        .flat_map(|c| match c {
+            'A'..='Z' => vec!['_', c.to_ascii_lowercase()],
+            c => vec![c],
+        })
+        .collect()
+}
+
+/// Links to the page of an interface, or of a function when `function` is given.
+fn doc_url(interface: &str, function: Option<&str>) -> String {
+    let page = match function {
+        Some(function) => format!("DOX_{interface}_{function}"),
+        None => format!("DOX_{interface}"),
+    };
+    format!(
+        " <https://gpuopen.com/manuals/adlx/adlx-{}/>",
+        doxygen_escape(&page)
+    )
+}
+
+/// How a value type crosses the FFI boundary.
+enum Value {
+    Bool,
+    Primitive(TokenStream),
+    Enum(Ident),
+    Ffi(Ident),
+}
+
+impl Value {
+    fn parse(name: &str, hand_written: &HandWritten) -> Option<Self> {
+        Some(match name {
+            "adlx_bool" => Self::Bool,
+            "adlx_int" | "adlx_int32" => Self::Primitive(quote!(i32)),
+            "adlx_uint" | "adlx_uint32" => Self::Primitive(quote!(u32)),
+            "adlx_int64" => Self::Primitive(quote!(i64)),
+            "adlx_uint64" => Self::Primitive(quote!(u64)),
+            "adlx_uint16" => Self::Primitive(quote!(u16)),
+            "adlx_uint8" => Self::Primitive(quote!(u8)),
+            "adlx_double" => Self::Primitive(quote!(f64)),
+            "adlx_size" => Self::Primitive(quote!(usize)),
+            name if hand_written.enums.contains_key(name) => {
+                Self::Enum(hand_written.enums[name].clone())
+            }
+            name if name.starts_with("ADLX_") => Self::Ffi(format_ident!("{name}")),
+            _ => return None,
+        })
+    }
+
+    /// Whether the value implements `FfiValue`, so that `adlx_interface!` can convert it.
+    fn is_ffi_value(&self, hand_written: &HandWritten) -> bool {
+        match self {
+            Self::Bool | Self::Primitive(_) | Self::Enum(_) => true,
+            Self::Ffi(ffi) => hand_written.ffi_values.contains(&ffi.to_string()),
+        }
+    }
+
+    fn rust_type(&self) -> TokenStream {
+        match self {
+            Self::Bool => quote!(bool),
+            Self::Primitive(ty) => ty.clone(),
+            Self::Enum(rust) => quote!(#rust),
+            Self::Ffi(ffi) => quote!(ffi::#ffi),
+        }
+    }
+}
+
+/// An argument of a vtable function, after `pThis`.
+enum Argument {
+    Value(Value),
+    Interface(TokenStream),
+    OutValue(Value),
+    OutInterface(TokenStream),
+    OutStr,
+}
+
+impl Argument {
+    fn parse(ty: &syn::Type, hand_written: &HandWritten) -> Option<Self> {
+        let syn::Type::Ptr(ptr) = ty else {
+            return Some(Self::Value(Value::parse(&last_ident(ty)?, hand_written)?));
+        };
+        match &*ptr.elem {
+            syn::Type::Ptr(inner) if inner.mutability.is_some() => {
+                let name = last_ident(&inner.elem)?;
+                Some(Self::OutInterface(
+                    hand_written.wrappers.get(&name)?.clone(),
+                ))
+            }
+            syn::Type::Ptr(inner) if last_ident(&inner.elem)? == "c_char" => Some(Self::OutStr),
+            syn::Type::Ptr(_) => None,
+            elem if ptr.mutability.is_some() => {
+                let name = last_ident(elem)?;
+                if name.starts_with("IADLX") {
+                    Some(Self::Interface(hand_written.wrappers.get(&name)?.clone()))
+                } else {
+                    Some(Self::OutValue(Value::parse(&name, hand_written)?))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn is_out(&self) -> bool {
+        matches!(
+            self,
+            Self::OutValue(_) | Self::OutInterface(_) | Self::OutStr
+        )
+    }
+}
+
+fn wrapper(
+    interface: &FfiInterface,
+    interfaces: &BTreeMap<&str, &FfiInterface>,
+    hand_written: &HandWritten,
+    macro_calls: &mut MacroCalls,
+) -> TokenStream {
+    let ffi_name = &interface.name;
+    let name = format_ident!("{}", rust_name(ffi_name));
+    let ffi_ident = format_ident!("{ffi_name}");
+    let vtable = format_ident!("{}", interface.vtable);
+
+    let parent = parent(interface, interfaces);
+    let inherited = parent.map_or(0, |p| p.functions.len());
+    let inheritance = parent.map(|parent| {
+        let mut ancestors = vec![hand_written.wrappers[&parent.name].clone()];
+        let mut ancestor = parent;
+        while let Some(next) = self::parent(ancestor, interfaces) {
+            ancestors.push(hand_written.wrappers[&next.name].clone());
+            ancestor = next;
+        }
+        quote!(: #(#ancestors),*)
+    });
+
+    let min_version =
+        MIN_VERSIONS
+            .iter()
+            .find(|(name, _)| name == ffi_name)
+            .map(|(_, (major, minor))| {
+                let (major, minor) = (
+                    Literal::u16_unsuffixed(*major),
+                    Literal::u16_unsuffixed(*minor),
+                );
+                quote!(const MIN_VERSION: AdlxVersion = AdlxVersion::new(#major, #minor);)
+            });
+
+    let (declarations, definitions) = methods(
+        interface.functions[inherited.max(3)..].iter(),
+        ffi_name,
+        hand_written,
+    );
+    let definitions = (!definitions.is_empty()).then(|| {
+        quote! {
+            impl #name {
+                #(#definitions)*
+            }
+        }
+    });
+
+    let declaration = macro_calls.wrapper(
+        ffi_name,
+        quote!(pub struct #name: #ffi_ident(#vtable) #inheritance),
+        min_version,
+        &declarations,
+    );
+    quote! {
+        #declaration
+
+        #definitions
+    }
+}
+
+/// Adds the functions of a hand-written wrapper that it does not call itself, or returns [`None`]
+/// if it already calls all those that can be wrapped.
+fn extension(
+    interface: &FfiInterface,
+    interfaces: &BTreeMap<&str, &FfiInterface>,
+    hand_written: &HandWritten,
+    macro_calls: &mut MacroCalls,
+) -> Option<TokenStream> {
+    let ty = hand_written.wrappers.get(&interface.name)?;
+    let used = hand_written.used.get(&ty.to_string());
+    let inherited = parent(interface, interfaces).map_or(0, |p| p.functions.len());
+    let (declarations, definitions) = methods(
+        interface.functions[inherited.max(3)..]
+            .iter()
+            .filter(|(function, _)| !used.is_some_and(|used| used.contains(function))),
+        &interface.name,
+        hand_written,
+    );
+    let declarations = (!declarations.is_empty()).then(|| macro_calls.extension(ty, &declarations));
+    let definitions = (!definitions.is_empty()).then(|| {
+        quote! {
+            impl #ty {
+                #(#definitions)*
+            }
+        }
+    });
+    (declarations.is_some() || definitions.is_some()).then(|| quote!(#declarations #definitions))
+}
+
+/// A vtable function that can be wrapped.
+enum Method {
+    /// A getter or setter, declared in an `adlx_interface!` call
+    Declaration(Declaration),
+    /// Any other function, defined in an `impl` block
+    Definition(TokenStream),
+}
+
+/// A getter or setter in an `adlx_interface!` call, like
+/// `fn is_enabled() -> bool = IsEnabled;`.
+struct Declaration {
+    tokens: TokenStream,
+    /// The declaration with its doc comment and alias, formatted like the hand-written calls
+    source: String,
+}
+
+impl Declaration {
+    fn new(url: &str, function: &str, declaration: TokenStream) -> Self {
+        let source = format!(
+            "///{url}\n#[doc(alias = \"{function}\")]\n{}",
+            short_source(&declaration)
+        );
+        Self {
+            tokens: quote! {
+                #[doc = #url]
+                #[doc(alias = #function)]
+                #declaration
+            },
+            source,
+        }
+    }
+}
+
+/// The `adlx_interface!` calls in the generated file. `prettyplease` puts the contents of macro
+/// calls on as few lines as possible and `rustfmt` leaves them alone, so they are rendered by
+/// hand, and stand in for by a numbered placeholder call until then.
+#[derive(Default)]
+struct MacroCalls {
+    /// The tokens and source of every call
+    calls: Vec<(TokenStream, String)>,
+}
+
+impl MacroCalls {
+    /// Declares the wrapper of `ffi_name` with `header`, like
+    /// `pub struct Name: IADLXName(IADLXNameVtbl)`.
+    fn wrapper(
+        &mut self,
+        ffi_name: &str,
+        header: TokenStream,
+        min_version: Option<TokenStream>,
+        declarations: &[Declaration],
+    ) -> TokenStream {
+        let url = doc_url(ffi_name, None);
+        let tokens = declarations.iter().map(|d| &d.tokens);
+        let tokens = quote! {
+            adlx_interface! {
+                #[doc = #url]
+                #[doc(alias = #ffi_name)]
+                #header {
+                    #min_version
+                    #(#tokens)*
+                }
+            }
+        };
+        let mut body = min_version
+            .map(|min_version| short_source(&min_version))
+            .into_iter()
+            .chain(declarations.iter().map(|d| d.source.clone()))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        if !body.is_empty() {
+            body = format!("\n{}\n    ", indent(&body, 8));
+        }
+        let source = format!(
+            "adlx_interface! {{\n    ///{url}\n    #[doc(alias = \"{ffi_name}\")]\n    {} {{{body}}}\n}}",
+            short_source(&header)
+        );
+        self.placeholder(tokens, source)
+    }
+
+    /// Adds the `declarations` to `ty`, which is declared by hand.
+    fn extension(&mut self, ty: &TokenStream, declarations: &[Declaration]) -> TokenStream {
+        let tokens = declarations.iter().map(|d| &d.tokens);
+        let tokens = quote! {
+            adlx_interface! {
+                impl #ty {
+                    #(#tokens)*
+                }
+            }
+        };
+        let body = declarations
+            .iter()
+            .map(|d| d.source.clone())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let source = format!(
+            "adlx_interface! {{\n    impl {ty} {{\n{}\n    }}\n}}",
+            indent(&body, 8)
+        );
+        self.placeholder(tokens, source)
+    }
+
+    fn placeholder(&mut self, tokens: TokenStream, source: String) -> TokenStream {
+        let index = Literal::usize_unsuffixed(self.calls.len());
+        self.calls.push((tokens, source));
+        quote!(adlx_interface!(#index);)
+    }
+
+    /// Replaces the placeholders in `source` with the calls.
+    fn render(&self, source: &str) -> String {
+        source
+            .lines()
+            .map(|line| {
+                line.strip_prefix("adlx_interface!(")
+                    .and_then(|index| index.strip_suffix(");"))
+                    .map_or(line, |index| &self.calls[index.parse::<usize>().unwrap()].1)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            + "\n"
+    }
+}
+
+/// The source of a declaration or type, without the spaces that `TokenStream` puts around
+/// punctuation.
+fn short_source(tokens: &TokenStream) -> String {
+    tokens
+        .to_string()
+        .replace(" (", "(")
+        .replace("( ", "(")
+        .replace(" )", ")")
+        .replace(" ,", ",")
+        .replace(" :", ":")
+        .replace(":: ", "::")
+        .replace(" ;", ";")
+}
+
+fn indent(source: &str, width: usize) -> String {
+    source
+        .lines()
+        .map(|line| format!("{:width$}{line}", ""))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wraps the supported `functions`, and returns the declarations of the getters and setters and
+/// the definitions of the other methods.
+fn methods<'a>(
+    functions: impl Iterator<Item = &'a (String, syn::TypeBareFn)>,
+    interface: &str,
+    hand_written: &HandWritten,
+) -> (Vec<Declaration>, Vec<TokenStream>) {
+    let mut declarations = Vec::new();
+    let mut definitions = Vec::new();
+    for (function, signature) in functions {
+        match method(interface, function, signature, hand_written) {
+            Some(Method::Declaration(declaration)) => declarations.push(declaration),
+            Some(Method::Definition(definition)) => definitions.push(definition),
+            None => {}
+        }
+    }
+    (declarations, definitions)
+}
+
+/// Wraps a vtable function, or returns [`None`] if any of its arguments is not supported.
+fn method(
+    interface: &str,
+    function: &str,
+    signature: &syn::TypeBareFn,
+    hand_written: &HandWritten,
+) -> Option<Method> {
+    // Functions like `IADLXChangedEvent::GetOrigin` return their value directly
+    match &signature.output {
+        syn::ReturnType::Type(_, ty) if last_ident(ty)? == "ADLX_RESULT" => {}
+        _ => return None,
+    }
+    let arguments = signature
+        .inputs
+        .iter()
+        .skip(1)
+        .map(|arg| {
+            let name = arg.name.as_ref()?.0.to_string();
+            Some((
+                snake_ident(argument_name(&name)),
+                Argument::parse(&arg.ty, hand_written)?,
+            ))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    for (_, argument) in &arguments {
+        if let Argument::Value(Value::Ffi(ffi)) | Argument::OutValue(Value::Ffi(ffi)) = argument {
+            assert!(
+                !hand_written.ffi_enums.contains(&ffi.to_string()),
+                "{interface}::{function} would expose the C enum {ffi}, declare it with adlx_enum! \
+                 in src/enums.rs"
+            );
+        }
+    }
+    // Outputs must trail the inputs
+    let outputs_start = arguments
+        .iter()
+        .position(|(_, arg)| arg.is_out())
+        .unwrap_or(arguments.len());
+    if arguments[outputs_start..]
+        .iter()
+        .any(|(_, arg)| !arg.is_out())
+    {
+        return None;
+    }
+    let (inputs, outputs) = arguments.split_at(outputs_start);
+
+    let field = format_ident!("{function}");
+    let method_name = snake_ident(function);
+    let url = doc_url(interface, Some(function));
+    match (inputs, outputs) {
+        ([], [(_, Argument::OutValue(value))]) if value.is_ffi_value(hand_written) => {
+            let ty = value.rust_type();
+            return Some(Method::Declaration(Declaration::new(
+                &url,
+                function,
+                quote!(fn #method_name() -> #ty = #field;),
+            )));
+        }
+        ([(name, Argument::Value(value))], [])
+            if function.starts_with("Set") && value.is_ffi_value(hand_written) =>
+        {
+            let ty = value.rust_type();
+            return Some(Method::Declaration(Declaration::new(
+                &url,
+                function,
+                quote!(fn #method_name(#name: #ty) = #field;),
+            )));
+        }
+        _ => {}
+    }
+    let receiver = if function.starts_with("Set") {
+        quote!(&mut self)
+    } else {
+        quote!(&self)
+    };
+
+    let params = inputs.iter().map(|(name, arg)| match arg {
+        Argument::Value(value) => {
+            let ty = value.rust_type();
+            quote!(#name: #ty)
+        }
+        Argument::Interface(wrapper) => quote!(#name: &#wrapper),
+        _ => unreachable!(),
+    });
+    let call_args = arguments.iter().map(|(name, arg)| match arg {
+        Argument::Value(Value::Bool) => quote!(#name.into()),
+        Argument::Value(Value::Enum(_)) => quote!(#name.into_raw()),
+        Argument::Value(_) => quote!(#name),
+        Argument::Interface(_) => quote!(#name.as_raw()),
+        _ => quote!(#name.as_mut_ptr()),
+    });
+    let out_types = outputs.iter().map(|(_, arg)| match arg {
+        Argument::OutValue(value) => value.rust_type(),
+        Argument::OutInterface(wrapper) => wrapper.clone(),
+        Argument::OutStr => quote!(&str),
+        _ => unreachable!(),
+    });
+    let out_names = outputs.iter().map(|(name, _)| name);
+    // Interfaces that the runtime is too old for are rejected before calling into ADLX
+    let version_checks = outputs.iter().filter_map(|(_, arg)| match arg {
+        Argument::OutInterface(wrapper) => {
+            Some(quote!(check_interface::<#wrapper>(thread_runtime_version())?;))
+        }
+        _ => None,
+    });
+
+    // Converts the initialized output `value` to its Rust type, within an `unsafe` block
+    let convert = |arg: &Argument, value: TokenStream| match arg {
+        Argument::OutValue(Value::Bool) => quote!(#value != 0),
+        Argument::OutValue(Value::Enum(rust)) => quote!(#rust::from_raw(#value)),
+        Argument::OutValue(_) => value,
+        Argument::OutInterface(wrapper) => {
+            let path = type_path(wrapper);
+            quote!(#path::from_raw(#value))
+        }
+        Argument::OutStr => quote!(CStr::from_ptr(#value).to_str().unwrap()),
+        _ => unreachable!(),
+    };
+    let (return_type, conversion) = match outputs {
+        [] => (quote!(()), quote!(Error::from_result(result))),
+        [(name, arg)] => {
+            let map = match arg {
+                Argument::OutValue(Value::Enum(rust)) => quote!(.map(#rust::from_raw)),
+                Argument::OutValue(Value::Primitive(_) | Value::Ffi(_)) => quote!(),
+                Argument::OutValue(Value::Bool) => quote!(.map(|#name| #name != 0)),
+                _ => {
+                    let value = convert(arg, quote!(#name));
+                    quote!(.map(|#name| unsafe { #value }))
+                }
+            };
+            let ty = out_types.clone().next().unwrap();
+            (
+                ty,
+                quote!(Error::from_result_with_assume_init_on_success(result, #name) #map),
+            )
+        }
+        _ => {
+            let values = outputs
+                .iter()
+                .map(|(name, arg)| convert(arg, quote!(#name.assume_init())));
+            (
+                quote!((#(#out_types),*)),
+                quote!(Error::from_result(result).map(|()| unsafe { (#(#values),*) })),
+            )
+        }
+    };
+
+    Some(Method::Definition(quote! {
+        #[doc = #url]
+        #[doc(alias = #function)]
+        pub fn #method_name(#receiver #(, #params)*) -> Result<#return_type> {
+            #(#version_checks)*
+            #(let mut #out_names = MaybeUninit::uninit();)*
+            let result = unsafe { (self.vtable().#field.unwrap())(self.as_raw() #(, #call_args)*) };
+            #conversion
+        }
+    }))
+}
+
+/// The expression path of a wrapper type, like `AdlxList::<Gpu>` for `AdlxList<Gpu>`.
+fn type_path(wrapper: &TokenStream) -> TokenStream {
+    let syn::Type::Path(mut path) = syn::parse2(wrapper.clone()).unwrap() else {
+        unreachable!()
+    };
+    for segment in &mut path.path.segments {
+        if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+            args.colon2_token = Some(Default::default());
+        }
+    }
+    quote!(#path)
+}
+
+/// Declares a typed list like `IADLXDisplayResolutionList` with `AdlxList`.
+fn list_alias(list: &FfiInterface, item: &TokenStream) -> TokenStream {
+    let list_name = &list.name;
+    let alias = format_ident!("{}", rust_name(list_name));
+    let list_ident = format_ident!("{list_name}");
+    let vtable = format_ident!("{}", list.vtable);
+    let function = |prefix: &str| {
+        let name = &list
+            .functions
+            .iter()
+            .find(|(name, _)| name.starts_with(prefix))
+            .unwrap_or_else(|| panic!("{list_name} has no {prefix}* function"))
+            .0;
+        format_ident!("{name}")
+    };
+    let (at, add_back) = (function("At_"), function("Add_Back_"));
+    let url = doc_url(list_name, None);
+    // `AdlxList::at()` and `AdlxList::add_back()` are shared by all lists, so the typed functions
+    // are found through the alias
+    let (at_name, add_back_name) = (at.to_string(), add_back.to_string());
+    quote! {
+        list_item!(#item => #list_ident(#vtable), #at, #add_back);
+
+        #[doc = #url]
+        #[doc(alias = #list_name)]
+        #[doc(alias = #at_name)]
+        #[doc(alias = #add_back_name)]
+        pub type #alias = AdlxList<#item>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{
+        argument_name, doc_url, ffi_enums, ffi_interfaces, method, parent, rust_name, snake_ident,
+        wrapper, HandWritten, MacroCalls, Method,
+    };
+
+    /// Declares an interface struct and its vtable, like bindgen does
+    macro_rules! interface {
+        ($name:ident($vtable:ident) { $($function:ident($($arg:ident: $ty:ty),*) -> $ret:ty),* }) => {
+            quote::quote! {
+                pub struct $name {
+                    pub pVtbl: *const $vtable,
+                }
+                pub struct $vtable {
+                    pub Acquire: Option<unsafe extern "C" fn(pThis: *mut $name) -> adlx_long>,
+                    pub Release: Option<unsafe extern "C" fn(pThis: *mut $name) -> adlx_long>,
+                    pub QueryInterface: Option<
+                        unsafe extern "C" fn(
+                            pThis: *mut $name,
+                            interfaceId: *const wchar_t,
+                            ppInterface: *mut *mut c_void,
+                        ) -> ADLX_RESULT,
+                    >,
+                    $(pub $function: Option<unsafe extern "C" fn(pThis: *mut $name $(, $arg: $ty)*) -> $ret>,)*
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn finds_parents_and_wrappable_functions() {
+        let anti_lag = interface!(IADLX3DAntiLag(IADLX3DAntiLagVtbl) {
+            IsSupported(supported: *mut adlx_bool) -> ADLX_RESULT,
+            SetEnabled(enable: adlx_bool) -> ADLX_RESULT
+        });
+        let anti_lag1 = interface!(IADLX3DAntiLag1(IADLX3DAntiLag1Vtbl) {
+            IsSupported(supported: *mut adlx_bool) -> ADLX_RESULT,
+            SetEnabled(enable: adlx_bool) -> ADLX_RESULT,
+            GetLevel(level: *mut ADLX_ANTILAG_STATE) -> ADLX_RESULT
+        });
+        let boost = interface!(IADLX3DBoost(IADLX3DBoostVtbl) {
+            IsSupported(supported: *mut adlx_bool) -> ADLX_RESULT,
+            SetEnabled(enable: adlx_bool) -> ADLX_RESULT,
+            GetOrigin() -> ADLX_SYNC_ORIGIN,
+            SetName(name: *const wchar_t) -> ADLX_RESULT
+        });
+        let bindings = syn::parse2(quote::quote!(#anti_lag #anti_lag1 #boost)).unwrap();
+
+        let interfaces = ffi_interfaces(&bindings);
+        let by_name = interfaces
+            .iter()
+            .map(|i| (i.name.as_str(), i))
+            .collect::<BTreeMap<_, _>>();
+        let parent_name = |name| parent(by_name[name], &by_name).map(|p| p.name.as_str());
+        assert_eq!(parent_name("IADLX3DAntiLag1"), Some("IADLX3DAntiLag"));
+        // Starts with the same functions, but does not extend `IADLX3DAntiLag`
+        assert_eq!(parent_name("IADLX3DBoost"), None);
+
+        let mut hand_written = HandWritten::default();
+        hand_written
+            .wrappers
+            .insert("IADLX3DAntiLag".to_owned(), quote::quote!(ThreeDAntiLag));
+        let mut macro_calls = MacroCalls::default();
+        wrapper(
+            by_name["IADLX3DAntiLag1"],
+            &by_name,
+            &hand_written,
+            &mut macro_calls,
+        );
+        assert_eq!(
+            macro_calls.calls[0].1,
+            r#"adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_lag1/>
+    #[doc(alias = "IADLX3DAntiLag1")]
+    pub struct ThreeDAntiLag1: IADLX3DAntiLag1(IADLX3DAntiLag1Vtbl): ThreeDAntiLag {
+        const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 2);
+    }
+}"#
+        );
+        let (_, is_supported) = &by_name["IADLX3DBoost"].functions[3];
+        let Some(Method::Declaration(declaration)) =
+            method("IADLX3DBoost", "IsSupported", is_supported, &hand_written)
+        else {
+            panic!("IADLX3DBoost::IsSupported is not a getter");
+        };
+        assert!(declaration
+            .source
+            .ends_with("\nfn is_supported() -> bool = IsSupported;"));
+        let wrapped = by_name["IADLX3DBoost"]
+            .functions
+            .iter()
+            .filter(|(name, signature)| {
+                method("IADLX3DBoost", name, signature, &hand_written).is_some()
+            })
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(wrapped, ["IsSupported", "SetEnabled"]);
+    }
+
+    #[test]
+    #[should_panic = "IADLX3DAntiLag1::GetLevel would expose the C enum ADLX_ANTILAG_STATE"]
+    fn refuses_to_expose_c_enums() {
+        let anti_lag1 = interface!(IADLX3DAntiLag1(IADLX3DAntiLag1Vtbl) {
+            GetLevel(level: *mut ADLX_ANTILAG_STATE) -> ADLX_RESULT,
+            GetRange(range: *mut ADLX_IntRange) -> ADLX_RESULT
+        });
+        let bindings = syn::parse2(quote::quote! {
+            pub const ADLX_ANTILAG_STATE_ANTILAG: ADLX_ANTILAG_STATE = 0;
+            pub type ADLX_ANTILAG_STATE = ::std::os::raw::c_int;
+            pub type adlx_int = ::std::os::raw::c_int;
+            pub struct ADLX_IntRange {
+                pub minValue: adlx_int,
+                pub maxValue: adlx_int,
+                pub step: adlx_int,
+            }
+            #anti_lag1
+        })
+        .unwrap();
+
+        let hand_written = HandWritten {
+            ffi_enums: ffi_enums(&bindings),
+            ..Default::default()
+        };
+        assert_eq!(
+            hand_written.ffi_enums.iter().collect::<Vec<_>>(),
+            ["ADLX_ANTILAG_STATE"]
+        );
+        let interfaces = ffi_interfaces(&bindings);
+        let (_, get_range) = &interfaces[0].functions[4];
+        assert!(method("IADLX3DAntiLag1", "GetRange", get_range, &hand_written).is_some());
+        let (_, get_level) = &interfaces[0].functions[3];
+        method("IADLX3DAntiLag1", "GetLevel", get_level, &hand_written);
+    }
+
+    #[test]
+    fn names_match_hand_written_wrappers() {
+        assert_eq!(rust_name("IADLX3DChill"), "ThreeDChill");
+        assert_eq!(rust_name("IADLXGPUMetricsSupport"), "GpuMetricsSupport");
+        assert_eq!(rust_name("IADLXGPU2"), "Gpu2");
+        assert_eq!(rust_name("IADLXGPUsChangedHandling"), "GpusChangedHandling");
+        assert_eq!(snake_ident("GetFPSRange"), "get_fps_range");
+        assert_eq!(snake_ident("Type"), "type_");
+        assert_eq!(
+            snake_ident("Get3DSettingsChangedHandling"),
+            "get_3d_settings_changed_handling"
+        );
+        assert_eq!(snake_ident("Get3DLUT"), "get_3d_lut");
+        assert_eq!(snake_ident(argument_name("ppChill")), "chill");
+        assert_eq!(snake_ident(argument_name("pGPU")), "gpu");
+        assert_eq!(snake_ident(argument_name("percent")), "percent");
+    }
+
+    #[test]
+    fn links_to_the_manual() {
+        assert_eq!(
+            doc_url("IADLX3DChill", Some("GetFPSRange")),
+            " <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_chill__get_f_p_s_range/>"
+        );
+        assert_eq!(
+            doc_url("IADLXList", Some("Remove_Back")),
+            " <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_list__remove__back/>"
+        );
+        assert_eq!(
+            doc_url("IADLXGPUsChangedHandling", None),
+            " <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_us_changed_handling/>"
+        );
+    }
+}
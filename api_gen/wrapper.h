@@ -8,3 +8,13 @@
 #include <IDisplaySettings.h>
 #include <IDisplays.h>
 #include <ISystem2.h>
+#include <IApplications.h>
+
+// ISystem2.h only forward declares the C interface of the listener that `IADLXGPU2::StartPowerOff`
+// takes, so it is declared here for its vtable to be generated
+typedef struct IADLXGPUConnectChangedListenerVtbl
+{
+    adlx_bool (ADLX_STD_CALL *OnGPUConnectChanged)(IADLXGPUConnectChangedListener* pThis, IADLXGPU2* pGPU, adlx_bool isConnect);
+    adlx_bool (ADLX_STD_CALL *OnGPUConnectChangedError)(IADLXGPUConnectChangedListener* pThis, IADLXGPU2* pGPU, ADLX_RESULT error);
+} IADLXGPUConnectChangedListenerVtbl;
+struct IADLXGPUConnectChangedListener { const IADLXGPUConnectChangedListenerVtbl* pVtbl; };
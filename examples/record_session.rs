@@ -0,0 +1,25 @@
+//! Record the ADLX values of this machine to a session file, which can be replayed without AMD
+//! hardware through [`adlx::helper::AdlxHelper::replay()`]
+
+use adlx::{helper::AdlxHelper, session::Recorder};
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "adlx-session.json".to_owned());
+
+    let helper = AdlxHelper::new()?;
+    let mut recorder = Recorder::new(&helper);
+
+    for _ in 0..10 {
+        recorder.sample_metrics();
+        std::thread::sleep(std::time::Duration::from_millis(500));
+    }
+    recorder.record_metrics_history(0, 5000);
+
+    recorder.finish().save(&path)?;
+    println!("Recorded ADLX session to `{path}`");
+
+    Ok(())
+}
@@ -0,0 +1,343 @@
+//! Finding the ADLX [`Gpu`] that corresponds to an adapter picked through DXGI, Vulkan or wgpu.
+//!
+//! The other API describes its adapter with an [`AdapterQuery`], which is compared against the
+//! [`AdapterIdentity`] of every ADLX GPU. Both are plain values, so that matching can be tested
+//! without ADLX.
+
+use std::cmp::Reverse;
+
+use super::{
+    gpu::{Gpu, Gpu2, PciAddress},
+    gpu_info::Luid,
+    interface::Interface,
+    pci_ids::parse_hex,
+    result::Result,
+    system::System,
+};
+
+/// The identifying values of an adapter as reported by another graphics API.
+///
+/// Fields that the API does not report are left [`None`]. DXGI reports a LUID and vendor and
+/// device ids, Vulkan a LUID (`VK_KHR_external_memory_capabilities`) and PCI address
+/// (`VK_EXT_pci_bus_info`), and wgpu only vendor and device ids.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AdapterQuery {
+    pub luid: Option<Luid>,
+    pub pci_address: Option<PciAddress>,
+    pub vendor_id: Option<u16>,
+    pub device_id: Option<u16>,
+}
+
+impl AdapterQuery {
+    pub fn from_luid(luid: Luid) -> Self {
+        Self {
+            luid: Some(luid),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_pci_address(pci_address: PciAddress) -> Self {
+        Self {
+            pci_address: Some(pci_address),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_ids(vendor_id: u16, device_id: u16) -> Self {
+        Self {
+            vendor_id: Some(vendor_id),
+            device_id: Some(device_id),
+            ..Default::default()
+        }
+    }
+
+    /// How well `identity` matches this query, or [`None`] if any value known to both differs
+    /// or they have no value in common.
+    pub fn match_strength(&self, identity: &AdapterIdentity) -> Option<MatchStrength> {
+        fn compare<T: PartialEq>(query: Option<T>, identity: Option<T>) -> Option<bool> {
+            Some(query? == identity?)
+        }
+
+        let luid = compare(self.luid, identity.luid);
+        let pci_address = compare(self.pci_address, identity.pci_address);
+        let vendor_id = compare(self.vendor_id, identity.vendor_id);
+        let device_id = compare(self.device_id, identity.device_id);
+        if [luid, pci_address, vendor_id, device_id].contains(&Some(false)) {
+            return None;
+        }
+
+        if luid == Some(true) {
+            Some(MatchStrength::Luid)
+        } else if pci_address == Some(true) {
+            Some(MatchStrength::PciAddress)
+        } else if device_id == Some(true) {
+            Some(MatchStrength::DeviceId)
+        } else if vendor_id == Some(true) {
+            Some(MatchStrength::VendorId)
+        } else {
+            None
+        }
+    }
+
+    /// Matches `candidates` against this query, ordered from the strongest to the weakest match.
+    ///
+    /// Candidates that match equally well keep their order. More than one candidate with the
+    /// strongest [`MatchStrength`], for example two identical GPUs matched by their ids, means
+    /// that the match is ambiguous, see [`AdapterMatch::is_ambiguous`].
+    pub fn rank<T>(
+        &self,
+        candidates: impl IntoIterator<Item = (T, AdapterIdentity)>,
+    ) -> Vec<AdapterMatch<T>> {
+        let mut matches = candidates
+            .into_iter()
+            .filter_map(|(candidate, identity)| {
+                let strength = self.match_strength(&identity)?;
+                Some(AdapterMatch {
+                    candidate,
+                    identity,
+                    strength,
+                    is_ambiguous: false,
+                })
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|m| Reverse(m.strength));
+
+        for i in 0..matches.len() {
+            let strength = matches[i].strength;
+            matches[i].is_ambiguous = matches
+                .iter()
+                .enumerate()
+                .any(|(j, other)| i != j && other.strength == strength);
+        }
+        matches
+    }
+}
+
+/// Which value an [`AdapterQuery`] matched on, ordered from weakest to strongest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchStrength {
+    /// Only the vendor is the same
+    VendorId,
+    /// The same model of GPU, which is ambiguous in systems with multiple identical GPUs
+    DeviceId,
+    PciAddress,
+    /// The same adapter on a running system
+    Luid,
+}
+
+/// The identifying values of an ADLX [`Gpu`], to be matched against an [`AdapterQuery`].
+///
+/// Values are [`None`] when ADLX could not return them, like the LUID of a GPU that does not
+/// implement [`Gpu2`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AdapterIdentity {
+    pub luid: Option<Luid>,
+    pub pci_address: Option<PciAddress>,
+    pub vendor_id: Option<u16>,
+    pub device_id: Option<u16>,
+}
+
+impl AdapterIdentity {
+    /// Reads the values from [`Gpu2::luid()`], [`Gpu::pci_address_from_unique_id()`] and
+    /// [`Gpu::pci_ids()`]. When the PCI ids can not be cross-checked against the PNP string, the
+    /// vendor and device ids are parsed from [`Gpu::vendor_id()`] and [`Gpu::device_id()`] alone.
+    pub fn new(gpu: &Gpu) -> Self {
+        let pci_ids = gpu.pci_ids().ok();
+        let parse_hex = |id: Result<&str>| parse_hex(id.ok()?).ok();
+        Self {
+            luid: gpu
+                .cast::<Gpu2>()
+                .and_then(|gpu| gpu.luid())
+                .ok()
+                .map(Luid::from),
+            pci_address: gpu.pci_address_from_unique_id(),
+            vendor_id: pci_ids
+                .map(|ids| ids.vendor_id)
+                .or_else(|| parse_hex(gpu.vendor_id())),
+            device_id: pci_ids
+                .map(|ids| ids.device_id)
+                .or_else(|| parse_hex(gpu.device_id())),
+        }
+    }
+}
+
+impl Gpu {
+    /// Shorthand for [`AdapterIdentity::new()`]
+    pub fn adapter_identity(&self) -> AdapterIdentity {
+        AdapterIdentity::new(self)
+    }
+}
+
+/// A candidate that matched an [`AdapterQuery`], returned by [`AdapterQuery::rank()`].
+#[derive(Clone, Debug)]
+pub struct AdapterMatch<T> {
+    pub candidate: T,
+    pub identity: AdapterIdentity,
+    pub strength: MatchStrength,
+    /// Another candidate matched with the same [`MatchStrength`]
+    pub is_ambiguous: bool,
+}
+
+impl System {
+    /// Ranks all GPUs of [`Self::gpus()`] against `query` with [`AdapterQuery::rank()`].
+    pub fn match_gpus(&self, query: &AdapterQuery) -> Result<Vec<AdapterMatch<Gpu>>> {
+        let gpus = self.gpus()?;
+        Ok(query.rank(gpus.iter().map(|gpu| {
+            let identity = gpu.adapter_identity();
+            (gpu, identity)
+        })))
+    }
+
+    /// The GPU that matches `query` best, or [`None`] if no GPU matches. Use
+    /// [`Self::match_gpus()`] to detect ambiguous matches.
+    pub fn find_gpu(&self, query: &AdapterQuery) -> Result<Option<Gpu>> {
+        Ok(self
+            .match_gpus(query)?
+            .into_iter()
+            .next()
+            .map(|m| m.candidate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AdapterIdentity, AdapterQuery, MatchStrength};
+    use crate::{Luid, PciAddress};
+
+    fn identity(low_part: u32, bus: u32) -> AdapterIdentity {
+        AdapterIdentity {
+            luid: Some(Luid {
+                low_part,
+                high_part: 0,
+            }),
+            pci_address: Some(PciAddress {
+                bus,
+                device: 0,
+                function: 0,
+            }),
+            vendor_id: Some(0x1002),
+            device_id: Some(0x744c),
+        }
+    }
+
+    #[test]
+    fn ranks_fake_identities() {
+        let igpu = AdapterIdentity {
+            device_id: Some(0x164e),
+            ..identity(0x10, 0x10)
+        };
+        let candidates = [
+            ("igpu", igpu),
+            ("dgpu0", identity(0x20, 0x03)),
+            ("dgpu1", identity(0x30, 0x0c)),
+        ];
+
+        let by_luid = AdapterQuery {
+            vendor_id: Some(0x1002),
+            ..AdapterQuery::from_luid(Luid {
+                low_part: 0x30,
+                high_part: 0,
+            })
+        };
+        let ranked = by_luid.rank(candidates);
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].candidate, "dgpu1");
+        assert_eq!(ranked[0].strength, MatchStrength::Luid);
+        assert!(!ranked[0].is_ambiguous);
+
+        let by_ids = AdapterQuery::from_ids(0x1002, 0x744c).rank(candidates);
+        let names = by_ids.iter().map(|m| m.candidate).collect::<Vec<_>>();
+        assert_eq!(names, ["dgpu0", "dgpu1"]);
+        assert!(by_ids.iter().all(|m| m.is_ambiguous));
+
+        let by_vendor = AdapterQuery {
+            vendor_id: Some(0x1002),
+            ..Default::default()
+        };
+        assert_eq!(
+            by_vendor.match_strength(&candidates[0].1),
+            Some(MatchStrength::VendorId)
+        );
+        assert!(AdapterQuery::from_ids(0x10de, 0x2684)
+            .rank(candidates)
+            .is_empty());
+        assert_eq!(AdapterQuery::default().rank(candidates).len(), 0);
+    }
+
+    #[test]
+    fn converts_vulkan_luid() {
+        assert_eq!(
+            Luid::from_bytes([0x34, 0x12, 0, 0, 0xff, 0xff, 0xff, 0xff]),
+            Luid {
+                low_part: 0x1234,
+                high_part: -1
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_conflicting_values() {
+        // A matching PCI address does not help if the LUID differs
+        let query = AdapterQuery {
+            luid: Some(Luid {
+                low_part: 0x99,
+                high_part: 0,
+            }),
+            ..AdapterQuery::from_pci_address(PciAddress {
+                bus: 0x03,
+                device: 0,
+                function: 0,
+            })
+        };
+        assert_eq!(query.match_strength(&identity(0x20, 0x03)), None);
+
+        // Values unknown to ADLX are skipped
+        let without_luid = AdapterIdentity {
+            luid: None,
+            ..identity(0, 0x03)
+        };
+        assert_eq!(
+            query.match_strength(&without_luid),
+            Some(MatchStrength::PciAddress)
+        );
+    }
+
+    #[cfg(feature = "replay")]
+    #[test]
+    fn finds_replayed_gpu() {
+        use crate::{
+            ffi,
+            replay::tests::{replay_gpu, replay_gpu2},
+        };
+
+        let (helper, _) = replay_gpu2(|_| {});
+        let system = helper.system();
+
+        let query = AdapterQuery::from_luid(Luid {
+            low_part: 0x1234,
+            high_part: 0,
+        });
+        let gpu = system.find_gpu(&query).unwrap().unwrap();
+        assert_eq!(gpu.name().unwrap(), "AMD Radeon RX 7900 XTX");
+
+        let identity = gpu.adapter_identity();
+        assert_eq!(identity.vendor_id, Some(0x1002));
+        assert_eq!(identity.device_id, Some(0x744c));
+        assert_eq!(
+            system
+                .find_gpu(&AdapterQuery::from_ids(0x10de, 0x2684))
+                .unwrap()
+                .map(|gpu| gpu.adapter_identity()),
+            None
+        );
+
+        // Without a PNP string to cross-check against, the getters are parsed on their own
+        let (_helper, gpu) = replay_gpu(|gpu| {
+            gpu.pnp_string = Err(ffi::ADLX_RESULT_ADLX_NOT_SUPPORTED);
+            gpu.vendor_id = Ok("0x1002".into());
+        });
+        let identity = gpu.adapter_identity();
+        assert_eq!(identity.vendor_id, Some(0x1002));
+        assert_eq!(identity.device_id, Some(0x744c));
+    }
+}
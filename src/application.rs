@@ -0,0 +1,230 @@
+use std::{
+    mem::{ManuallyDrop, MaybeUninit},
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+};
+
+use super::{
+    enums::AppGpuDependency,
+    ffi,
+    gpu::Gpu2,
+    interface::{Interface, InterfaceImpl},
+    list::{list_item, AdlxList},
+    result::{Error, Result},
+    version::AdlxVersion,
+};
+
+/// Converts a NUL-terminated UTF-16 `wchar_t` string.
+///
+/// # Safety
+/// `s` must be a valid, NUL-terminated string.
+pub(crate) unsafe fn wide_str_to_string(s: *const ffi::wchar_t) -> String {
+    let mut len = 0;
+    while *s.add(len) != 0 {
+        len += 1;
+    }
+    String::from_utf16_lossy(std::slice::from_raw_parts(s, len))
+}
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_application/>
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc(alias = "IADLXApplication")]
+pub struct Application(InterfaceImpl);
+
+unsafe impl Interface for Application {
+    type Impl = ffi::IADLXApplication;
+    type Vtable = ffi::IADLXApplicationVtbl;
+    const IID: &'static str = "IADLXApplication";
+    const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 3);
+}
+
+impl Application {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_application__process_i_d/>
+    #[doc(alias = "ProcessID")]
+    pub fn process_id(&self) -> Result<u32> {
+        let mut pid = MaybeUninit::uninit();
+        let result = unsafe { (self.vtable().ProcessID.unwrap())(self.as_raw(), pid.as_mut_ptr()) };
+
+        Error::from_result_with_assume_init_on_success(result, pid)
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_application__full_path/>
+    #[doc(alias = "FullPath")]
+    pub fn full_path(&self) -> Result<String> {
+        let mut full_path = MaybeUninit::uninit();
+        let result =
+            unsafe { (self.vtable().FullPath.unwrap())(self.as_raw(), full_path.as_mut_ptr()) };
+
+        Error::from_result_with_assume_init_on_success(result, full_path)
+            .map(|full_path| unsafe { wide_str_to_string(full_path) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_application__name/>
+    #[doc(alias = "Name")]
+    pub fn name(&self) -> Result<String> {
+        let mut name = MaybeUninit::uninit();
+        let result = unsafe { (self.vtable().Name.unwrap())(self.as_raw(), name.as_mut_ptr()) };
+
+        Error::from_result_with_assume_init_on_success(result, name)
+            .map(|name| unsafe { wide_str_to_string(name) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_application__g_p_u_dependency_type/>
+    #[doc(alias = "GPUDependencyType")]
+    pub fn gpu_dependency_type(&self) -> Result<AppGpuDependency> {
+        let mut dependency = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GPUDependencyType.unwrap())(self.as_raw(), dependency.as_mut_ptr())
+        };
+
+        Error::from_result_with_assume_init_on_success(result, dependency)
+            .map(AppGpuDependency::from_raw)
+    }
+}
+
+list_item!(Application => IADLXApplicationList(IADLXApplicationListVtbl), At_ApplicationList, Add_Back_ApplicationList);
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_application_list/>
+#[doc(alias = "IADLXApplicationList")]
+#[doc(alias = "At_ApplicationList")]
+#[doc(alias = "Add_Back_ApplicationList")]
+pub type ApplicationList = AdlxList<Application>;
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_apps_list_changed_handling/>
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc(alias = "IADLXGPUAppsListChangedHandling")]
+pub struct GpuAppsListChangedHandling(InterfaceImpl);
+
+unsafe impl Interface for GpuAppsListChangedHandling {
+    type Impl = ffi::IADLXGPUAppsListChangedHandling;
+    type Vtable = ffi::IADLXGPUAppsListChangedHandlingVtbl;
+    const IID: &'static str = "IADLXGPUAppsListChangedHandling";
+    const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 3);
+}
+
+impl GpuAppsListChangedHandling {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_apps_list_changed_handling__add_g_p_u_apps_list_event_listener/>
+    ///
+    /// Calls `callback` with the GPU and its new list of applications whenever an application
+    /// starts or stops using a GPU, until the returned [`GpuAppsListEventListener`] is dropped.
+    /// `callback` is called on an ADLX thread, and panics inside of it are discarded.
+    #[doc(alias = "AddGPUAppsListEventListener")]
+    pub fn add_listener(
+        &self,
+        callback: impl FnMut(Gpu2, ApplicationList) + Send + 'static,
+    ) -> Result<GpuAppsListEventListener> {
+        let listener = Box::new(ListenerImpl {
+            base: ffi::IADLXGPUAppsListEventListener {
+                pVtbl: &LISTENER_VTBL,
+            },
+            callback: Mutex::new(Box::new(callback)),
+        });
+        let raw = <*const _>::cast_mut(&listener.base);
+        let result =
+            unsafe { (self.vtable().AddGPUAppsListEventListener.unwrap())(self.as_raw(), raw) };
+
+        Error::from_result(result).map(|()| GpuAppsListEventListener {
+            handling: self.clone(),
+            listener,
+        })
+    }
+}
+
+type Callback = Box<dyn FnMut(Gpu2, ApplicationList) + Send>;
+
+/// The `IADLXGPUAppsListEventListener` object handed to ADLX, which starts with its vtable.
+#[repr(C)]
+struct ListenerImpl {
+    base: ffi::IADLXGPUAppsListEventListener,
+    callback: Mutex<Callback>,
+}
+
+static LISTENER_VTBL: ffi::IADLXGPUAppsListEventListenerVtbl =
+    ffi::IADLXGPUAppsListEventListenerVtbl {
+        OnGPUAppsListChanged: Some(on_gpu_apps_list_changed),
+    };
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_apps_list_event_listener__on_g_p_u_apps_list_changed/>
+unsafe extern "C" fn on_gpu_apps_list_changed(
+    this: *mut ffi::IADLXGPUAppsListEventListener,
+    gpu: *mut ffi::IADLXGPU2,
+    applications: *mut ffi::IADLXApplicationList,
+) -> ffi::adlx_bool {
+    if !gpu.is_null() && !applications.is_null() {
+        let listener = &*this.cast::<ListenerImpl>();
+        // The arguments are borrowed from ADLX, take our own reference to hand them out
+        let gpu = ManuallyDrop::new(Gpu2::from_raw(gpu));
+        let applications = ManuallyDrop::new(ApplicationList::from_raw(applications));
+        let (gpu, applications) = (Gpu2::clone(&gpu), ApplicationList::clone(&applications));
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut callback = listener.callback.lock().unwrap_or_else(|e| e.into_inner());
+            callback(gpu, applications)
+        }));
+    }
+    // Let ADLX notify the other listeners too
+    true as _
+}
+
+/// A listener registered with [`GpuAppsListChangedHandling::add_listener()`], which is removed
+/// again when this is dropped.
+#[doc(alias = "IADLXGPUAppsListEventListener")]
+pub struct GpuAppsListEventListener {
+    handling: GpuAppsListChangedHandling,
+    listener: Box<ListenerImpl>,
+}
+
+impl std::fmt::Debug for GpuAppsListEventListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GpuAppsListEventListener")
+            .field("handling", &self.handling)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for GpuAppsListEventListener {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_apps_list_changed_handling__remove_g_p_u_apps_list_event_listener/>
+    #[doc(alias = "RemoveGPUAppsListEventListener")]
+    fn drop(&mut self) {
+        let raw = <*const _>::cast_mut(&self.listener.base);
+        let _result = unsafe {
+            (self
+                .handling
+                .vtable()
+                .RemoveGPUAppsListEventListener
+                .unwrap())(self.handling.as_raw(), raw)
+        };
+    }
+}
+
+#[cfg(all(test, feature = "replay"))]
+mod tests {
+    use crate::{ffi, replay::tests::replay_gpu2, session::ApplicationRecord, AppGpuDependency};
+
+    #[test]
+    fn lists_applications() {
+        let (_helper, gpu) = replay_gpu2(|gpu| {
+            gpu.applications = Ok(vec![ApplicationRecord {
+                process_id: Ok(4242),
+                name: Ok("Blender™.exe".into()),
+                full_path: Ok("C:\\Program Files\\Blender\\Blender™.exe".into()),
+                gpu_dependency_type: Ok(ffi::ADLX_APP_GPU_DEPENDENCY_APP_GPU_BOUND),
+            }]);
+        });
+        let applications = gpu.get_applications().unwrap();
+
+        assert_eq!(applications.size(), 1);
+        let application = applications.iter().next().unwrap();
+        assert_eq!(application.process_id(), Ok(4242));
+        assert_eq!(application.name().unwrap(), "Blender™.exe");
+        assert_eq!(
+            application.full_path().unwrap(),
+            "C:\\Program Files\\Blender\\Blender™.exe"
+        );
+        assert_eq!(
+            application.gpu_dependency_type(),
+            Ok(AppGpuDependency::Bound)
+        );
+    }
+}
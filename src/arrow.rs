@@ -0,0 +1,217 @@
+//! Conversion of metrics into Apache Arrow [`RecordBatch`]es and Parquet files.
+//!
+//! Every metric becomes a nullable column, with nulls for samples where the metric could not be
+//! retrieved. Parquet files written by [`GpuMetricsParquetWriter`] carry the identity of the GPU
+//! in their key-value metadata, under `adlx.gpu.*` keys.
+
+use std::{collections::HashMap, io::Write, sync::Arc};
+
+use anyhow::Context;
+use arrow_array::{ArrayRef, Float64Array, Int32Array, Int64Array, RecordBatch};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+
+use super::{
+    fps::{FpsList, FpsSample},
+    gpu::Gpu,
+    gpu_metrics::{GpuMetricsList, GpuMetricsSample},
+    result::Result,
+};
+
+fn field(name: &str, data_type: DataType, nullable: bool, unit: &str) -> Field {
+    Field::new(name, data_type, nullable)
+        .with_metadata(HashMap::from([("unit".to_owned(), unit.to_owned())]))
+}
+
+/// Schema of [`gpu_metrics_record_batch()`], with the unit of every column in the `unit` field
+/// metadata.
+pub fn gpu_metrics_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        field("time_stamp", DataType::Int64, false, "ms"),
+        field("usage", DataType::Float64, true, "%"),
+        field("clock_speed", DataType::Int32, true, "MHz"),
+        field("vram_clock_speed", DataType::Int32, true, "MHz"),
+        field("power", DataType::Float64, true, "W"),
+        field("total_board_power", DataType::Float64, true, "W"),
+        field("voltage", DataType::Int32, true, "mV"),
+        field("vram", DataType::Int32, true, "MB"),
+        field("fan_speed", DataType::Int32, true, "RPM"),
+        field("temperature", DataType::Float64, true, "°C"),
+        field("intake_temperature", DataType::Float64, true, "°C"),
+        field("hotspot_temperature", DataType::Float64, true, "°C"),
+    ]))
+}
+
+/// Converts `samples` into a [`RecordBatch`] with [`gpu_metrics_schema()`].
+pub fn gpu_metrics_record_batch(samples: &[GpuMetricsSample]) -> RecordBatch {
+    fn float(
+        samples: &[GpuMetricsSample],
+        f: impl Fn(&GpuMetricsSample) -> Option<f64>,
+    ) -> ArrayRef {
+        Arc::new(samples.iter().map(f).collect::<Float64Array>())
+    }
+    fn int(samples: &[GpuMetricsSample], f: impl Fn(&GpuMetricsSample) -> Option<i32>) -> ArrayRef {
+        Arc::new(samples.iter().map(f).collect::<Int32Array>())
+    }
+
+    let columns = vec![
+        Arc::new(Int64Array::from_iter_values(
+            samples.iter().map(|s| s.time_stamp),
+        )) as ArrayRef,
+        float(samples, |s| s.usage),
+        int(samples, |s| s.clock_speed),
+        int(samples, |s| s.vram_clock_speed),
+        float(samples, |s| s.power),
+        float(samples, |s| s.total_board_power),
+        int(samples, |s| s.voltage),
+        int(samples, |s| s.vram),
+        int(samples, |s| s.fan_speed),
+        float(samples, |s| s.temperature),
+        float(samples, |s| s.intake_temperature),
+        float(samples, |s| s.hotspot_temperature),
+    ];
+    RecordBatch::try_new(gpu_metrics_schema(), columns)
+        .expect("Columns must match gpu_metrics_schema()")
+}
+
+/// Schema of [`fps_record_batch()`]
+pub fn fps_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        field("time_stamp", DataType::Int64, false, "ms"),
+        field("fps", DataType::Int32, false, "FPS"),
+    ]))
+}
+
+/// Converts `samples` into a [`RecordBatch`] with [`fps_schema()`].
+pub fn fps_record_batch(samples: &[FpsSample]) -> RecordBatch {
+    let columns = vec![
+        Arc::new(Int64Array::from_iter_values(
+            samples.iter().map(|s| s.time_stamp),
+        )) as ArrayRef,
+        Arc::new(Int32Array::from_iter_values(samples.iter().map(|s| s.fps))) as ArrayRef,
+    ];
+    RecordBatch::try_new(fps_schema(), columns).expect("Columns must match fps_schema()")
+}
+
+impl GpuMetricsList {
+    /// Converts every element in this list with [`gpu_metrics_record_batch()`].
+    pub fn to_record_batch(&self) -> Result<RecordBatch> {
+        self.samples()
+            .map(|samples| gpu_metrics_record_batch(&samples))
+    }
+}
+
+impl FpsList {
+    /// Converts every element in this list with [`fps_record_batch()`].
+    pub fn to_record_batch(&self) -> Result<RecordBatch> {
+        self.samples().map(|samples| fps_record_batch(&samples))
+    }
+}
+
+/// Identity of `gpu` as `adlx.gpu.*` key-value pairs. Properties that cannot be retrieved are
+/// left out.
+pub fn gpu_identity_metadata(gpu: &Gpu) -> HashMap<String, String> {
+    let properties = [
+        ("name", gpu.name().map(str::to_owned)),
+        ("vendor_id", gpu.vendor_id().map(str::to_owned)),
+        ("device_id", gpu.device_id().map(str::to_owned)),
+        ("revision_id", gpu.revision_id().map(str::to_owned)),
+        ("sub_system_id", gpu.sub_system_id().map(str::to_owned)),
+        (
+            "sub_system_vendor_id",
+            gpu.sub_system_vendor_id().map(str::to_owned),
+        ),
+        ("unique_id", gpu.unique_id().map(|id| id.to_string())),
+        ("pnp_string", gpu.pnp_string().map(str::to_owned)),
+        ("driver_path", gpu.driver_path().map(str::to_owned)),
+        ("total_vram", gpu.total_vram().map(|vram| vram.to_string())),
+        ("vram_type", gpu.vram_type().map(str::to_owned)),
+    ];
+    properties
+        .into_iter()
+        .filter_map(|(key, value)| Some((format!("adlx.gpu.{key}"), value.ok()?)))
+        .collect()
+}
+
+/// Streams [`GpuMetricsSample`]s of a single GPU into a Snappy-compressed Parquet file, for
+/// example from a [`MetricsSampler`] that is [cleared] after every write.
+///
+/// [`MetricsSampler`]: super::MetricsSampler
+/// [cleared]: super::MetricsSampler::clear()
+pub struct GpuMetricsParquetWriter<W: Write + Send> {
+    writer: ArrowWriter<W>,
+    schema: SchemaRef,
+}
+
+impl<W: Write + Send> GpuMetricsParquetWriter<W> {
+    /// Starts a Parquet file with the [`gpu_identity_metadata()`] of `gpu`.
+    pub fn new(writer: W, gpu: &Gpu) -> anyhow::Result<Self> {
+        let schema = gpu_metrics_schema();
+        let schema = Arc::new(Schema::new_with_metadata(
+            schema.fields().clone(),
+            gpu_identity_metadata(gpu),
+        ));
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let writer = ArrowWriter::try_new(writer, schema.clone(), Some(properties))
+            .context("Failed to create Parquet writer")?;
+        Ok(Self { writer, schema })
+    }
+
+    pub fn write(&mut self, samples: &[GpuMetricsSample]) -> anyhow::Result<()> {
+        // Attach the identity metadata of the writer's schema
+        let batch = gpu_metrics_record_batch(samples)
+            .with_schema(self.schema.clone())
+            .context("Failed to attach GPU metadata")?;
+        self.writer
+            .write(&batch)
+            .context("Failed to write GPU metrics to Parquet")
+    }
+
+    /// Writes the Parquet footer and returns the underlying writer.
+    pub fn finish(self) -> anyhow::Result<W> {
+        self.writer
+            .into_inner()
+            .context("Failed to finish Parquet file")
+    }
+}
+
+#[cfg(all(test, feature = "replay"))]
+mod tests {
+    use bytes::Bytes;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use super::GpuMetricsParquetWriter;
+    use crate::{replay::tests::session, AdlxHelper, GpuMetricsSample};
+
+    #[test]
+    fn parquet_round_trip() {
+        let helper = AdlxHelper::replay(session());
+        let gpu = helper.system().gpus().unwrap().at(0).unwrap();
+
+        let mut writer = GpuMetricsParquetWriter::new(vec![], &gpu).unwrap();
+        writer
+            .write(&[
+                GpuMetricsSample {
+                    time_stamp: 0,
+                    clock_speed: Some(2100),
+                    ..Default::default()
+                },
+                GpuMetricsSample {
+                    time_stamp: 500,
+                    ..Default::default()
+                },
+            ])
+            .unwrap();
+        let file = Bytes::from(writer.finish().unwrap());
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+        let metadata = reader.schema().metadata();
+        assert_eq!(metadata["adlx.gpu.name"], gpu.name().unwrap());
+        let batch = reader.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.column_by_name("clock_speed").unwrap().null_count(), 1);
+        assert_eq!(batch.column_by_name("usage").unwrap().null_count(), 2);
+    }
+}
@@ -0,0 +1,144 @@
+use std::{fmt, str::FromStr};
+
+use super::driver_version::version_type;
+
+/// Video BIOS information returned by [`Gpu::bios_info()`].
+///
+/// [`Gpu::bios_info()`]: super::Gpu::bios_info()
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BiosInfo {
+    /// Part number of the board, like `113-D4120100-100`
+    pub part_number: String,
+    pub version: BiosVersion,
+    /// Build date, or [`None`] if ADLX returned it in an unknown format
+    pub date: Option<BiosDate>,
+    /// Build date as returned by ADLX, like `2022/11/09 21:40`
+    pub raw_date: String,
+}
+
+impl BiosInfo {
+    pub fn new(part_number: &str, version: &str, date: &str) -> Self {
+        Self {
+            part_number: part_number.to_owned(),
+            version: version.into(),
+            date: date.parse().ok(),
+            raw_date: date.to_owned(),
+        }
+    }
+}
+
+version_type! {
+    /// A video BIOS version like `022.001.002.008.000001` returned by [`Gpu::bios_info()`].
+    ///
+    /// [`Gpu::bios_info()`]: super::Gpu::bios_info()
+    pub struct BiosVersion;
+}
+
+/// Build date of a video BIOS, with minute precision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BiosDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+}
+
+/// The date passed to [`BiosDate::from_str()`] is not formatted as `YYYY/MM/DD[ HH:MM]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseBiosDateError(String);
+
+impl fmt::Display for ParseBiosDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid BIOS date `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseBiosDateError {}
+
+impl FromStr for BiosDate {
+    type Err = ParseBiosDateError;
+
+    /// Parses `YYYY/MM/DD HH:MM`, where the time is optional and the date may also be separated
+    /// by `-`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseBiosDateError(s.to_owned());
+        let (date, time) = s.trim().split_once(' ').unwrap_or((s.trim(), "00:00"));
+
+        let mut date = date.split(['/', '-']).map(str::parse::<u16>);
+        let (Some(Ok(year)), Some(Ok(month)), Some(Ok(day)), None) =
+            (date.next(), date.next(), date.next(), date.next())
+        else {
+            return Err(err());
+        };
+        let (hour, minute) = time.trim().split_once(':').ok_or_else(err)?;
+        let (Ok(hour), Ok(minute)) = (hour.parse::<u8>(), minute.parse::<u8>()) else {
+            return Err(err());
+        };
+
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+            return Err(err());
+        }
+        Ok(Self {
+            year,
+            month: month as u8,
+            day: day as u8,
+            hour,
+            minute,
+        })
+    }
+}
+
+impl fmt::Display for BiosDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}/{:02}/{:02} {:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BiosDate, BiosInfo, BiosVersion};
+
+    #[test]
+    fn parses_bios_info() {
+        let info = BiosInfo::new(
+            "113-D4120100-100",
+            "022.001.002.008.000001",
+            "2022/11/09 21:40",
+        );
+        assert_eq!(info.version.components(), [22, 1, 2, 8, 1]);
+        assert_eq!(
+            info.date,
+            Some(BiosDate {
+                year: 2022,
+                month: 11,
+                day: 9,
+                hour: 21,
+                minute: 40,
+            })
+        );
+        assert_eq!(info.date.unwrap().to_string(), info.raw_date);
+
+        assert_eq!(BiosInfo::new("", "", "unknown").date, None);
+        assert!("2022-11-09".parse::<BiosDate>().is_ok());
+        assert!("2022/13/09".parse::<BiosDate>().is_err());
+    }
+
+    #[test]
+    fn orders_versions_numerically() {
+        let old = BiosVersion::from("022.001.002.008.000001");
+        let new = BiosVersion::from("022.001.002.010.000001");
+        assert!(old < new);
+        assert!(BiosVersion::from("9.0") < BiosVersion::from("10.0"));
+        // Like driver versions, leading zeros and missing trailing components do not matter
+        assert_eq!(
+            BiosVersion::from("022.001.002"),
+            BiosVersion::from("22.1.2.0")
+        );
+        assert_eq!(new.to_string(), "022.001.002.010.000001");
+    }
+}
@@ -0,0 +1,395 @@
+//! A report of every interface and feature that the installed driver supports, returned by
+//! [`System::capabilities()`].
+
+use std::{collections::BTreeMap, fmt};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    display::Display,
+    ffi,
+    gpu::{Gpu, Gpu1, Gpu2},
+    gpu_metrics::GpuMetricsSupport,
+    interface::{unknown_interface_as_none, Interface},
+    performance_monitoring_services::PerformanceMonitoringServices,
+    result::{Error, Result},
+    system::{System, System1, System2},
+    three_d_settings_services::ThreeDSettingsServices,
+};
+
+/// The outcome of probing a single interface or feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Probe {
+    Supported,
+    /// ADLX reported that the interface or feature is not available
+    Unsupported,
+    /// ADLX returned an unexpected [`ffi::ADLX_RESULT`] while probing
+    Failed(ffi::ADLX_RESULT),
+}
+
+impl Probe {
+    pub fn is_supported(&self) -> bool {
+        matches!(self, Self::Supported)
+    }
+
+    fn from_error(e: Error) -> Self {
+        match e.adlx_result() {
+            ffi::ADLX_RESULT_ADLX_NOT_SUPPORTED | ffi::ADLX_RESULT_ADLX_UNKNOWN_INTERFACE => {
+                Self::Unsupported
+            }
+            result => Self::Failed(result),
+        }
+    }
+
+    /// Probes an `IsSupported*()` call.
+    fn from_is_supported(result: Result<bool>) -> Self {
+        match result {
+            Ok(true) => Self::Supported,
+            Ok(false) => Self::Unsupported,
+            Err(e) => Self::from_error(e),
+        }
+    }
+
+    /// Probes a `QueryInterface` or `Get*` call, returning the interface if it is supported.
+    fn from_interface<I>(result: Result<I>) -> (Self, Option<I>) {
+        match unknown_interface_as_none(result) {
+            Ok(Some(interface)) => (Self::Supported, Some(interface)),
+            Ok(None) => (Self::Unsupported, None),
+            Err(e) => (Self::from_error(e), None),
+        }
+    }
+}
+
+impl fmt::Display for Probe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Supported => f.write_str("Supported"),
+            Self::Unsupported => f.write_str("Unsupported"),
+            Self::Failed(result) => write!(f, "Failed: {}", Error::new(*result)),
+        }
+    }
+}
+
+/// Probe outcomes keyed by the ADLX name of the interface or `IsSupported*` function, like
+/// `IADLXGPU2` or `IsSupportedGPUUsage`.
+pub type Probes = BTreeMap<String, Probe>;
+
+/// What the installed driver and hardware support, returned by [`System::capabilities()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Capabilities {
+    /// Extensions of [`System`] and the services it returns
+    pub system: Probes,
+    pub gpus: Vec<GpuCapabilities>,
+    pub displays: Vec<DisplayCapabilities>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GpuCapabilities {
+    pub name: Option<String>,
+    /// [`Gpu::unique_id()`], to relate displays to their GPU
+    pub unique_id: Option<i32>,
+    /// Interface versions, 3D settings, application lists and GPU metrics
+    pub features: Probes,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DisplayCapabilities {
+    /// [`Gpu::unique_id()`] of the GPU driving this display
+    pub gpu_unique_id: Option<i32>,
+    pub features: Probes,
+}
+
+fn insert(probes: &mut Probes, name: &str, probe: Probe) {
+    probes.insert(name.to_owned(), probe);
+}
+
+/// The features of a GPU or display that its list failed to return, which only hold the error of
+/// `at_function`, like `At_GPUList`.
+fn failed_item(at_function: &str, e: Error) -> Probes {
+    let mut features = Probes::new();
+    insert(&mut features, at_function, Probe::from_error(e));
+    features
+}
+
+/// Probes an interface with an `IsSupported()` function, like `IADLX3DChill`.
+fn insert_feature<I>(
+    probes: &mut Probes,
+    name: &str,
+    interface: Result<I>,
+    is_supported: impl FnOnce(&I) -> Result<bool>,
+) {
+    let probe = match Probe::from_interface(interface) {
+        (_, Some(interface)) => Probe::from_is_supported(is_supported(&interface)),
+        (probe, None) => probe,
+    };
+    insert(probes, name, probe);
+}
+
+type IsSupportedMetric = fn(&GpuMetricsSupport) -> Result<bool>;
+
+fn probe_gpu(
+    gpu: &Gpu,
+    three_d_settings: Option<&ThreeDSettingsServices>,
+    performance_monitoring: Option<&PerformanceMonitoringServices>,
+) -> GpuCapabilities {
+    let mut features = Probes::new();
+
+    let (probe, _) = Probe::from_interface(gpu.cast::<Gpu1>());
+    insert(&mut features, Gpu1::IID, probe);
+    let (probe, gpu2) = Probe::from_interface(gpu.cast::<Gpu2>());
+    insert(&mut features, Gpu2::IID, probe);
+    if let Some(gpu2) = gpu2 {
+        insert(
+            &mut features,
+            "IsSupportedApplicationList",
+            Probe::from_is_supported(gpu2.is_supported_application_list()),
+        );
+    }
+
+    if let Some(services) = three_d_settings {
+        insert_feature(
+            &mut features,
+            "IADLX3DWaitForVerticalRefresh",
+            services.get_wait_for_vertical_refresh(gpu),
+            |i| i.is_supported(),
+        );
+        insert_feature(
+            &mut features,
+            "IADLX3DChill",
+            services.get_chill(gpu),
+            |i| i.is_supported(),
+        );
+        insert_feature(
+            &mut features,
+            "IADLX3DFrameRateTargetControl",
+            services.get_frame_rate_target_control(gpu),
+            |i| i.is_supported(),
+        );
+    }
+
+    if let Some(services) = performance_monitoring {
+        let (probe, support) = Probe::from_interface(services.supported_gpu_metrics(gpu));
+        insert(&mut features, GpuMetricsSupport::IID, probe);
+        if let Some(support) = support {
+            let metrics: [(&str, IsSupportedMetric); 11] = [
+                (
+                    "IsSupportedGPUUsage",
+                    GpuMetricsSupport::is_supported_gpu_usage,
+                ),
+                (
+                    "IsSupportedGPUClockSpeed",
+                    GpuMetricsSupport::is_supported_gpu_clock_speed,
+                ),
+                (
+                    "IsSupportedGPUVRAMClockSpeed",
+                    GpuMetricsSupport::is_supported_gpu_vram_clock_speed,
+                ),
+                (
+                    "IsSupportedGPUTemperature",
+                    GpuMetricsSupport::is_supported_gpu_temperature,
+                ),
+                (
+                    "IsSupportedGPUHotspotTemperature",
+                    GpuMetricsSupport::is_supported_gpu_hotspot_temperature,
+                ),
+                (
+                    "IsSupportedGPUPower",
+                    GpuMetricsSupport::is_supported_gpu_power,
+                ),
+                (
+                    "IsSupportedGPUTotalBoardPower",
+                    GpuMetricsSupport::is_supported_gpu_total_board_power,
+                ),
+                (
+                    "IsSupportedGPUFanSpeed",
+                    GpuMetricsSupport::is_supported_gpu_fan_speed,
+                ),
+                (
+                    "IsSupportedGPUVRAM",
+                    GpuMetricsSupport::is_supported_gpu_vram,
+                ),
+                (
+                    "IsSupportedGPUVoltage",
+                    GpuMetricsSupport::is_supported_gpu_voltage,
+                ),
+                (
+                    "IsSupportedGPUIntakeTemperature",
+                    GpuMetricsSupport::is_supported_gpu_intake_temperature,
+                ),
+            ];
+            for (name, is_supported) in metrics {
+                insert(
+                    &mut features,
+                    name,
+                    Probe::from_is_supported(is_supported(&support)),
+                );
+            }
+        }
+    }
+
+    GpuCapabilities {
+        name: gpu.name().ok().map(str::to_owned),
+        unique_id: gpu.unique_id().ok(),
+        features,
+    }
+}
+
+fn probe_display(display: &Display, free_sync: Result<bool>) -> DisplayCapabilities {
+    let mut features = Probes::new();
+    insert(
+        &mut features,
+        "IADLXDisplayFreeSync",
+        Probe::from_is_supported(free_sync),
+    );
+    DisplayCapabilities {
+        gpu_unique_id: display.get_gpu().and_then(|gpu| gpu.unique_id()).ok(),
+        features,
+    }
+}
+
+impl Capabilities {
+    /// Probes everything this crate wraps. Failures are recorded in the report instead of
+    /// returned, so that a single broken feature does not hide the others. A GPU or display that
+    /// ADLX fails to return is listed with only the failed `At_GPUList` or `At_DisplayList` probe.
+    pub fn probe(system: &System) -> Self {
+        let mut probes = Probes::new();
+
+        let (probe, _) = Probe::from_interface(system.cast::<System1>());
+        insert(&mut probes, System1::IID, probe);
+        let (probe, _) = Probe::from_interface(system.cast::<System2>());
+        insert(&mut probes, System2::IID, probe);
+
+        let (probe, display_services) = Probe::from_interface(system.get_displays_services());
+        insert(&mut probes, "IADLXDisplayServices", probe);
+        let (probe, three_d_settings) = Probe::from_interface(system.get_3d_settings_services());
+        insert(&mut probes, "IADLX3DSettingsServices", probe);
+        let (probe, performance_monitoring) =
+            Probe::from_interface(system.performance_monitoring_services());
+        insert(&mut probes, "IADLXPerformanceMonitoringServices", probe);
+        let (probe, _) = Probe::from_interface(
+            system
+                .cast::<System2>()
+                .and_then(|system| system.get_gpu_apps_list_changed_handling()),
+        );
+        insert(&mut probes, "IADLXGPUAppsListChangedHandling", probe);
+
+        let gpus = match system.gpus() {
+            Ok(gpus) => gpus
+                .try_iter()
+                .map(|gpu| match gpu {
+                    Ok(gpu) => probe_gpu(
+                        &gpu,
+                        three_d_settings.as_ref(),
+                        performance_monitoring.as_ref(),
+                    ),
+                    Err(e) => GpuCapabilities {
+                        features: failed_item("At_GPUList", e),
+                        ..Default::default()
+                    },
+                })
+                .collect(),
+            Err(e) => {
+                insert(&mut probes, "GetGPUs", Probe::from_error(e));
+                vec![]
+            }
+        };
+
+        let displays = match display_services.as_ref().map(|s| s.get_displays()) {
+            Some(Ok(displays)) => displays
+                .try_iter()
+                .map(|display| match display {
+                    Ok(display) => {
+                        let services = display_services.as_ref().unwrap();
+                        let free_sync = services
+                            .get_free_sync(&display)
+                            .and_then(|free_sync| free_sync.is_supported());
+                        probe_display(&display, free_sync)
+                    }
+                    Err(e) => DisplayCapabilities {
+                        features: failed_item("At_DisplayList", e),
+                        ..Default::default()
+                    },
+                })
+                .collect(),
+            Some(Err(e)) => {
+                insert(&mut probes, "GetDisplays", Probe::from_error(e));
+                vec![]
+            }
+            None => vec![],
+        };
+
+        Self {
+            system: probes,
+            gpus,
+            displays,
+        }
+    }
+}
+
+impl System {
+    /// Shorthand for [`Capabilities::probe()`]
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::probe(self)
+    }
+}
+
+#[cfg(all(test, feature = "replay"))]
+mod tests {
+    use super::{failed_item, Probe};
+    use crate::{ffi, replay::tests::session, AdlxHelper, Error};
+
+    #[test]
+    fn probes_replayed_system() {
+        let helper = AdlxHelper::replay(session());
+        let capabilities = helper.system().capabilities();
+
+        // Replays do not implement the System extensions
+        assert_eq!(capabilities.system["IADLXSystem2"], Probe::Unsupported);
+        assert_eq!(
+            capabilities.system["IADLXPerformanceMonitoringServices"],
+            Probe::Supported
+        );
+        assert_eq!(
+            capabilities.system["IADLXGPUAppsListChangedHandling"],
+            Probe::Unsupported
+        );
+
+        let gpu = &capabilities.gpus[0];
+        assert_eq!(gpu.name.as_deref(), Some("AMD Radeon RX 7900 XTX"));
+        assert_eq!(gpu.features["IADLXGPU1"], Probe::Supported);
+        assert_eq!(gpu.features["IADLXGPU2"], Probe::Unsupported);
+        assert!(gpu.features.contains_key("IsSupportedGPUUsage"));
+        assert!(!gpu.features.contains_key("IsSupportedApplicationList"));
+
+        let display = &capabilities.displays[0];
+        assert_eq!(display.gpu_unique_id, gpu.unique_id);
+        assert_eq!(display.features["IADLXDisplayFreeSync"], Probe::Supported);
+
+        let json = serde_json::to_string(&capabilities).unwrap();
+        assert_eq!(
+            serde_json::from_str::<super::Capabilities>(&json).unwrap(),
+            capabilities
+        );
+        assert_eq!(
+            Probe::Failed(ffi::ADLX_RESULT_ADLX_FAIL).to_string(),
+            "Failed: This result indicates an unspecified failure"
+        );
+    }
+
+    #[test]
+    fn records_items_that_fail_to_return() {
+        let features = failed_item("At_GPUList", Error::new(ffi::ADLX_RESULT_ADLX_FAIL));
+        assert_eq!(
+            features.into_iter().collect::<Vec<_>>(),
+            [(
+                "At_GPUList".to_owned(),
+                Probe::Failed(ffi::ADLX_RESULT_ADLX_FAIL)
+            )]
+        );
+    }
+}
@@ -0,0 +1,376 @@
+//! Rendering of metrics timelines to standalone SVG line charts, for example to embed in HTML
+//! reports.
+//!
+//! ```no_run
+//! # fn main() -> anyhow::Result<()> {
+//! # let helper = adlx::AdlxHelper::new()?;
+//! use adlx::{Chart, SvgChart};
+//!
+//! let services = helper.system().performance_monitoring_services()?;
+//! let gpu = helper.system().gpus()?.at(0)?;
+//! let support = services.supported_gpu_metrics(&gpu)?;
+//! let history = services.gpu_metrics_history(&gpu, 60_000, 0)?.samples()?;
+//!
+//! for chart in Chart::ALL {
+//!     SvgChart::new(chart)
+//!         .with_support(&support)
+//!         .save(&history, format!("{chart:?}.svg"))?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Write as _, fs, io, ops::RangeInclusive, path::Path};
+
+use super::{
+    gpu_metrics::{GpuMetricsSample, GpuMetricsSupport},
+    result::Result,
+};
+
+/// A metric group that is drawn into a single chart, with one line per series.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Chart {
+    ClockSpeed,
+    Power,
+    Temperature,
+    Usage,
+}
+
+struct Series {
+    name: &'static str,
+    color: &'static str,
+    value: fn(&GpuMetricsSample) -> Option<f64>,
+    range: fn(&GpuMetricsSupport) -> Result<RangeInclusive<i32>>,
+}
+
+impl Chart {
+    pub const ALL: [Self; 4] = [
+        Self::ClockSpeed,
+        Self::Power,
+        Self::Temperature,
+        Self::Usage,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::ClockSpeed => "Clock speed",
+            Self::Power => "Power",
+            Self::Temperature => "Temperature",
+            Self::Usage => "Usage",
+        }
+    }
+
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::ClockSpeed => "MHz",
+            Self::Power => "W",
+            Self::Temperature => "°C",
+            Self::Usage => "%",
+        }
+    }
+
+    fn series(self) -> &'static [Series] {
+        match self {
+            Self::ClockSpeed => &[
+                Series {
+                    name: "GPU",
+                    color: "#e4002b",
+                    value: |s| s.clock_speed.map(f64::from),
+                    range: GpuMetricsSupport::gpu_clock_speed_range,
+                },
+                Series {
+                    name: "VRAM",
+                    color: "#0072c6",
+                    value: |s| s.vram_clock_speed.map(f64::from),
+                    range: GpuMetricsSupport::gpu_vram_clock_speed_range,
+                },
+            ],
+            Self::Power => &[
+                Series {
+                    name: "GPU",
+                    color: "#e4002b",
+                    value: |s| s.power,
+                    range: GpuMetricsSupport::gpu_power_range,
+                },
+                Series {
+                    name: "Total board",
+                    color: "#0072c6",
+                    value: |s| s.total_board_power,
+                    range: GpuMetricsSupport::gpu_total_board_power_range,
+                },
+            ],
+            Self::Temperature => &[
+                Series {
+                    name: "Edge",
+                    color: "#e4002b",
+                    value: |s| s.temperature,
+                    range: GpuMetricsSupport::gpu_temperature_range,
+                },
+                Series {
+                    name: "Hotspot",
+                    color: "#ff8c00",
+                    value: |s| s.hotspot_temperature,
+                    range: GpuMetricsSupport::gpu_hotspot_temperature_range,
+                },
+                Series {
+                    name: "Intake",
+                    color: "#0072c6",
+                    value: |s| s.intake_temperature,
+                    range: GpuMetricsSupport::gpu_intake_temperature_range,
+                },
+            ],
+            Self::Usage => &[Series {
+                name: "GPU",
+                color: "#e4002b",
+                value: |s| s.usage,
+                range: GpuMetricsSupport::gpu_usage_range,
+            }],
+        }
+    }
+
+    /// The union of the ranges reported by `support` for every metric in this chart, or [`None`]
+    /// if none of them are supported.
+    pub fn range(self, support: &GpuMetricsSupport) -> Option<RangeInclusive<f64>> {
+        self.series()
+            .iter()
+            .filter_map(|series| (series.range)(support).ok())
+            .map(|range| f64::from(*range.start())..=f64::from(*range.end()))
+            .reduce(|a, b| a.start().min(*b.start())..=a.end().max(*b.end()))
+    }
+}
+
+const MARGIN_LEFT: f64 = 64.0;
+const MARGIN_RIGHT: f64 = 16.0;
+const MARGIN_TOP: f64 = 32.0;
+const MARGIN_BOTTOM: f64 = 40.0;
+const TICKS: u32 = 5;
+
+/// Renders a [`Chart`] of [`GpuMetricsSample`]s to SVG.
+///
+/// The time axis shows seconds since the first sample. The value axis spans the range set with
+/// [`SvgChart::with_support()`] or [`SvgChart::with_range()`], extended to fit every sample, or
+/// only the samples if no range is set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgChart {
+    chart: Chart,
+    width: u32,
+    height: u32,
+    range: Option<RangeInclusive<f64>>,
+}
+
+impl SvgChart {
+    pub fn new(chart: Chart) -> Self {
+        Self {
+            chart,
+            width: 800,
+            height: 300,
+            range: None,
+        }
+    }
+
+    /// Sets the size of the SVG document in pixels, 800x300 by default.
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn with_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Sets the value axis to [`Chart::range()`].
+    pub fn with_support(mut self, support: &GpuMetricsSupport) -> Self {
+        self.range = self.chart.range(support).or(self.range);
+        self
+    }
+
+    pub fn render(&self, samples: &[GpuMetricsSample]) -> String {
+        let series = self.chart.series();
+        let width = f64::from(self.width);
+        let height = f64::from(self.height);
+        let plot_width = (width - MARGIN_LEFT - MARGIN_RIGHT).max(1.0);
+        let plot_height = (height - MARGIN_TOP - MARGIN_BOTTOM).max(1.0);
+
+        let start_ms = samples.iter().map(|s| s.time_stamp).min().unwrap_or(0);
+        let end_ms = samples.iter().map(|s| s.time_stamp).max().unwrap_or(0);
+        let duration_ms = (end_ms - start_ms).max(1) as f64;
+
+        let (mut min, mut max) = self
+            .range
+            .as_ref()
+            .map_or((f64::INFINITY, f64::NEG_INFINITY), |r| {
+                (*r.start(), *r.end())
+            });
+        for value in samples
+            .iter()
+            .flat_map(|s| series.iter().filter_map(|series| (series.value)(s)))
+            .filter(|v| v.is_finite())
+        {
+            min = min.min(value);
+            max = max.max(value);
+        }
+        if min > max {
+            (min, max) = (0.0, 1.0);
+        } else if min == max {
+            (min, max) = (min - 0.5, max + 0.5);
+        }
+
+        let x = |time_stamp: i64| {
+            MARGIN_LEFT + (time_stamp - start_ms) as f64 / duration_ms * plot_width
+        };
+        let y = |value: f64| MARGIN_TOP + (max - value) / (max - min) * plot_height;
+
+        let mut svg = String::new();
+        // Writing to a String cannot fail
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" font-family="sans-serif" font-size="12">"#,
+            self.width, self.height, self.width, self.height
+        );
+        let _ = writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#);
+        let _ = writeln!(
+            svg,
+            r#"<text x="{MARGIN_LEFT}" y="20" font-size="14" font-weight="bold">{} ({})</text>"#,
+            self.chart.title(),
+            self.chart.unit()
+        );
+
+        // Value axis with grid lines
+        for i in 0..=TICKS {
+            let value = min + (max - min) * f64::from(i) / f64::from(TICKS);
+            let ty = y(value);
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{MARGIN_LEFT}" y1="{ty:.1}" x2="{:.1}" y2="{ty:.1}" stroke="#ddd"/>"##,
+                MARGIN_LEFT + plot_width
+            );
+            let _ = writeln!(
+                svg,
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="end">{}</text>"#,
+                MARGIN_LEFT - 6.0,
+                ty + 4.0,
+                format_tick(value)
+            );
+        }
+
+        // Time axis in seconds
+        for i in 0..=TICKS {
+            let offset_ms = duration_ms * f64::from(i) / f64::from(TICKS);
+            let tx = MARGIN_LEFT + offset_ms / duration_ms * plot_width;
+            let _ = writeln!(
+                svg,
+                r#"<text x="{tx:.1}" y="{:.1}" text-anchor="middle">{}</text>"#,
+                MARGIN_TOP + plot_height + 16.0,
+                format_tick(offset_ms / 1000.0)
+            );
+        }
+        let _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle">Time (s)</text>"#,
+            MARGIN_LEFT + plot_width / 2.0,
+            height - 6.0
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{MARGIN_LEFT}" y="{MARGIN_TOP}" width="{plot_width:.1}" height="{plot_height:.1}" fill="none" stroke="black"/>"#
+        );
+
+        // One path per series, interrupted where a sample has no value. A sample between two gaps
+        // is drawn as a zero-length line, which the round caps turn into a dot
+        for (i, series) in series.iter().enumerate() {
+            let mut path = String::new();
+            // The point that was moved to, while no line has been drawn from it
+            let mut isolated = None;
+            let mut pen_down = false;
+            for sample in samples {
+                match (series.value)(sample).filter(|v| v.is_finite()) {
+                    Some(value) => {
+                        let point = (x(sample.time_stamp), y(value));
+                        let command = if pen_down { 'L' } else { 'M' };
+                        let _ = write!(path, "{command}{:.1},{:.1} ", point.0, point.1);
+                        isolated = (!pen_down).then_some(point);
+                        pen_down = true;
+                    }
+                    None => {
+                        if let Some((x, y)) = isolated.take() {
+                            let _ = write!(path, "L{x:.1},{y:.1} ");
+                        }
+                        pen_down = false;
+                    }
+                }
+            }
+            if let Some((x, y)) = isolated {
+                let _ = write!(path, "L{x:.1},{y:.1} ");
+            }
+            if path.is_empty() {
+                continue;
+            }
+            let _ = writeln!(
+                svg,
+                r#"<path d="{}" fill="none" stroke="{}" stroke-width="1.5" stroke-linecap="round"/>"#,
+                path.trim_end(),
+                series.color
+            );
+            let _ = writeln!(
+                svg,
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="end" fill="{}">{}</text>"#,
+                width - MARGIN_RIGHT - 4.0,
+                MARGIN_TOP + 16.0 * (i as f64 + 1.0),
+                series.color,
+                series.name
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    pub fn save(&self, samples: &[GpuMetricsSample], path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.render(samples))
+    }
+}
+
+fn format_tick(value: f64) -> String {
+    if value.fract() == 0.0 || value.abs() >= 100.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chart, SvgChart};
+    use crate::GpuMetricsSample;
+
+    #[test]
+    fn renders_series_with_gaps() {
+        let sample = |time_stamp, clock_speed| GpuMetricsSample {
+            time_stamp,
+            clock_speed,
+            vram_clock_speed: Some(1000),
+            ..Default::default()
+        };
+        let svg = SvgChart::new(Chart::ClockSpeed)
+            .with_range(0.0..=2500.0)
+            .render(&[
+                sample(1000, Some(500)),
+                sample(2000, None),
+                sample(3000, Some(2000)),
+            ]);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("Clock speed (MHz)"));
+        assert!(svg.contains(">2500</text>"));
+        // The GPU clock is interrupted by the missing sample, leaving two dots, the VRAM clock is
+        // not
+        assert!(svg.contains(
+            r##"<path d="M64.0,214.4 L64.0,214.4 M784.0,77.6 L784.0,77.6" fill="none" stroke="#e4002b""##
+        ));
+        assert!(svg.contains(
+            r##"<path d="M64.0,168.8 L424.0,168.8 L784.0,168.8" fill="none" stroke="#0072c6""##
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+//! Downsampling of long metric series, either into fixed-duration buckets with the minimum,
+//! maximum and mean of every metric, or with
+//! [Largest-Triangle-Three-Buckets](https://skemman.is/bitstream/1946/15343/3/SS_MSthesis.pdf)
+//! to keep the visual shape of a series in a fraction of the points.
+
+use super::gpu_metrics::GpuMetricsSample;
+
+/// Aggregate of all [`GpuMetricsSample`]s within a time span. The time stamp of [`Self::min`],
+/// [`Self::max`] and [`Self::mean`] is [`Self::start`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GpuMetricsBucket {
+    /// Start of the bucket in milliseconds, inclusive
+    pub start: i64,
+    /// End of the bucket in milliseconds, exclusive
+    pub end: i64,
+    /// Number of samples in the bucket
+    pub count: usize,
+    pub min: GpuMetricsSample,
+    pub max: GpuMetricsSample,
+    /// Metrics that are not available in any sample of the bucket are [`None`]. Integer metrics
+    /// are rounded to the nearest value.
+    pub mean: GpuMetricsSample,
+}
+
+/// Per-metric accumulator of [`Option`] values.
+#[derive(Clone, Copy, Default)]
+struct Stats {
+    min: Option<f64>,
+    max: Option<f64>,
+    sum: f64,
+    count: u32,
+}
+
+impl Stats {
+    fn add(&mut self, value: Option<f64>) {
+        let Some(value) = value else { return };
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / f64::from(self.count))
+    }
+}
+
+/// Applies `$m!(field, float_or_int)` to every metric of [`GpuMetricsSample`].
+macro_rules! for_each_metric {
+    ($m:ident) => {
+        $m!(usage, float);
+        $m!(clock_speed, int);
+        $m!(vram_clock_speed, int);
+        $m!(power, float);
+        $m!(total_board_power, float);
+        $m!(voltage, int);
+        $m!(vram, int);
+        $m!(fan_speed, int);
+        $m!(temperature, float);
+        $m!(intake_temperature, float);
+        $m!(hotspot_temperature, float);
+    };
+}
+
+#[derive(Default)]
+struct SampleStats {
+    usage: Stats,
+    clock_speed: Stats,
+    vram_clock_speed: Stats,
+    power: Stats,
+    total_board_power: Stats,
+    voltage: Stats,
+    vram: Stats,
+    fan_speed: Stats,
+    temperature: Stats,
+    intake_temperature: Stats,
+    hotspot_temperature: Stats,
+}
+
+impl SampleStats {
+    fn add(&mut self, sample: &GpuMetricsSample) {
+        macro_rules! add {
+            ($field:ident, float) => {
+                self.$field.add(sample.$field)
+            };
+            ($field:ident, int) => {
+                self.$field.add(sample.$field.map(f64::from))
+            };
+        }
+        for_each_metric!(add);
+    }
+
+    fn sample(&self, time_stamp: i64, stat: impl Fn(&Stats) -> Option<f64>) -> GpuMetricsSample {
+        let mut sample = GpuMetricsSample {
+            time_stamp,
+            ..Default::default()
+        };
+        macro_rules! set {
+            ($field:ident, float) => {
+                sample.$field = stat(&self.$field)
+            };
+            ($field:ident, int) => {
+                sample.$field = stat(&self.$field).map(|v| v.round() as i32)
+            };
+        }
+        for_each_metric!(set);
+        sample
+    }
+}
+
+/// Groups `samples`, which must be sorted by time stamp, into buckets of `bucket_ms`
+/// milliseconds aligned to the first sample. Empty buckets are left out.
+///
+/// # Panics
+/// If `bucket_ms` is not positive.
+pub fn bucket_gpu_metrics(samples: &[GpuMetricsSample], bucket_ms: i64) -> Vec<GpuMetricsBucket> {
+    assert!(bucket_ms > 0, "Bucket duration must be positive");
+    let Some(first) = samples.first() else {
+        return vec![];
+    };
+
+    let mut buckets = vec![];
+    let mut rest = samples;
+    while let Some(sample) = rest.first() {
+        let start = first.time_stamp
+            + (sample.time_stamp - first.time_stamp).div_euclid(bucket_ms) * bucket_ms;
+        let end = start + bucket_ms;
+        let len = rest
+            .iter()
+            .position(|s| s.time_stamp >= end)
+            .unwrap_or(rest.len());
+        let (bucket, tail) = rest.split_at(len);
+
+        let mut stats = SampleStats::default();
+        bucket.iter().for_each(|s| stats.add(s));
+        buckets.push(GpuMetricsBucket {
+            start,
+            end,
+            count: bucket.len(),
+            min: stats.sample(start, |s| s.min),
+            max: stats.sample(start, |s| s.max),
+            mean: stats.sample(start, Stats::mean),
+        });
+        rest = tail;
+    }
+    buckets
+}
+
+/// Selects at most `threshold` items of `data` with the Largest-Triangle-Three-Buckets algorithm,
+/// where `point` returns the `(x, y)` position of an item, for example
+/// `|s: &GpuMetricsSample| (s.time_stamp as f64, s.clock_speed.unwrap_or(0).into())`.
+///
+/// The first and last item are always kept. `data` is returned unchanged if it is not longer than
+/// `threshold`, or if `threshold` is less than 3.
+pub fn lttb<T: Clone>(data: &[T], threshold: usize, point: impl Fn(&T) -> (f64, f64)) -> Vec<T> {
+    if threshold >= data.len() || threshold < 3 {
+        return data.to_vec();
+    }
+
+    let mut sampled = Vec::with_capacity(threshold);
+    // The first and last items are fixed, the rest is divided into `threshold - 2` buckets
+    let every = (data.len() - 2) as f64 / (threshold - 2) as f64;
+    let bucket_bounds = |i: usize| {
+        let start = (i as f64 * every) as usize + 1;
+        let end = (((i + 1) as f64 * every) as usize + 1).min(data.len() - 1);
+        start..end
+    };
+
+    let mut a = 0;
+    sampled.push(data[0].clone());
+    for i in 0..threshold - 2 {
+        // Average of the next bucket, or the last item for the last bucket
+        let next = if i + 1 < threshold - 2 {
+            bucket_bounds(i + 1)
+        } else {
+            data.len() - 1..data.len()
+        };
+        let (sum_x, sum_y) = data[next.clone()]
+            .iter()
+            .map(&point)
+            .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+        let (avg_x, avg_y) = (sum_x / next.len() as f64, sum_y / next.len() as f64);
+
+        let (ax, ay) = point(&data[a]);
+        let mut max_area = -1.0;
+        let mut selected = a;
+        for j in bucket_bounds(i) {
+            let (x, y) = point(&data[j]);
+            let area = ((ax - avg_x) * (y - ay) - (ax - x) * (avg_y - ay)).abs();
+            if area > max_area {
+                max_area = area;
+                selected = j;
+            }
+        }
+        sampled.push(data[selected].clone());
+        a = selected;
+    }
+    sampled.push(data[data.len() - 1].clone());
+    sampled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bucket_gpu_metrics, lttb};
+    use crate::GpuMetricsSample;
+
+    fn sample(time_stamp: i64, clock_speed: Option<i32>, power: f64) -> GpuMetricsSample {
+        GpuMetricsSample {
+            time_stamp,
+            clock_speed,
+            power: Some(power),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn buckets_aggregate_metrics() {
+        let samples = [
+            sample(100, Some(1000), 50.0),
+            sample(200, None, 60.0),
+            sample(300, Some(2001), 70.0),
+            sample(1150, Some(500), 10.0),
+        ];
+        let buckets = bucket_gpu_metrics(&samples, 1000);
+
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(
+            (buckets[0].start, buckets[0].end, buckets[0].count),
+            (100, 1100, 3)
+        );
+        assert_eq!(buckets[0].min.clock_speed, Some(1000));
+        assert_eq!(buckets[0].max.clock_speed, Some(2001));
+        assert_eq!(buckets[0].mean.clock_speed, Some(1501));
+        assert_eq!(buckets[0].mean.power, Some(60.0));
+        assert_eq!(buckets[0].mean.usage, None);
+        assert_eq!((buckets[1].start, buckets[1].count), (1100, 1));
+        assert_eq!(buckets[1].mean.time_stamp, 1100);
+    }
+
+    #[test]
+    fn lttb_keeps_extremes() {
+        let points = (0..100)
+            .map(|x| (x, if x == 42 { 100.0 } else { 0.0 }))
+            .collect::<Vec<_>>();
+        let sampled = lttb(&points, 10, |&(x, y)| (f64::from(x), y));
+
+        assert_eq!(sampled.len(), 10);
+        assert_eq!(sampled.first(), Some(&(0, 0.0)));
+        assert_eq!(sampled.last(), Some(&(99, 0.0)));
+        assert!(sampled.contains(&(42, 100.0)));
+        assert!(sampled.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
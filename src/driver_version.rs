@@ -0,0 +1,393 @@
+//! Parsed driver and AMD Software versions of a [`Gpu2`], and requirements to check them against.
+
+use std::{cmp::Ordering, fmt, hash::Hash, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{gpu::Gpu2, result::Error};
+
+/// Compares version components, where missing trailing components are zero.
+pub(crate) fn cmp_components(a: &[u32], b: &[u32]) -> Ordering {
+    let len = a.len().max(b.len());
+    let pad = |c: &[u32]| {
+        c.iter()
+            .copied()
+            .chain(std::iter::repeat(0))
+            .take(len)
+            .collect()
+    };
+    Vec::<u32>::cmp(&pad(a), &pad(b))
+}
+
+/// Declares a dotted version type that parses, orders and hashes its numeric components.
+macro_rules! version_type {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident;
+    ) => {
+        $(#[$meta])*
+        ///
+        /// Versions are ordered by their leading numeric components, where missing components are
+        /// zero, so that `23.40` equals `23.40.0` but is less than `23.40.02.02`. The original
+        /// string is kept for display.
+        #[derive(Clone, Debug)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(::serde::Serialize, ::serde::Deserialize),
+            serde(from = "String", into = "String")
+        )]
+        pub struct $name {
+            raw: String,
+            components: Vec<u32>,
+        }
+
+        impl $name {
+            /// The numeric components up to the first one that is not a number
+            pub fn components(&self) -> &[u32] {
+                &self.components
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.raw
+            }
+
+            /// Whether this version satisfies all clauses of `requirement`.
+            pub fn satisfies(&self, requirement: &$crate::driver_version::VersionReq) -> bool {
+                requirement.matches(&self.components)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(raw: &str) -> Self {
+                let components = raw
+                    .trim()
+                    .split('.')
+                    .map_while(|c| c.parse().ok())
+                    .collect();
+                Self {
+                    raw: raw.to_owned(),
+                    components,
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(raw: String) -> Self {
+                raw.as_str().into()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(version: $name) -> Self {
+                version.raw
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == ::std::cmp::Ordering::Equal
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl ::std::hash::Hash for $name {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                let len = self.components.iter().rposition(|&c| c != 0).map_or(0, |i| i + 1);
+                self.components[..len].hash(state);
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                $crate::driver_version::cmp_components(&self.components, &other.components)
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(&self.raw)
+            }
+        }
+    };
+}
+pub(crate) use version_type;
+
+version_type! {
+    /// A Windows display driver version, like `31.0.24002.92` returned by
+    /// [`Gpu2::driver_version()`] or `23.40.02.02` returned by
+    /// [`Gpu2::amd_windows_driver_version()`].
+    pub struct DriverVersion;
+}
+
+version_type! {
+    /// An AMD Software (Adrenalin Edition) version, like `24.1.1` returned by
+    /// [`Gpu2::amd_software_version()`].
+    pub struct SoftwareVersion;
+}
+
+/// Release date of AMD Software, returned by [`Gpu2::amd_software_release_date()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReleaseDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl fmt::Display for ReleaseDate {
+    /// Formats the date as `YYYY-MM-DD`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// A requirement on a [`DriverVersion`] or [`SoftwareVersion`], like `>= 23.40` or
+/// `>= 23.10, != 23.12.1`.
+///
+/// Every comma-separated clause consists of one of `=`, `==`, `!=`, `<`, `<=`, `>` or `>=` and a
+/// version. Only as many components as the clause specifies are compared, so `>= 23.40` and
+/// `= 23.40` match `23.40.02.02` while `> 23.40` does not.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VersionReq {
+    raw: String,
+    clauses: Vec<(Op, Vec<u32>)>,
+}
+
+/// The requirement passed to [`VersionReq::from_str()`] is not valid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseVersionReqError(String);
+
+impl fmt::Display for ParseVersionReqError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid version requirement `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseVersionReqError {}
+
+impl FromStr for VersionReq {
+    type Err = ParseVersionReqError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseVersionReqError(s.to_owned());
+        let clauses = s
+            .split(',')
+            .map(|clause| {
+                let clause = clause.trim();
+                let (op, version) = [
+                    ("==", Op::Eq),
+                    ("!=", Op::Ne),
+                    ("<=", Op::Le),
+                    (">=", Op::Ge),
+                    ("=", Op::Eq),
+                    ("<", Op::Lt),
+                    (">", Op::Gt),
+                ]
+                .into_iter()
+                .find_map(|(prefix, op)| Some((op, clause.strip_prefix(prefix)?)))
+                .ok_or_else(err)?;
+                let components = version
+                    .trim()
+                    .split('.')
+                    .map(str::parse)
+                    .collect::<Result<Vec<u32>, _>>()
+                    .map_err(|_| err())?;
+                Ok((op, components))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            raw: s.trim().to_owned(),
+            clauses,
+        })
+    }
+}
+
+impl VersionReq {
+    pub(crate) fn matches(&self, components: &[u32]) -> bool {
+        self.clauses.iter().all(|(op, required)| {
+            let len = components.len().min(required.len());
+            let ordering = cmp_components(&components[..len], required);
+            match op {
+                Op::Eq => ordering.is_eq(),
+                Op::Ne => ordering.is_ne(),
+                Op::Lt => ordering.is_lt(),
+                Op::Le => ordering.is_le(),
+                Op::Gt => ordering.is_gt(),
+                Op::Ge => ordering.is_ge(),
+            }
+        })
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+/// Error returned by [`Gpu2::require_driver()`] and [`Gpu2::require_software()`], with the
+/// [`DriverVersion`] or [`SoftwareVersion`] that was checked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RequirementError<V> {
+    /// The version could not be read from ADLX
+    Adlx(Error),
+    InvalidRequirement(ParseVersionReqError),
+    /// The installed version does not satisfy the requirement
+    Unsatisfied {
+        version: V,
+        requirement: VersionReq,
+    },
+}
+
+impl<V: fmt::Display> fmt::Display for RequirementError<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Adlx(e) => e.fmt(f),
+            Self::InvalidRequirement(e) => e.fmt(f),
+            Self::Unsatisfied {
+                version,
+                requirement,
+            } => write!(f, "Version `{version}` does not satisfy `{requirement}`"),
+        }
+    }
+}
+
+impl<V: fmt::Debug + fmt::Display> std::error::Error for RequirementError<V> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Adlx(e) => Some(e),
+            Self::InvalidRequirement(e) => Some(e),
+            Self::Unsatisfied { .. } => None,
+        }
+    }
+}
+
+impl<V> From<Error> for RequirementError<V> {
+    fn from(e: Error) -> Self {
+        Self::Adlx(e)
+    }
+}
+
+impl<V> From<ParseVersionReqError> for RequirementError<V> {
+    fn from(e: ParseVersionReqError) -> Self {
+        Self::InvalidRequirement(e)
+    }
+}
+
+impl Gpu2 {
+    /// Checks [`Self::amd_windows_driver_version()`] against `requirement`, like `>= 23.40`,
+    /// returning the installed version if it is satisfied. See [`VersionReq`] for the syntax.
+    pub fn require_driver(
+        &self,
+        requirement: &str,
+    ) -> Result<DriverVersion, RequirementError<DriverVersion>> {
+        let requirement = requirement.parse::<VersionReq>()?;
+        let version = self.amd_windows_driver_version()?;
+        if !version.satisfies(&requirement) {
+            return Err(RequirementError::Unsatisfied {
+                version,
+                requirement,
+            });
+        }
+        Ok(version)
+    }
+
+    /// Checks [`Self::amd_software_version()`] against `requirement`, like `!= 24.1.1`, to
+    /// block known-bad AMD Software releases.
+    pub fn require_software(
+        &self,
+        requirement: &str,
+    ) -> Result<SoftwareVersion, RequirementError<SoftwareVersion>> {
+        let requirement = requirement.parse::<VersionReq>()?;
+        let version = self.amd_software_version()?;
+        if !version.satisfies(&requirement) {
+            return Err(RequirementError::Unsatisfied {
+                version,
+                requirement,
+            });
+        }
+        Ok(version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DriverVersion, SoftwareVersion, VersionReq};
+
+    #[test]
+    fn orders_versions_numerically() {
+        let old = DriverVersion::from("23.20.11.04");
+        let new = DriverVersion::from("23.40.02.02");
+        assert!(old < new);
+        assert_eq!(DriverVersion::from("23.40"), DriverVersion::from("23.40.0"));
+        assert!(SoftwareVersion::from("23.12.1") < SoftwareVersion::from("24.1.1"));
+        assert_eq!(new.components(), [23, 40, 2, 2]);
+        assert_eq!(new.to_string(), "23.40.02.02");
+    }
+
+    #[test]
+    fn checks_requirements() {
+        let version = DriverVersion::from("23.40.02.02");
+        let satisfies = |req: &str| version.satisfies(&req.parse::<VersionReq>().unwrap());
+
+        assert!(satisfies(">= 23.40"));
+        assert!(satisfies("=23.40"));
+        assert!(satisfies("<= 23.40"));
+        assert!(!satisfies("> 23.40"));
+        assert!(!satisfies("< 23.40.2"));
+        assert!(satisfies(">= 23.10, != 23.40.02.01"));
+        assert!(!satisfies(">= 23.10, != 23.40"));
+
+        assert!("23.40".parse::<VersionReq>().is_err());
+        assert!(">= 23.x".parse::<VersionReq>().is_err());
+        assert!(">= 23.40,".parse::<VersionReq>().is_err());
+    }
+
+    #[cfg(feature = "replay")]
+    #[test]
+    fn requires_replayed_driver() {
+        use crate::{replay::tests::replay_gpu2, ReleaseDate, RequirementError, SoftwareVersion};
+
+        let (_helper, gpu) = replay_gpu2(|_| {});
+
+        assert_eq!(
+            gpu.require_driver(">= 23.40").unwrap().as_str(),
+            "23.40.02.02"
+        );
+        assert!(matches!(
+            gpu.require_software("!= 24.1.1"),
+            Err(RequirementError::Unsatisfied { version, .. })
+                if version == SoftwareVersion::from("24.1.1.0")
+        ));
+        assert!(matches!(
+            gpu.require_driver("23.40"),
+            Err(RequirementError::InvalidRequirement(_))
+        ));
+        assert_eq!(
+            gpu.amd_software_release_date(),
+            Ok(ReleaseDate {
+                year: 2024,
+                month: 1,
+                day: 31
+            })
+        );
+    }
+}
@@ -0,0 +1,423 @@
+//! Rust enums for the ADLX C enums in the public API.
+//!
+//! Values that this crate does not know about, for example because they were added in a newer
+//! ADLX version, are represented by an `Unknown` variant holding the raw value. They convert back
+//! to the same raw value.
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::ffi;
+
+/// The raw value passed to `TryFrom<i32>` does not correspond to a known variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnknownEnumValue {
+    /// Name of the ADLX C enum, like `ADLX_GPU_TYPE`
+    pub enum_name: &'static str,
+    pub value: i32,
+}
+
+impl fmt::Display for UnknownEnumValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown {} value `{}`", self.enum_name, self.value)
+    }
+}
+
+impl std::error::Error for UnknownEnumValue {}
+
+macro_rules! adlx_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident($ffi:ident) {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:ident => $display:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[non_exhaustive]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                #[doc = concat!("[`ffi::", stringify!($value), "`]")]
+                $variant,
+            )*
+            /// A value that is not known to this crate
+            Unknown(i32),
+        }
+
+        impl $name {
+            /// Converts a raw value, falling back to [`Self::Unknown`].
+            pub fn from_raw(raw: ffi::$ffi) -> Self {
+                match raw {
+                    $(ffi::$value => Self::$variant,)*
+                    raw => Self::Unknown(raw),
+                }
+            }
+
+            pub fn into_raw(self) -> ffi::$ffi {
+                match self {
+                    $(Self::$variant => ffi::$value,)*
+                    Self::Unknown(raw) => raw,
+                }
+            }
+        }
+
+        impl $crate::interface::FfiValue for $name {
+            type Raw = ffi::$ffi;
+
+            fn from_ffi(raw: Self::Raw) -> Self {
+                Self::from_raw(raw)
+            }
+
+            fn into_ffi(self) -> Self::Raw {
+                self.into_raw()
+            }
+        }
+
+        impl TryFrom<i32> for $name {
+            type Error = UnknownEnumValue;
+
+            /// Converts a raw value, failing instead of returning [`Self::Unknown`].
+            fn try_from(raw: i32) -> Result<Self, Self::Error> {
+                match Self::from_raw(raw) {
+                    Self::Unknown(value) => Err(UnknownEnumValue {
+                        enum_name: stringify!($ffi),
+                        value,
+                    }),
+                    known => Ok(known),
+                }
+            }
+        }
+
+        impl From<$name> for i32 {
+            fn from(value: $name) -> Self {
+                value.into_raw()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$variant => f.write_str($display),)*
+                    Self::Unknown(raw) => write!(f, "Unknown ({raw})"),
+                }
+            }
+        }
+    };
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__a_s_i_c__f_a_m_i_l_y__t_y_p_e/>
+    #[doc(alias = "ADLX_ASIC_FAMILY_TYPE")]
+    pub enum AsicFamilyType(ADLX_ASIC_FAMILY_TYPE) {
+        Undefined = ADLX_ASIC_FAMILY_TYPE_ASIC_UNDEFINED => "Undefined",
+        Radeon = ADLX_ASIC_FAMILY_TYPE_ASIC_RADEON => "Radeon",
+        FirePro = ADLX_ASIC_FAMILY_TYPE_ASIC_FIREPRO => "FirePro",
+        FireMv = ADLX_ASIC_FAMILY_TYPE_ASIC_FIREMV => "FireMV",
+        FireStream = ADLX_ASIC_FAMILY_TYPE_ASIC_FIRESTREAM => "FireStream",
+        Fusion = ADLX_ASIC_FAMILY_TYPE_ASIC_FUSION => "Fusion",
+        Embedded = ADLX_ASIC_FAMILY_TYPE_ASIC_EMBEDDED => "Embedded",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__g_p_u__t_y_p_e/>
+    #[doc(alias = "ADLX_GPU_TYPE")]
+    pub enum GpuType(ADLX_GPU_TYPE) {
+        Undefined = ADLX_GPU_TYPE_GPUTYPE_UNDEFINED => "Undefined",
+        Integrated = ADLX_GPU_TYPE_GPUTYPE_INTEGRATED => "Integrated",
+        Discrete = ADLX_GPU_TYPE_GPUTYPE_DISCRETE => "Discrete",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__p_c_i__b_u_s__t_y_p_e/>
+    #[doc(alias = "ADLX_PCI_BUS_TYPE")]
+    pub enum PciBusType(ADLX_PCI_BUS_TYPE) {
+        Undefined = ADLX_PCI_BUS_TYPE_UNDEFINED => "Undefined",
+        Pci = ADLX_PCI_BUS_TYPE_PCI => "PCI",
+        Agp = ADLX_PCI_BUS_TYPE_AGP => "AGP",
+        Pcie = ADLX_PCI_BUS_TYPE_PCIE => "PCIe",
+        Pcie2 = ADLX_PCI_BUS_TYPE_PCIE_2_0 => "PCIe 2.0",
+        Pcie3 = ADLX_PCI_BUS_TYPE_PCIE_3_0 => "PCIe 3.0",
+        Pcie4 = ADLX_PCI_BUS_TYPE_PCIE_4_0 => "PCIe 4.0",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__m_g_p_u__m_o_d_e/>
+    #[doc(alias = "ADLX_MGPU_MODE")]
+    pub enum MultiGpuMode(ADLX_MGPU_MODE) {
+        /// Not part of a multi-GPU setup
+        None = ADLX_MGPU_MODE_MGPU_NONE => "None",
+        Primary = ADLX_MGPU_MODE_MGPU_PRIMARY => "Primary",
+        Secondary = ADLX_MGPU_MODE_MGPU_SECONDARY => "Secondary",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__h_g__t_y_p_e/>
+    #[doc(alias = "ADLX_HG_TYPE")]
+    pub enum HybridGraphicsType(ADLX_HG_TYPE) {
+        None = ADLX_HG_TYPE_NONE => "None",
+        Amd = ADLX_HG_TYPE_AMD => "AMD",
+        Other = ADLX_HG_TYPE_OTHER => "Other",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__w_a_i_t__f_o_r__v_e_r_t_i_c_a_l__r_e_f_r_e_s_h__m_o_d_e/>
+    #[doc(alias = "ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE")]
+    pub enum WaitForVerticalRefreshMode(ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE) {
+        AlwaysOff = ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE_WFVR_ALWAYS_OFF => "Always off",
+        OffUnlessAppSpecifies = ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE_WFVR_OFF_UNLESS_APP_SPECIFIES
+            => "Off, unless application specifies",
+        OnUnlessAppSpecifies = ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE_WFVR_ON_UNLESS_APP_SPECIFIES
+            => "On, unless application specifies",
+        AlwaysOn = ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE_WFVR_ALWAYS_ON => "Always on",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__a_p_p__g_p_u__d_e_p_e_n_d_e_n_c_y/>
+    #[doc(alias = "ADLX_APP_GPU_DEPENDENCY")]
+    pub enum AppGpuDependency(ADLX_APP_GPU_DEPENDENCY) {
+        /// ADLX could not determine whether the application depends on the GPU
+        Undetermined = ADLX_APP_GPU_DEPENDENCY_APP_GPU_UNKNOWN => "Undetermined",
+        /// The application runs on the GPU and keeps it powered on
+        Bound = ADLX_APP_GPU_DEPENDENCY_APP_GPU_BOUND => "Bound",
+        NotBound = ADLX_APP_GPU_DEPENDENCY_APP_GPU_NOT_BOUND => "Not bound",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__d_p__l_i_n_k__r_a_t_e/>
+    #[doc(alias = "ADLX_DP_LINK_RATE")]
+    pub enum DpLinkRate(ADLX_DP_LINK_RATE) {
+        Undefined = ADLX_DP_LINK_RATE_DP_LINK_RATE_UNKNOWN => "Unknown",
+        /// 1.62 Gbps per lane
+        Rbr = ADLX_DP_LINK_RATE_DP_LINK_RATE_RBR => "RBR",
+        Gbps2_16 = ADLX_DP_LINK_RATE_DP_LINK_RATE_2_16GBPS => "2.16 Gbps",
+        Gbps2_43 = ADLX_DP_LINK_RATE_DP_LINK_RATE_2_43GBPS => "2.43 Gbps",
+        /// 2.7 Gbps per lane
+        Hbr = ADLX_DP_LINK_RATE_DP_LINK_RATE_HBR => "HBR",
+        Gbps4_32 = ADLX_DP_LINK_RATE_DP_LINK_RATE_4_32GBPS => "4.32 Gbps",
+        /// 5.4 Gbps per lane
+        Hbr2 = ADLX_DP_LINK_RATE_DP_LINK_RATE_HBR2 => "HBR2",
+        /// 8.1 Gbps per lane
+        Hbr3 = ADLX_DP_LINK_RATE_DP_LINK_RATE_HBR3 => "HBR3",
+        Uhbr10 = ADLX_DP_LINK_RATE_DP_LINK_RATE_UHBR10 => "UHBR10",
+        Uhbr13_5 = ADLX_DP_LINK_RATE_DP_LINK_RATE_UHBR13D5 => "UHBR13.5",
+        Uhbr20 = ADLX_DP_LINK_RATE_DP_LINK_RATE_UHBR20 => "UHBR20",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__s_c_a_l_e__m_o_d_e/>
+    #[doc(alias = "ADLX_SCALE_MODE")]
+    pub enum ScaleMode(ADLX_SCALE_MODE) {
+        PreserveAspectRatio = ADLX_SCALE_MODE_PRESERVE_ASPECT_RATIO => "Preserve aspect ratio",
+        FullPanel = ADLX_SCALE_MODE_FULL_PANEL => "Full panel",
+        Centered = ADLX_SCALE_MODE_CENTERED => "Centered",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__c_o_l_o_r__d_e_p_t_h/>
+    #[doc(alias = "ADLX_COLOR_DEPTH")]
+    pub enum ColorDepth(ADLX_COLOR_DEPTH) {
+        Undefined = ADLX_COLOR_DEPTH_BPC_UNKNOWN => "Unknown",
+        Bpc6 = ADLX_COLOR_DEPTH_BPC_6 => "6 bpc",
+        Bpc8 = ADLX_COLOR_DEPTH_BPC_8 => "8 bpc",
+        Bpc10 = ADLX_COLOR_DEPTH_BPC_10 => "10 bpc",
+        Bpc12 = ADLX_COLOR_DEPTH_BPC_12 => "12 bpc",
+        Bpc14 = ADLX_COLOR_DEPTH_BPC_14 => "14 bpc",
+        Bpc16 = ADLX_COLOR_DEPTH_BPC_16 => "16 bpc",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__p_i_x_e_l__f_o_r_m_a_t/>
+    #[doc(alias = "ADLX_PIXEL_FORMAT")]
+    pub enum PixelFormat(ADLX_PIXEL_FORMAT) {
+        Undefined = ADLX_PIXEL_FORMAT_FORMAT_UNKNOWN => "Unknown",
+        Rgb444Full = ADLX_PIXEL_FORMAT_RGB_444_FULL => "RGB 4:4:4 full range",
+        YCbCr444 = ADLX_PIXEL_FORMAT_YCBCR_444 => "YCbCr 4:4:4",
+        YCbCr422 = ADLX_PIXEL_FORMAT_YCBCR_422 => "YCbCr 4:2:2",
+        Rgb444Limited = ADLX_PIXEL_FORMAT_RGB_444_LIMITED => "RGB 4:4:4 limited range",
+        YCbCr420 = ADLX_PIXEL_FORMAT_YCBCR_420 => "YCbCr 4:2:0",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__a_n_t_i__a_l_i_a_s_i_n_g__m_o_d_e/>
+    #[doc(alias = "ADLX_ANTI_ALIASING_MODE")]
+    pub enum AntiAliasingMode(ADLX_ANTI_ALIASING_MODE) {
+        UseAppSettings = ADLX_ANTI_ALIASING_MODE_AA_MODE_USE_APP_SETTINGS
+            => "Use application settings",
+        EnhanceAppSettings = ADLX_ANTI_ALIASING_MODE_AA_MODE_ENHANCE_APP_SETTINGS
+            => "Enhance application settings",
+        OverrideAppSettings = ADLX_ANTI_ALIASING_MODE_AA_MODE_OVERRIDE_APP_SETTINGS
+            => "Override application settings",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__a_n_t_i__a_l_i_a_s_i_n_g__l_e_v_e_l/>
+    #[doc(alias = "ADLX_ANTI_ALIASING_LEVEL")]
+    pub enum AntiAliasingLevel(ADLX_ANTI_ALIASING_LEVEL) {
+        Invalid = ADLX_ANTI_ALIASING_LEVEL_AA_LEVEL_INVALID => "Invalid",
+        X2 = ADLX_ANTI_ALIASING_LEVEL_AA_LEVEL_2X => "2X",
+        X2Eq = ADLX_ANTI_ALIASING_LEVEL_AA_LEVEL_2XEQ => "2X EQ",
+        X4 = ADLX_ANTI_ALIASING_LEVEL_AA_LEVEL_4X => "4X",
+        X4Eq = ADLX_ANTI_ALIASING_LEVEL_AA_LEVEL_4XEQ => "4X EQ",
+        X8 = ADLX_ANTI_ALIASING_LEVEL_AA_LEVEL_8X => "8X",
+        X8Eq = ADLX_ANTI_ALIASING_LEVEL_AA_LEVEL_8XEQ => "8X EQ",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__a_n_t_i__a_l_i_a_s_i_n_g__m_e_t_h_o_d/>
+    #[doc(alias = "ADLX_ANTI_ALIASING_METHOD")]
+    pub enum AntiAliasingMethod(ADLX_ANTI_ALIASING_METHOD) {
+        Multisampling = ADLX_ANTI_ALIASING_METHOD_AA_METHOD_MULTISAMPLING => "Multisampling",
+        AdaptiveMultisampling = ADLX_ANTI_ALIASING_METHOD_AA_METHOD_ADAPTIVE_MULTISAMPLING
+            => "Adaptive multisampling",
+        Supersampling = ADLX_ANTI_ALIASING_METHOD_AA_METHOD_SUPERSAMPLING => "Supersampling",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__a_n_i_s_o_t_r_o_p_i_c__f_i_l_t_e_r_i_n_g__l_e_v_e_l/>
+    #[doc(alias = "ADLX_ANISOTROPIC_FILTERING_LEVEL")]
+    pub enum AnisotropicFilteringLevel(ADLX_ANISOTROPIC_FILTERING_LEVEL) {
+        Invalid = ADLX_ANISOTROPIC_FILTERING_LEVEL_AF_LEVEL_INVALID => "Invalid",
+        X2 = ADLX_ANISOTROPIC_FILTERING_LEVEL_AF_LEVEL_X2 => "2X",
+        X4 = ADLX_ANISOTROPIC_FILTERING_LEVEL_AF_LEVEL_X4 => "4X",
+        X8 = ADLX_ANISOTROPIC_FILTERING_LEVEL_AF_LEVEL_X8 => "8X",
+        X16 = ADLX_ANISOTROPIC_FILTERING_LEVEL_AF_LEVEL_X16 => "16X",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__t_e_s_s_e_l_l_a_t_i_o_n__m_o_d_e/>
+    #[doc(alias = "ADLX_TESSELLATION_MODE")]
+    pub enum TessellationMode(ADLX_TESSELLATION_MODE) {
+        AmdOptimized = ADLX_TESSELLATION_MODE_T_MODE_AMD_OPTIMIZED => "AMD optimized",
+        UseAppSettings = ADLX_TESSELLATION_MODE_T_MODE_USE_APP_SETTINGS
+            => "Use application settings",
+        OverrideAppSettings = ADLX_TESSELLATION_MODE_T_MODE_OVERRIDE_APP_SETTINGS
+            => "Override application settings",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__t_e_s_s_e_l_l_a_t_i_o_n__l_e_v_e_l/>
+    #[doc(alias = "ADLX_TESSELLATION_LEVEL")]
+    pub enum TessellationLevel(ADLX_TESSELLATION_LEVEL) {
+        Off = ADLX_TESSELLATION_LEVEL_T_LEVEL_OFF => "Off",
+        X2 = ADLX_TESSELLATION_LEVEL_T_LEVEL_2X => "2X",
+        X4 = ADLX_TESSELLATION_LEVEL_T_LEVEL_4X => "4X",
+        X6 = ADLX_TESSELLATION_LEVEL_T_LEVEL_6X => "6X",
+        X8 = ADLX_TESSELLATION_LEVEL_T_LEVEL_8X => "8X",
+        X16 = ADLX_TESSELLATION_LEVEL_T_LEVEL_16X => "16X",
+        X32 = ADLX_TESSELLATION_LEVEL_T_LEVEL_32X => "32X",
+        X64 = ADLX_TESSELLATION_LEVEL_T_LEVEL_64X => "64X",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__a_n_t_i_l_a_g__s_t_a_t_e/>
+    #[doc(alias = "ADLX_ANTILAG_STATE")]
+    pub enum AntiLagState(ADLX_ANTILAG_STATE) {
+        AntiLag = ADLX_ANTILAG_STATE_ANTILAG => "Anti-Lag",
+        AntiLagNext = ADLX_ANTILAG_STATE_ANTILAGNEXT => "Anti-Lag Next",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__d_i_s_p_l_a_y__t_y_p_e/>
+    #[doc(alias = "ADLX_DISPLAY_TYPE")]
+    pub enum DisplayType(ADLX_DISPLAY_TYPE) {
+        Undefined = ADLX_DISPLAY_TYPE_DISPLAY_TYPE_UNKOWN => "Unknown",
+        Monitor = ADLX_DISPLAY_TYPE_DISPLAY_TYPE_MONITOR => "Monitor",
+        Television = ADLX_DISPLAY_TYPE_DISPLAY_TYPE_TELEVISION => "Television",
+        LcdPanel = ADLX_DISPLAY_TYPE_DISPLAY_TYPE_LCD_PANEL => "LCD panel",
+        DigitalFlatPanel = ADLX_DISPLAY_TYPE_DISPLAY_TYPE_DIGITAL_FLAT_PANEL
+            => "Digital flat panel",
+        ComponentVideo = ADLX_DISPLAY_TYPE_DISPLAY_TYPE_COMPONENT_VIDEO => "Component video",
+        Projector = ADLX_DISPLAY_TYPE_DISPLAY_TYPE_PROJECTOR => "Projector",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__d_i_s_p_l_a_y__c_o_n_n_e_c_t_o_r__t_y_p_e/>
+    #[doc(alias = "ADLX_DISPLAY_CONNECTOR_TYPE")]
+    pub enum DisplayConnectorType(ADLX_DISPLAY_CONNECTOR_TYPE) {
+        Undefined = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_UNKNOWN => "Unknown",
+        Vga = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_VGA => "VGA",
+        DviD = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_DVI_D => "DVI-D",
+        DviI = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_DVI_I => "DVI-I",
+        CvDongleNtsc = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_CVDONGLE_NTSC
+            => "Component video dongle (NTSC)",
+        CvDongleJpn = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_CVDONGLE_JPN
+            => "Component video dongle (JPN)",
+        CvDongleNonI2cJpn = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_CVDONGLE_NONI2C_JPN
+            => "Component video dongle (JPN, non-I2C)",
+        CvDongleNonI2cNtsc = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_CVDONGLE_NONI2C_NTSC
+            => "Component video dongle (NTSC, non-I2C)",
+        Proprietary = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_PROPRIETARY => "Proprietary",
+        HdmiTypeA = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_HDMI_TYPE_A => "HDMI type A",
+        HdmiTypeB = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_HDMI_TYPE_B => "HDMI type B",
+        SVideo = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_SVIDEO => "S-Video",
+        Composite = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_COMPOSITE => "Composite",
+        Rca3Component = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_RCA_3COMPONENT
+            => "RCA 3-component",
+        DisplayPort = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_DISPLAYPORT => "DisplayPort",
+        Edp = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_EDP => "eDP",
+        WirelessDisplay = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_WIRELESSDISPLAY
+            => "Wireless display",
+        UsbTypeC = ADLX_DISPLAY_CONNECTOR_TYPE_DISPLAY_CONTYPE_USB_TYPE_C => "USB type-C",
+    }
+}
+
+adlx_enum! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__a_d_l_x__d_i_s_p_l_a_y__s_c_a_n__t_y_p_e/>
+    #[doc(alias = "ADLX_DISPLAY_SCAN_TYPE")]
+    pub enum DisplayScanType(ADLX_DISPLAY_SCAN_TYPE) {
+        Progressive = ADLX_DISPLAY_SCAN_TYPE_PROGRESSIVE => "Progressive",
+        Interlaced = ADLX_DISPLAY_SCAN_TYPE_INTERLACED => "Interlaced",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GpuType, PciBusType, UnknownEnumValue};
+    use crate::ffi;
+
+    #[test]
+    fn converts_raw_values() {
+        assert_eq!(
+            GpuType::from_raw(ffi::ADLX_GPU_TYPE_GPUTYPE_DISCRETE),
+            GpuType::Discrete
+        );
+        assert_eq!(GpuType::from_raw(42), GpuType::Unknown(42));
+        assert_eq!(i32::from(GpuType::Unknown(42)), 42);
+        assert_eq!(PciBusType::try_from(6), Ok(PciBusType::Pcie4));
+        assert_eq!(
+            PciBusType::try_from(7),
+            Err(UnknownEnumValue {
+                enum_name: "ADLX_PCI_BUS_TYPE",
+                value: 7
+            })
+        );
+        assert_eq!(PciBusType::Pcie4.to_string(), "PCIe 4.0");
+        assert_eq!(GpuType::Unknown(42).to_string(), "Unknown (42)");
+    }
+}
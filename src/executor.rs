@@ -0,0 +1,370 @@
+//! Serialised access to ADLX from a single dedicated thread.
+//!
+//! AMD does not document whether ADLX interfaces may be called from multiple threads at once.
+//! [`AdlxExecutor`] owns an [`AdlxHelper`] on its own thread and runs closures against its
+//! [`System`] one at a time, in the order they were submitted through any [`AdlxHandle`].
+//!
+//! ```no_run
+//! # fn main() -> anyhow::Result<()> {
+//! use adlx::AdlxExecutor;
+//!
+//! let executor = AdlxExecutor::new()?;
+//! let handle = executor.handle();
+//! let worker = std::thread::spawn(move || {
+//!     handle.run(|system| Ok::<_, adlx::Error>(system.gpus()?.size()))
+//! });
+//! let gpu_count = worker.join().unwrap()??;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt,
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{mpsc, Arc, Condvar, Mutex},
+    task::{Context, Poll, Waker},
+    thread::{self, JoinHandle},
+};
+
+use super::{helper::AdlxHelper, system::System};
+
+type Job = Box<dyn FnOnce(&System) + Send>;
+
+enum Message {
+    Run(Job),
+    Shutdown,
+}
+
+/// Error returned when a closure submitted to an [`AdlxExecutor`] did not complete.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutorError {
+    /// The closure was submitted after the executor was dropped
+    Stopped,
+    /// The closure panicked. The executor keeps running other closures.
+    Panicked,
+}
+
+impl fmt::Display for ExecutorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stopped => f.write_str("The ADLX executor has stopped"),
+            Self::Panicked => f.write_str("The closure panicked on the ADLX executor thread"),
+        }
+    }
+}
+
+impl std::error::Error for ExecutorError {}
+
+/// Owns an [`AdlxHelper`] on a dedicated thread, see the [module documentation][self].
+///
+/// Dropping the executor blocks until the closures that were queued before it have run, then
+/// terminates ADLX on its thread. Closures that are submitted afterwards fail with
+/// [`ExecutorError::Stopped`].
+#[derive(Debug)]
+pub struct AdlxExecutor {
+    handle: AdlxHandle,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl AdlxExecutor {
+    /// Initializes ADLX with [`AdlxHelper::new()`] on a new thread.
+    pub fn new() -> anyhow::Result<Self> {
+        Self::with_helper(AdlxHelper::new)
+    }
+
+    /// Creates the [`AdlxHelper`] with `init` on a new thread, for example to serve a
+    /// [`Session`][crate::session::Session] with [`AdlxHelper::replay()`].
+    pub fn with_helper(
+        init: impl FnOnce() -> anyhow::Result<AdlxHelper> + Send + 'static,
+    ) -> anyhow::Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+        let (init_sender, init_receiver) = mpsc::sync_channel(1);
+
+        let thread = thread::Builder::new()
+            .name("adlx-executor".to_owned())
+            .spawn(move || {
+                let helper = match init() {
+                    Ok(helper) => {
+                        let _ = init_sender.send(Ok(()));
+                        helper
+                    }
+                    Err(e) => {
+                        let _ = init_sender.send(Err(e));
+                        return;
+                    }
+                };
+                while let Ok(Message::Run(job)) = receiver.recv() {
+                    job(helper.system());
+                }
+                // Jobs that were queued after `Shutdown` are dropped with the receiver, failing
+                // them with `Stopped`
+            })?;
+
+        match init_receiver.recv() {
+            Ok(Ok(())) => Ok(Self {
+                handle: AdlxHandle { sender },
+                thread: Some(thread),
+            }),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err(anyhow::anyhow!(
+                "The ADLX executor thread panicked while initializing"
+            )),
+        }
+    }
+
+    /// A handle to submit closures from any thread.
+    pub fn handle(&self) -> AdlxHandle {
+        self.handle.clone()
+    }
+}
+
+impl Drop for AdlxExecutor {
+    fn drop(&mut self) {
+        let _ = self.handle.sender.send(Message::Shutdown);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A cloneable, [`Send`] and [`Sync`] handle to submit closures to an [`AdlxExecutor`].
+#[derive(Clone, Debug)]
+pub struct AdlxHandle {
+    sender: mpsc::Sender<Message>,
+}
+
+impl AdlxHandle {
+    /// Queues `f` to run on the executor thread, returning a [`Pending`] result that can be
+    /// awaited or waited for.
+    ///
+    /// ADLX interfaces are not [`Send`], so `f` can not return them to leave the executor thread.
+    /// Return owned values like [`GpuInfo`][crate::GpuInfo] instead:
+    ///
+    /// ```compile_fail
+    /// # fn main() -> anyhow::Result<()> {
+    /// let executor = adlx::AdlxExecutor::new()?;
+    /// let gpu = executor.handle().run(|system| system.gpus()?.at(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn spawn<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&System) -> R + Send + 'static,
+    ) -> Pending<R> {
+        let shared = Arc::new(Shared {
+            slot: Mutex::new(Slot {
+                result: None,
+                waker: None,
+            }),
+            condvar: Condvar::new(),
+        });
+        let completer = Completer(shared.clone());
+        let job: Job = Box::new(move |system| {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(system)))
+                .map_err(|_| ExecutorError::Panicked);
+            completer.complete(result);
+        });
+        // If the executor has stopped, the job is dropped here and completes with `Stopped`
+        let _ = self.sender.send(Message::Run(job));
+        Pending(shared)
+    }
+
+    /// Runs `f` on the executor thread and blocks until it returns.
+    pub fn run<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&System) -> R + Send + 'static,
+    ) -> Result<R, ExecutorError> {
+        self.spawn(f).wait()
+    }
+}
+
+struct Slot<R> {
+    result: Option<Result<R, ExecutorError>>,
+    waker: Option<Waker>,
+}
+
+struct Shared<R> {
+    slot: Mutex<Slot<R>>,
+    condvar: Condvar,
+}
+
+/// Fills the result exactly once, with [`ExecutorError::Stopped`] if the job is dropped without
+/// running.
+struct Completer<R>(Arc<Shared<R>>);
+
+impl<R> Completer<R> {
+    fn complete(&self, result: Result<R, ExecutorError>) {
+        let mut slot = self.0.slot.lock().unwrap();
+        if slot.result.is_none() {
+            slot.result = Some(result);
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+            self.0.condvar.notify_all();
+        }
+    }
+}
+
+impl<R> Drop for Completer<R> {
+    fn drop(&mut self) {
+        self.complete(Err(ExecutorError::Stopped));
+    }
+}
+
+/// The result of a closure submitted with [`AdlxHandle::spawn()`].
+///
+/// Await it from async code, or block on it with [`Pending::wait()`]. Dropping it does not cancel
+/// the closure.
+pub struct Pending<R>(Arc<Shared<R>>);
+
+impl<R> Pending<R> {
+    /// Blocks until the closure has completed.
+    pub fn wait(self) -> Result<R, ExecutorError> {
+        let mut slot = self.0.slot.lock().unwrap();
+        loop {
+            if let Some(result) = slot.result.take() {
+                return result;
+            }
+            slot = self.0.condvar.wait(slot).unwrap();
+        }
+    }
+}
+
+impl<R> Future for Pending<R> {
+    type Output = Result<R, ExecutorError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.0.slot.lock().unwrap();
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<R> fmt::Debug for Pending<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let done = self.0.slot.lock().unwrap().result.is_some();
+        f.debug_struct("Pending").field("done", &done).finish()
+    }
+}
+
+#[cfg(all(test, feature = "replay"))]
+mod tests {
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::{mpsc, Arc},
+        task::{Context, Poll, Wake, Waker},
+        thread::{self, Thread},
+    };
+
+    use super::{AdlxExecutor, ExecutorError};
+    use crate::{replay::tests::session, AdlxHelper};
+
+    fn executor() -> AdlxExecutor {
+        AdlxExecutor::with_helper(|| Ok(AdlxHelper::replay(session()))).unwrap()
+    }
+
+    /// Polls `future` on the current thread, parking until it is woken.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct Unpark(Thread);
+
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn runs_closures_from_many_threads() {
+        let executor = executor();
+        let threads = (0..4)
+            .map(|_| {
+                let handle = executor.handle();
+                thread::spawn(move || {
+                    let name = handle.run(|system| {
+                        system
+                            .gpus()
+                            .unwrap()
+                            .at(0)
+                            .unwrap()
+                            .name()
+                            .unwrap()
+                            .to_owned()
+                    });
+                    let on_executor = handle.run(|_| thread::current().name().map(str::to_owned));
+                    (name, on_executor)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            let (name, on_executor) = thread.join().unwrap();
+            assert_eq!(name.unwrap(), "AMD Radeon RX 7900 XTX");
+            assert_eq!(on_executor.unwrap().as_deref(), Some("adlx-executor"));
+        }
+
+        let handle = executor.handle();
+        assert_eq!(
+            block_on(handle.spawn(|system| system.gpus().unwrap().size())),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn survives_panics_and_stops() {
+        let executor = executor();
+        let handle = executor.handle();
+
+        assert_eq!(
+            handle.run(|_| panic!("boom")),
+            Err::<(), _>(ExecutorError::Panicked)
+        );
+        assert_eq!(handle.run(|_| 1), Ok(1));
+
+        drop(executor);
+        assert_eq!(handle.run(|_| 1), Err(ExecutorError::Stopped));
+        assert_eq!(block_on(handle.spawn(|_| 1)), Err(ExecutorError::Stopped));
+    }
+
+    #[test]
+    fn runs_queued_closures_before_stopping() {
+        let executor = executor();
+        let handle = executor.handle();
+        let (release, gate) = mpsc::channel();
+        let blocked = handle.spawn(move |_| gate.recv().unwrap());
+        let queued = handle.spawn(|_| 2);
+
+        // Shutdown is queued behind both closures
+        let dropper = thread::spawn(move || drop(executor));
+        release.send(1).unwrap();
+        dropper.join().unwrap();
+
+        assert_eq!(blocked.wait(), Ok(1));
+        assert_eq!(queued.wait(), Ok(2));
+        assert_eq!(handle.run(|_| 3), Err(ExecutorError::Stopped));
+    }
+
+    #[test]
+    fn reports_init_errors() {
+        let error = AdlxExecutor::with_helper(|| anyhow::bail!("No driver")).unwrap_err();
+        assert_eq!(error.to_string(), "No driver");
+    }
+}
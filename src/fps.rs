@@ -0,0 +1,74 @@
+use std::mem::MaybeUninit;
+
+use super::{
+    ffi,
+    interface::{Interface, InterfaceImpl},
+    list::{list_item, AdlxList},
+    result::{Error, Result},
+};
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_f_p_s/>
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc(alias = "IADLXFPS")]
+pub struct Fps(InterfaceImpl);
+
+unsafe impl Interface for Fps {
+    type Impl = ffi::IADLXFPS;
+    type Vtable = ffi::IADLXFPSVtbl;
+    const IID: &'static str = "IADLXFPS";
+}
+
+impl Fps {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_f_p_s__time_stamp/>
+    #[doc(alias = "TimeStamp")]
+    pub fn time_stamp(&self) -> Result<i64> {
+        let mut time_stamp = MaybeUninit::uninit();
+        let result =
+            unsafe { (self.vtable().TimeStamp.unwrap())(self.as_raw(), time_stamp.as_mut_ptr()) };
+
+        Error::from_result_with_assume_init_on_success(result, time_stamp)
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_f_p_s__f_p_s/>
+    #[doc(alias = "FPS")]
+    pub fn fps(&self) -> Result<i32> {
+        let mut fps = MaybeUninit::uninit();
+        let result = unsafe { (self.vtable().FPS.unwrap())(self.as_raw(), fps.as_mut_ptr()) };
+
+        Error::from_result_with_assume_init_on_success(result, fps)
+    }
+
+    /// Retrieves both values at once.
+    pub fn sample(&self) -> Result<FpsSample> {
+        Ok(FpsSample {
+            time_stamp: self.time_stamp()?,
+            fps: self.fps()?,
+        })
+    }
+}
+
+/// An owned snapshot of [`Fps`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FpsSample {
+    /// Time stamp of the sample in milliseconds
+    pub time_stamp: i64,
+    pub fps: i32,
+}
+
+list_item!(Fps => IADLXFPSList(IADLXFPSListVtbl), At_FPSList, Add_Back_FPSList);
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_f_p_s_list/>
+#[doc(alias = "IADLXFPSList")]
+#[doc(alias = "At_FPSList")]
+#[doc(alias = "Add_Back_FPSList")]
+pub type FpsList = AdlxList<Fps>;
+
+impl FpsList {
+    /// Retrieves [`Fps::sample()`] of every element in this list.
+    pub fn samples(&self) -> Result<Vec<FpsSample>> {
+        self.try_iter()
+            .map(|fps| fps.and_then(|fps| fps.sample()))
+            .collect()
+    }
+}
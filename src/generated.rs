@@ -0,0 +1,11 @@
+//! Safe wrappers generated by `api_gen` for the interfaces that are not wrapped by hand, and the
+//! methods that hand-written wrappers leave out, like the settings getters of
+//! [`ThreeDSettingsServices`][crate::ThreeDSettingsServices].
+//!
+//! Like [`ffi`][crate::ffi], every supported SDK version has its own module, selected with an
+//! `sdk-*` feature. Its wrappers are re-exported from the crate root.
+
+#[cfg(feature = "sdk-1-4")]
+mod sdk_1_4;
+#[cfg(feature = "sdk-1-4")]
+pub use sdk_1_4::*;
@@ -0,0 +1,1553 @@
+//! Wrappers generated by `api_gen` from the ADLX SDK 1.4 bindings, for the interfaces
+//! that are not wrapped by hand, and the methods that hand-written wrappers leave out. Do not
+//! edit this file, run `cargo run -p api_gen` instead.
+
+use std::{ffi::CStr, mem::MaybeUninit};
+
+use crate::{
+    ffi,
+    interface::{adlx_interface, Interface},
+    list::{list_item, AdlxList},
+    result::{Error, Result},
+    version::{check_interface, thread_runtime_version, AdlxVersion},
+    AnisotropicFilteringLevel, AntiAliasingLevel, AntiAliasingMethod, AntiAliasingMode,
+    AntiLagState, ColorDepth, Display, DisplayConnectorType, DisplayFreeSync, DisplayScanType,
+    DisplayServices, DisplayType, Fps, Gpu, GpuMetrics, PerformanceMonitoringServices, PixelFormat,
+    ScaleMode, TessellationLevel, TessellationMode, ThreeDSettingsServices,
+};
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_support/>
+    #[doc(alias = "IADLXSystemMetricsSupport")]
+    pub struct SystemMetricsSupport: IADLXSystemMetricsSupport(IADLXSystemMetricsSupportVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_support__is_supported_c_p_u_usage/>
+        #[doc(alias = "IsSupportedCPUUsage")]
+        fn is_supported_cpu_usage() -> bool = IsSupportedCPUUsage;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_support__is_supported_system_r_a_m/>
+        #[doc(alias = "IsSupportedSystemRAM")]
+        fn is_supported_system_ram() -> bool = IsSupportedSystemRAM;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_support__is_supported_smart_shift/>
+        #[doc(alias = "IsSupportedSmartShift")]
+        fn is_supported_smart_shift() -> bool = IsSupportedSmartShift;
+    }
+}
+
+impl SystemMetricsSupport {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_support__get_c_p_u_usage_range/>
+    #[doc(alias = "GetCPUUsageRange")]
+    pub fn get_cpu_usage_range(&self) -> Result<(i32, i32)> {
+        let mut min_value = MaybeUninit::uninit();
+        let mut max_value = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetCPUUsageRange.unwrap())(
+                self.as_raw(),
+                min_value.as_mut_ptr(),
+                max_value.as_mut_ptr(),
+            )
+        };
+        Error::from_result(result)
+            .map(|()| unsafe { (min_value.assume_init(), max_value.assume_init()) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_support__get_system_r_a_m_range/>
+    #[doc(alias = "GetSystemRAMRange")]
+    pub fn get_system_ram_range(&self) -> Result<(i32, i32)> {
+        let mut min_value = MaybeUninit::uninit();
+        let mut max_value = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetSystemRAMRange.unwrap())(
+                self.as_raw(),
+                min_value.as_mut_ptr(),
+                max_value.as_mut_ptr(),
+            )
+        };
+        Error::from_result(result)
+            .map(|()| unsafe { (min_value.assume_init(), max_value.assume_init()) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_support__get_smart_shift_range/>
+    #[doc(alias = "GetSmartShiftRange")]
+    pub fn get_smart_shift_range(&self) -> Result<(i32, i32)> {
+        let mut min_value = MaybeUninit::uninit();
+        let mut max_value = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetSmartShiftRange.unwrap())(
+                self.as_raw(),
+                min_value.as_mut_ptr(),
+                max_value.as_mut_ptr(),
+            )
+        };
+        Error::from_result(result)
+            .map(|()| unsafe { (min_value.assume_init(), max_value.assume_init()) })
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics/>
+    #[doc(alias = "IADLXSystemMetrics")]
+    pub struct SystemMetrics: IADLXSystemMetrics(IADLXSystemMetricsVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics__time_stamp/>
+        #[doc(alias = "TimeStamp")]
+        fn time_stamp() -> i64 = TimeStamp;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics__c_p_u_usage/>
+        #[doc(alias = "CPUUsage")]
+        fn cpu_usage() -> f64 = CPUUsage;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics__system_r_a_m/>
+        #[doc(alias = "SystemRAM")]
+        fn system_ram() -> i32 = SystemRAM;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics__smart_shift/>
+        #[doc(alias = "SmartShift")]
+        fn smart_shift() -> i32 = SmartShift;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_all_metrics/>
+    #[doc(alias = "IADLXAllMetrics")]
+    pub struct AllMetrics: IADLXAllMetrics(IADLXAllMetricsVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_all_metrics__time_stamp/>
+        #[doc(alias = "TimeStamp")]
+        fn time_stamp() -> i64 = TimeStamp;
+    }
+}
+
+impl AllMetrics {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_all_metrics__get_system_metrics/>
+    #[doc(alias = "GetSystemMetrics")]
+    pub fn get_system_metrics(&self) -> Result<SystemMetrics> {
+        check_interface::<SystemMetrics>(thread_runtime_version())?;
+        let mut metrics = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetSystemMetrics.unwrap())(self.as_raw(), metrics.as_mut_ptr())
+        };
+        Error::from_result_with_assume_init_on_success(result, metrics)
+            .map(|metrics| unsafe { SystemMetrics::from_raw(metrics) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_all_metrics__get_f_p_s/>
+    #[doc(alias = "GetFPS")]
+    pub fn get_fps(&self) -> Result<Fps> {
+        check_interface::<Fps>(thread_runtime_version())?;
+        let mut metrics = MaybeUninit::uninit();
+        let result =
+            unsafe { (self.vtable().GetFPS.unwrap())(self.as_raw(), metrics.as_mut_ptr()) };
+        Error::from_result_with_assume_init_on_success(result, metrics)
+            .map(|metrics| unsafe { Fps::from_raw(metrics) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_all_metrics__get_g_p_u_metrics/>
+    #[doc(alias = "GetGPUMetrics")]
+    pub fn get_gpu_metrics(&self, gpu: &Gpu) -> Result<GpuMetrics> {
+        check_interface::<GpuMetrics>(thread_runtime_version())?;
+        let mut metrics = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetGPUMetrics.unwrap())(
+                self.as_raw(),
+                gpu.as_raw(),
+                metrics.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, metrics)
+            .map(|metrics| unsafe { GpuMetrics::from_raw(metrics) })
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_support1/>
+    #[doc(alias = "IADLXSystemMetricsSupport1")]
+    pub struct SystemMetricsSupport1: IADLXSystemMetricsSupport1(IADLXSystemMetricsSupport1Vtbl): SystemMetricsSupport {
+        const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 2);
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_support1__is_supported_power_distribution/>
+        #[doc(alias = "IsSupportedPowerDistribution")]
+        fn is_supported_power_distribution() -> bool = IsSupportedPowerDistribution;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics1/>
+    #[doc(alias = "IADLXSystemMetrics1")]
+    pub struct SystemMetrics1: IADLXSystemMetrics1(IADLXSystemMetrics1Vtbl): SystemMetrics {
+        const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 2);
+    }
+}
+
+impl SystemMetrics1 {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics1__power_distribution/>
+    #[doc(alias = "PowerDistribution")]
+    pub fn power_distribution(&self) -> Result<(i32, i32, i32, i32, i32)> {
+        let mut apu_shift_value = MaybeUninit::uninit();
+        let mut gpu_shift_value = MaybeUninit::uninit();
+        let mut apu_shift_limit = MaybeUninit::uninit();
+        let mut gpu_shift_limit = MaybeUninit::uninit();
+        let mut total_shift_limit = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().PowerDistribution.unwrap())(
+                self.as_raw(),
+                apu_shift_value.as_mut_ptr(),
+                gpu_shift_value.as_mut_ptr(),
+                apu_shift_limit.as_mut_ptr(),
+                gpu_shift_limit.as_mut_ptr(),
+                total_shift_limit.as_mut_ptr(),
+            )
+        };
+        Error::from_result(result).map(|()| unsafe {
+            (
+                apu_shift_value.assume_init(),
+                gpu_shift_value.assume_init(),
+                apu_shift_limit.assume_init(),
+                gpu_shift_limit.assume_init(),
+                total_shift_limit.assume_init(),
+            )
+        })
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_lag/>
+    #[doc(alias = "IADLX3DAntiLag")]
+    pub struct ThreeDAntiLag: IADLX3DAntiLag(IADLX3DAntiLagVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_lag__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_lag__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_lag__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enable: bool) = SetEnabled;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_lag1/>
+    #[doc(alias = "IADLX3DAntiLag1")]
+    pub struct ThreeDAntiLag1: IADLX3DAntiLag1(IADLX3DAntiLag1Vtbl): ThreeDAntiLag {
+        const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 2);
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_lag1__get_level/>
+        #[doc(alias = "GetLevel")]
+        fn get_level() -> AntiLagState = GetLevel;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_lag1__set_level/>
+        #[doc(alias = "SetLevel")]
+        fn set_level(level: AntiLagState) = SetLevel;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_boost/>
+    #[doc(alias = "IADLX3DBoost")]
+    pub struct ThreeDBoost: IADLX3DBoost(IADLX3DBoostVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_boost__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_boost__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_boost__get_resolution_range/>
+        #[doc(alias = "GetResolutionRange")]
+        fn get_resolution_range() -> ffi::ADLX_IntRange = GetResolutionRange;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_boost__get_resolution/>
+        #[doc(alias = "GetResolution")]
+        fn get_resolution() -> i32 = GetResolution;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_boost__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enable: bool) = SetEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_boost__set_resolution/>
+        #[doc(alias = "SetResolution")]
+        fn set_resolution(min_res: i32) = SetResolution;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_image_sharpening/>
+    #[doc(alias = "IADLX3DImageSharpening")]
+    pub struct ThreeDImageSharpening: IADLX3DImageSharpening(IADLX3DImageSharpeningVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_image_sharpening__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_image_sharpening__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_image_sharpening__get_sharpness_range/>
+        #[doc(alias = "GetSharpnessRange")]
+        fn get_sharpness_range() -> ffi::ADLX_IntRange = GetSharpnessRange;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_image_sharpening__get_sharpness/>
+        #[doc(alias = "GetSharpness")]
+        fn get_sharpness() -> i32 = GetSharpness;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_image_sharpening__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enable: bool) = SetEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_image_sharpening__set_sharpness/>
+        #[doc(alias = "SetSharpness")]
+        fn set_sharpness(sharpness: i32) = SetSharpness;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_enhanced_sync/>
+    #[doc(alias = "IADLX3DEnhancedSync")]
+    pub struct ThreeDEnhancedSync: IADLX3DEnhancedSync(IADLX3DEnhancedSyncVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_enhanced_sync__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_enhanced_sync__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_enhanced_sync__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enable: bool) = SetEnabled;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_aliasing/>
+    #[doc(alias = "IADLX3DAntiAliasing")]
+    pub struct ThreeDAntiAliasing: IADLX3DAntiAliasing(IADLX3DAntiAliasingVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_aliasing__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_aliasing__get_mode/>
+        #[doc(alias = "GetMode")]
+        fn get_mode() -> AntiAliasingMode = GetMode;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_aliasing__get_level/>
+        #[doc(alias = "GetLevel")]
+        fn get_level() -> AntiAliasingLevel = GetLevel;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_aliasing__get_method/>
+        #[doc(alias = "GetMethod")]
+        fn get_method() -> AntiAliasingMethod = GetMethod;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_aliasing__set_mode/>
+        #[doc(alias = "SetMode")]
+        fn set_mode(mode: AntiAliasingMode) = SetMode;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_aliasing__set_level/>
+        #[doc(alias = "SetLevel")]
+        fn set_level(level: AntiAliasingLevel) = SetLevel;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anti_aliasing__set_method/>
+        #[doc(alias = "SetMethod")]
+        fn set_method(method: AntiAliasingMethod) = SetMethod;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_morphological_anti_aliasing/>
+    #[doc(alias = "IADLX3DMorphologicalAntiAliasing")]
+    pub struct ThreeDMorphologicalAntiAliasing: IADLX3DMorphologicalAntiAliasing(IADLX3DMorphologicalAntiAliasingVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_morphological_anti_aliasing__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_morphological_anti_aliasing__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_morphological_anti_aliasing__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enable: bool) = SetEnabled;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anisotropic_filtering/>
+    #[doc(alias = "IADLX3DAnisotropicFiltering")]
+    pub struct ThreeDAnisotropicFiltering: IADLX3DAnisotropicFiltering(IADLX3DAnisotropicFilteringVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anisotropic_filtering__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anisotropic_filtering__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anisotropic_filtering__get_level/>
+        #[doc(alias = "GetLevel")]
+        fn get_level() -> AnisotropicFilteringLevel = GetLevel;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anisotropic_filtering__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enable: bool) = SetEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_anisotropic_filtering__set_level/>
+        #[doc(alias = "SetLevel")]
+        fn set_level(level: AnisotropicFilteringLevel) = SetLevel;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_tessellation/>
+    #[doc(alias = "IADLX3DTessellation")]
+    pub struct ThreeDTessellation: IADLX3DTessellation(IADLX3DTessellationVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_tessellation__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_tessellation__get_mode/>
+        #[doc(alias = "GetMode")]
+        fn get_mode() -> TessellationMode = GetMode;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_tessellation__get_level/>
+        #[doc(alias = "GetLevel")]
+        fn get_level() -> TessellationLevel = GetLevel;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_tessellation__set_mode/>
+        #[doc(alias = "SetMode")]
+        fn set_mode(mode: TessellationMode) = SetMode;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_tessellation__set_level/>
+        #[doc(alias = "SetLevel")]
+        fn set_level(level: TessellationLevel) = SetLevel;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_radeon_super_resolution/>
+    #[doc(alias = "IADLX3DRadeonSuperResolution")]
+    pub struct ThreeDRadeonSuperResolution: IADLX3DRadeonSuperResolution(IADLX3DRadeonSuperResolutionVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_radeon_super_resolution__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_radeon_super_resolution__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_radeon_super_resolution__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enable: bool) = SetEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_radeon_super_resolution__get_sharpness_range/>
+        #[doc(alias = "GetSharpnessRange")]
+        fn get_sharpness_range() -> ffi::ADLX_IntRange = GetSharpnessRange;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_radeon_super_resolution__get_sharpness/>
+        #[doc(alias = "GetSharpness")]
+        fn get_sharpness() -> i32 = GetSharpness;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_radeon_super_resolution__set_sharpness/>
+        #[doc(alias = "SetSharpness")]
+        fn set_sharpness(sharpness: i32) = SetSharpness;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_reset_shader_cache/>
+    #[doc(alias = "IADLX3DResetShaderCache")]
+    pub struct ThreeDResetShaderCache: IADLX3DResetShaderCache(IADLX3DResetShaderCacheVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_reset_shader_cache__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+    }
+}
+
+impl ThreeDResetShaderCache {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_reset_shader_cache__reset_shader_cache/>
+    #[doc(alias = "ResetShaderCache")]
+    pub fn reset_shader_cache(&self) -> Result<()> {
+        let result = unsafe { (self.vtable().ResetShaderCache.unwrap())(self.as_raw()) };
+        Error::from_result(result)
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_changed_handling/>
+    #[doc(alias = "IADLX3DSettingsChangedHandling")]
+    pub struct ThreeDSettingsChangedHandling: IADLX3DSettingsChangedHandling(IADLX3DSettingsChangedHandlingVtbl) {}
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_v_s_r/>
+    #[doc(alias = "IADLXDisplayVSR")]
+    pub struct DisplayVsr: IADLXDisplayVSR(IADLXVSRVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_v_s_r__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_v_s_r__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_v_s_r__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enabled: bool) = SetEnabled;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_g_p_u_scaling/>
+    #[doc(alias = "IADLXDisplayGPUScaling")]
+    pub struct DisplayGpuScaling: IADLXDisplayGPUScaling(IADLXGPUScalingVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_g_p_u_scaling__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_g_p_u_scaling__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_g_p_u_scaling__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enabled: bool) = SetEnabled;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_scaling_mode/>
+    #[doc(alias = "IADLXDisplayScalingMode")]
+    pub struct DisplayScalingMode: IADLXDisplayScalingMode(IADLXDisplayScalingModeVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_scaling_mode__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_scaling_mode__get_mode/>
+        #[doc(alias = "GetMode")]
+        fn get_mode() -> ScaleMode = GetMode;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_scaling_mode__set_mode/>
+        #[doc(alias = "SetMode")]
+        fn set_mode(mode: ScaleMode) = SetMode;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_integer_scaling/>
+    #[doc(alias = "IADLXDisplayIntegerScaling")]
+    pub struct DisplayIntegerScaling: IADLXDisplayIntegerScaling(IADLXIntegerScalingVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_integer_scaling__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_integer_scaling__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_integer_scaling__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enabled: bool) = SetEnabled;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth/>
+    #[doc(alias = "IADLXDisplayColorDepth")]
+    pub struct DisplayColorDepth: IADLXDisplayColorDepth(IADLX_COLOR_DEPTHVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__get_value/>
+        #[doc(alias = "GetValue")]
+        fn get_value() -> ColorDepth = GetValue;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__set_value/>
+        #[doc(alias = "SetValue")]
+        fn set_value(color_depth: ColorDepth) = SetValue;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__is_supported_b_p_c_6/>
+        #[doc(alias = "IsSupportedBPC_6")]
+        fn is_supported_bpc_6() -> bool = IsSupportedBPC_6;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__is_supported_b_p_c_8/>
+        #[doc(alias = "IsSupportedBPC_8")]
+        fn is_supported_bpc_8() -> bool = IsSupportedBPC_8;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__is_supported_b_p_c_10/>
+        #[doc(alias = "IsSupportedBPC_10")]
+        fn is_supported_bpc_10() -> bool = IsSupportedBPC_10;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__is_supported_b_p_c_12/>
+        #[doc(alias = "IsSupportedBPC_12")]
+        fn is_supported_bpc_12() -> bool = IsSupportedBPC_12;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__is_supported_b_p_c_14/>
+        #[doc(alias = "IsSupportedBPC_14")]
+        fn is_supported_bpc_14() -> bool = IsSupportedBPC_14;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__is_supported_b_p_c_16/>
+        #[doc(alias = "IsSupportedBPC_16")]
+        fn is_supported_bpc_16() -> bool = IsSupportedBPC_16;
+    }
+}
+
+impl DisplayColorDepth {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_color_depth__is_supported_color_depth/>
+    #[doc(alias = "IsSupportedColorDepth")]
+    pub fn is_supported_color_depth(&self, color_depth: ColorDepth) -> Result<bool> {
+        let mut supported = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().IsSupportedColorDepth.unwrap())(
+                self.as_raw(),
+                color_depth.into_raw(),
+                supported.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, supported)
+            .map(|supported| supported != 0)
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format/>
+    #[doc(alias = "IADLXDisplayPixelFormat")]
+    pub struct DisplayPixelFormat: IADLXDisplayPixelFormat(IADLX_PIXEL_FORMATVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format__get_value/>
+        #[doc(alias = "GetValue")]
+        fn get_value() -> PixelFormat = GetValue;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format__set_value/>
+        #[doc(alias = "SetValue")]
+        fn set_value(pixel_format: PixelFormat) = SetValue;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format__is_supported_r_g_b444_full/>
+        #[doc(alias = "IsSupportedRGB444Full")]
+        fn is_supported_rgb444_full() -> bool = IsSupportedRGB444Full;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format__is_supported_y_cb_cr444/>
+        #[doc(alias = "IsSupportedYCbCr444")]
+        fn is_supported_y_cb_cr444() -> bool = IsSupportedYCbCr444;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format__is_supported_y_cb_cr422/>
+        #[doc(alias = "IsSupportedYCbCr422")]
+        fn is_supported_y_cb_cr422() -> bool = IsSupportedYCbCr422;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format__is_supported_r_g_b444_limited/>
+        #[doc(alias = "IsSupportedRGB444Limited")]
+        fn is_supported_rgb444_limited() -> bool = IsSupportedRGB444Limited;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format__is_supported_y_cb_cr420/>
+        #[doc(alias = "IsSupportedYCbCr420")]
+        fn is_supported_y_cb_cr420() -> bool = IsSupportedYCbCr420;
+    }
+}
+
+impl DisplayPixelFormat {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_pixel_format__is_supported_pixel_format/>
+    #[doc(alias = "IsSupportedPixelFormat")]
+    pub fn is_supported_pixel_format(&self, pixel_format: PixelFormat) -> Result<bool> {
+        let mut supportd = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().IsSupportedPixelFormat.unwrap())(
+                self.as_raw(),
+                pixel_format.into_raw(),
+                supportd.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, supportd)
+            .map(|supportd| supportd != 0)
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color/>
+    #[doc(alias = "IADLXDisplayCustomColor")]
+    pub struct DisplayCustomColor: IADLXDisplayCustomColor(IADLXCustomColorVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__is_hue_supported/>
+        #[doc(alias = "IsHueSupported")]
+        fn is_hue_supported() -> bool = IsHueSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_hue_range/>
+        #[doc(alias = "GetHueRange")]
+        fn get_hue_range() -> ffi::ADLX_IntRange = GetHueRange;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_hue/>
+        #[doc(alias = "GetHue")]
+        fn get_hue() -> i32 = GetHue;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__set_hue/>
+        #[doc(alias = "SetHue")]
+        fn set_hue(hue: i32) = SetHue;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__is_saturation_supported/>
+        #[doc(alias = "IsSaturationSupported")]
+        fn is_saturation_supported() -> bool = IsSaturationSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_saturation_range/>
+        #[doc(alias = "GetSaturationRange")]
+        fn get_saturation_range() -> ffi::ADLX_IntRange = GetSaturationRange;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_saturation/>
+        #[doc(alias = "GetSaturation")]
+        fn get_saturation() -> i32 = GetSaturation;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__set_saturation/>
+        #[doc(alias = "SetSaturation")]
+        fn set_saturation(saturation: i32) = SetSaturation;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__is_brightness_supported/>
+        #[doc(alias = "IsBrightnessSupported")]
+        fn is_brightness_supported() -> bool = IsBrightnessSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_brightness_range/>
+        #[doc(alias = "GetBrightnessRange")]
+        fn get_brightness_range() -> ffi::ADLX_IntRange = GetBrightnessRange;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_brightness/>
+        #[doc(alias = "GetBrightness")]
+        fn get_brightness() -> i32 = GetBrightness;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__set_brightness/>
+        #[doc(alias = "SetBrightness")]
+        fn set_brightness(brightness: i32) = SetBrightness;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__is_contrast_supported/>
+        #[doc(alias = "IsContrastSupported")]
+        fn is_contrast_supported() -> bool = IsContrastSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_contrast_range/>
+        #[doc(alias = "GetContrastRange")]
+        fn get_contrast_range() -> ffi::ADLX_IntRange = GetContrastRange;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_contrast/>
+        #[doc(alias = "GetContrast")]
+        fn get_contrast() -> i32 = GetContrast;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__set_contrast/>
+        #[doc(alias = "SetContrast")]
+        fn set_contrast(contrast: i32) = SetContrast;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__is_temperature_supported/>
+        #[doc(alias = "IsTemperatureSupported")]
+        fn is_temperature_supported() -> bool = IsTemperatureSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_temperature_range/>
+        #[doc(alias = "GetTemperatureRange")]
+        fn get_temperature_range() -> ffi::ADLX_IntRange = GetTemperatureRange;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__get_temperature/>
+        #[doc(alias = "GetTemperature")]
+        fn get_temperature() -> i32 = GetTemperature;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_color__set_temperature/>
+        #[doc(alias = "SetTemperature")]
+        fn set_temperature(temperature: i32) = SetTemperature;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_h_d_c_p/>
+    #[doc(alias = "IADLXDisplayHDCP")]
+    pub struct DisplayHdcp: IADLXDisplayHDCP(IADLXHDCPVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_h_d_c_p__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_h_d_c_p__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_h_d_c_p__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enabled: bool) = SetEnabled;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_resolution/>
+    #[doc(alias = "IADLXDisplayResolution")]
+    pub struct DisplayResolution: IADLXDisplayResolution(IADLXDisplayResolutionVtbl) {}
+}
+
+impl DisplayResolution {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_resolution__get_value/>
+    #[doc(alias = "GetValue")]
+    pub fn get_value(&self) -> Result<ffi::ADLX_CustomResolution> {
+        let mut cr = MaybeUninit::uninit();
+        let result = unsafe { (self.vtable().GetValue.unwrap())(self.as_raw(), cr.as_mut_ptr()) };
+        Error::from_result_with_assume_init_on_success(result, cr)
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_resolution__set_value/>
+    #[doc(alias = "SetValue")]
+    pub fn set_value(&mut self, cr: ffi::ADLX_CustomResolution) -> Result<()> {
+        let result = unsafe { (self.vtable().SetValue.unwrap())(self.as_raw(), cr) };
+        Error::from_result(result)
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_resolution/>
+    #[doc(alias = "IADLXDisplayCustomResolution")]
+    pub struct DisplayCustomResolution: IADLXDisplayCustomResolution(IADLXDisplayCustomResolutionVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_resolution__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+    }
+}
+
+impl DisplayCustomResolution {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_resolution__get_resolution_list/>
+    #[doc(alias = "GetResolutionList")]
+    pub fn get_resolution_list(&self) -> Result<AdlxList<DisplayResolution>> {
+        check_interface::<AdlxList<DisplayResolution>>(thread_runtime_version())?;
+        let mut resolution_list = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetResolutionList.unwrap())(self.as_raw(), resolution_list.as_mut_ptr())
+        };
+        Error::from_result_with_assume_init_on_success(result, resolution_list).map(
+            |resolution_list| unsafe { AdlxList::<DisplayResolution>::from_raw(resolution_list) },
+        )
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_resolution__get_current_applied_resolution/>
+    #[doc(alias = "GetCurrentAppliedResolution")]
+    pub fn get_current_applied_resolution(&self) -> Result<DisplayResolution> {
+        check_interface::<DisplayResolution>(thread_runtime_version())?;
+        let mut resolution = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetCurrentAppliedResolution.unwrap())(
+                self.as_raw(),
+                resolution.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, resolution)
+            .map(|resolution| unsafe { DisplayResolution::from_raw(resolution) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_resolution__create_new_resolution/>
+    #[doc(alias = "CreateNewResolution")]
+    pub fn create_new_resolution(&self, resolution: &DisplayResolution) -> Result<()> {
+        let result = unsafe {
+            (self.vtable().CreateNewResolution.unwrap())(self.as_raw(), resolution.as_raw())
+        };
+        Error::from_result(result)
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_custom_resolution__delete_resolution/>
+    #[doc(alias = "DeleteResolution")]
+    pub fn delete_resolution(&self, resolution: &DisplayResolution) -> Result<()> {
+        let result = unsafe {
+            (self.vtable().DeleteResolution.unwrap())(self.as_raw(), resolution.as_raw())
+        };
+        Error::from_result(result)
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright/>
+    #[doc(alias = "IADLXDisplayVariBright")]
+    pub struct DisplayVariBright: IADLXDisplayVariBright(IADLXDisplayVariBrightVtbl) {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__is_supported/>
+        #[doc(alias = "IsSupported")]
+        fn is_supported() -> bool = IsSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__is_enabled/>
+        #[doc(alias = "IsEnabled")]
+        fn is_enabled() -> bool = IsEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enabled: bool) = SetEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__is_current_maximize_brightness/>
+        #[doc(alias = "IsCurrentMaximizeBrightness")]
+        fn is_current_maximize_brightness() -> bool = IsCurrentMaximizeBrightness;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__is_current_optimize_brightness/>
+        #[doc(alias = "IsCurrentOptimizeBrightness")]
+        fn is_current_optimize_brightness() -> bool = IsCurrentOptimizeBrightness;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__is_current_balanced/>
+        #[doc(alias = "IsCurrentBalanced")]
+        fn is_current_balanced() -> bool = IsCurrentBalanced;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__is_current_optimize_battery/>
+        #[doc(alias = "IsCurrentOptimizeBattery")]
+        fn is_current_optimize_battery() -> bool = IsCurrentOptimizeBattery;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__is_current_maximize_battery/>
+        #[doc(alias = "IsCurrentMaximizeBattery")]
+        fn is_current_maximize_battery() -> bool = IsCurrentMaximizeBattery;
+    }
+}
+
+impl DisplayVariBright {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__set_maximize_brightness/>
+    #[doc(alias = "SetMaximizeBrightness")]
+    pub fn set_maximize_brightness(&mut self) -> Result<()> {
+        let result = unsafe { (self.vtable().SetMaximizeBrightness.unwrap())(self.as_raw()) };
+        Error::from_result(result)
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__set_optimize_brightness/>
+    #[doc(alias = "SetOptimizeBrightness")]
+    pub fn set_optimize_brightness(&mut self) -> Result<()> {
+        let result = unsafe { (self.vtable().SetOptimizeBrightness.unwrap())(self.as_raw()) };
+        Error::from_result(result)
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__set_balanced/>
+    #[doc(alias = "SetBalanced")]
+    pub fn set_balanced(&mut self) -> Result<()> {
+        let result = unsafe { (self.vtable().SetBalanced.unwrap())(self.as_raw()) };
+        Error::from_result(result)
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__set_optimize_battery/>
+    #[doc(alias = "SetOptimizeBattery")]
+    pub fn set_optimize_battery(&mut self) -> Result<()> {
+        let result = unsafe { (self.vtable().SetOptimizeBattery.unwrap())(self.as_raw()) };
+        Error::from_result(result)
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright__set_maximize_battery/>
+    #[doc(alias = "SetMaximizeBattery")]
+    pub fn set_maximize_battery(&mut self) -> Result<()> {
+        let result = unsafe { (self.vtable().SetMaximizeBattery.unwrap())(self.as_raw()) };
+        Error::from_result(result)
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1/>
+    #[doc(alias = "IADLXDisplayVariBright1")]
+    pub struct DisplayVariBright1: IADLXDisplayVariBright1(IADLXDisplayVariBright1Vtbl): DisplayVariBright {
+        const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 2);
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__is_backlight_adaptive_supported/>
+        #[doc(alias = "IsBacklightAdaptiveSupported")]
+        fn is_backlight_adaptive_supported() -> bool = IsBacklightAdaptiveSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__is_backlight_adaptive_enabled/>
+        #[doc(alias = "IsBacklightAdaptiveEnabled")]
+        fn is_backlight_adaptive_enabled() -> bool = IsBacklightAdaptiveEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__set_backlight_adaptive_enabled/>
+        #[doc(alias = "SetBacklightAdaptiveEnabled")]
+        fn set_backlight_adaptive_enabled(enabled: bool) = SetBacklightAdaptiveEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__is_battery_life_supported/>
+        #[doc(alias = "IsBatteryLifeSupported")]
+        fn is_battery_life_supported() -> bool = IsBatteryLifeSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__is_battery_life_enabled/>
+        #[doc(alias = "IsBatteryLifeEnabled")]
+        fn is_battery_life_enabled() -> bool = IsBatteryLifeEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__set_battery_life_enabled/>
+        #[doc(alias = "SetBatteryLifeEnabled")]
+        fn set_battery_life_enabled(enabled: bool) = SetBatteryLifeEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__is_windows_power_mode_supported/>
+        #[doc(alias = "IsWindowsPowerModeSupported")]
+        fn is_windows_power_mode_supported() -> bool = IsWindowsPowerModeSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__is_windows_power_mode_enabled/>
+        #[doc(alias = "IsWindowsPowerModeEnabled")]
+        fn is_windows_power_mode_enabled() -> bool = IsWindowsPowerModeEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__set_windows_power_mode_enabled/>
+        #[doc(alias = "SetWindowsPowerModeEnabled")]
+        fn set_windows_power_mode_enabled(enabled: bool) = SetWindowsPowerModeEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__is_full_screen_video_supported/>
+        #[doc(alias = "IsFullScreenVideoSupported")]
+        fn is_full_screen_video_supported() -> bool = IsFullScreenVideoSupported;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__is_full_screen_video_enabled/>
+        #[doc(alias = "IsFullScreenVideoEnabled")]
+        fn is_full_screen_video_enabled() -> bool = IsFullScreenVideoEnabled;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_vari_bright1__set_full_screen_video_enabled/>
+        #[doc(alias = "SetFullScreenVideoEnabled")]
+        fn set_full_screen_video_enabled(enabled: bool) = SetFullScreenVideoEnabled;
+    }
+}
+
+adlx_interface! {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_changed_handling/>
+    #[doc(alias = "IADLXDisplayChangedHandling")]
+    pub struct DisplayChangedHandling: IADLXDisplayChangedHandling(IADLXDisplayChangedHandlingVtbl) {}
+}
+
+list_item!(SystemMetrics => IADLXSystemMetricsList(IADLXSystemMetricsListVtbl), At_SystemMetricsList, Add_Back_SystemMetricsList);
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system_metrics_list/>
+#[doc(alias = "IADLXSystemMetricsList")]
+#[doc(alias = "At_SystemMetricsList")]
+#[doc(alias = "Add_Back_SystemMetricsList")]
+pub type SystemMetricsList = AdlxList<SystemMetrics>;
+
+list_item!(AllMetrics => IADLXAllMetricsList(IADLXAllMetricsListVtbl), At_AllMetricsList, Add_Back_AllMetricsList);
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_all_metrics_list/>
+#[doc(alias = "IADLXAllMetricsList")]
+#[doc(alias = "At_AllMetricsList")]
+#[doc(alias = "Add_Back_AllMetricsList")]
+pub type AllMetricsList = AdlxList<AllMetrics>;
+
+list_item!(DisplayResolution => IADLXDisplayResolutionList(IADLXDisplayResolutionListVtbl), At_DisplayResolutionList, Add_Back_DisplayResolutionList);
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_resolution_list/>
+#[doc(alias = "IADLXDisplayResolutionList")]
+#[doc(alias = "At_DisplayResolutionList")]
+#[doc(alias = "Add_Back_DisplayResolutionList")]
+pub type DisplayResolutionList = AdlxList<DisplayResolution>;
+
+impl PerformanceMonitoringServices {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_performance_monitoring_services__get_all_metrics_history/>
+    #[doc(alias = "GetAllMetricsHistory")]
+    pub fn get_all_metrics_history(
+        &self,
+        start_ms: i32,
+        stop_ms: i32,
+    ) -> Result<AdlxList<AllMetrics>> {
+        check_interface::<AdlxList<AllMetrics>>(thread_runtime_version())?;
+        let mut metrics_list = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetAllMetricsHistory.unwrap())(
+                self.as_raw(),
+                start_ms,
+                stop_ms,
+                metrics_list.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, metrics_list)
+            .map(|metrics_list| unsafe { AdlxList::<AllMetrics>::from_raw(metrics_list) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_performance_monitoring_services__get_system_metrics_history/>
+    #[doc(alias = "GetSystemMetricsHistory")]
+    pub fn get_system_metrics_history(
+        &self,
+        start_ms: i32,
+        stop_ms: i32,
+    ) -> Result<AdlxList<SystemMetrics>> {
+        check_interface::<AdlxList<SystemMetrics>>(thread_runtime_version())?;
+        let mut metrics_list = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetSystemMetricsHistory.unwrap())(
+                self.as_raw(),
+                start_ms,
+                stop_ms,
+                metrics_list.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, metrics_list)
+            .map(|metrics_list| unsafe { AdlxList::<SystemMetrics>::from_raw(metrics_list) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_performance_monitoring_services__get_current_all_metrics/>
+    #[doc(alias = "GetCurrentAllMetrics")]
+    pub fn get_current_all_metrics(&self) -> Result<AllMetrics> {
+        check_interface::<AllMetrics>(thread_runtime_version())?;
+        let mut metrics = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetCurrentAllMetrics.unwrap())(self.as_raw(), metrics.as_mut_ptr())
+        };
+        Error::from_result_with_assume_init_on_success(result, metrics)
+            .map(|metrics| unsafe { AllMetrics::from_raw(metrics) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_performance_monitoring_services__get_current_system_metrics/>
+    #[doc(alias = "GetCurrentSystemMetrics")]
+    pub fn get_current_system_metrics(&self) -> Result<SystemMetrics> {
+        check_interface::<SystemMetrics>(thread_runtime_version())?;
+        let mut metrics = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetCurrentSystemMetrics.unwrap())(self.as_raw(), metrics.as_mut_ptr())
+        };
+        Error::from_result_with_assume_init_on_success(result, metrics)
+            .map(|metrics| unsafe { SystemMetrics::from_raw(metrics) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_performance_monitoring_services__get_supported_system_metrics/>
+    #[doc(alias = "GetSupportedSystemMetrics")]
+    pub fn get_supported_system_metrics(&self) -> Result<SystemMetricsSupport> {
+        check_interface::<SystemMetricsSupport>(thread_runtime_version())?;
+        let mut metrics_supported = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetSupportedSystemMetrics.unwrap())(
+                self.as_raw(),
+                metrics_supported.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, metrics_supported)
+            .map(|metrics_supported| unsafe { SystemMetricsSupport::from_raw(metrics_supported) })
+    }
+}
+
+impl ThreeDSettingsServices {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_anti_lag/>
+    #[doc(alias = "GetAntiLag")]
+    pub fn get_anti_lag(&self, gpu: &Gpu) -> Result<ThreeDAntiLag> {
+        check_interface::<ThreeDAntiLag>(thread_runtime_version())?;
+        let mut pp_3d_anti_lag = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetAntiLag.unwrap())(
+                self.as_raw(),
+                gpu.as_raw(),
+                pp_3d_anti_lag.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_anti_lag)
+            .map(|pp_3d_anti_lag| unsafe { ThreeDAntiLag::from_raw(pp_3d_anti_lag) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_boost/>
+    #[doc(alias = "GetBoost")]
+    pub fn get_boost(&self, gpu: &Gpu) -> Result<ThreeDBoost> {
+        check_interface::<ThreeDBoost>(thread_runtime_version())?;
+        let mut pp_3d_boost = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetBoost.unwrap())(self.as_raw(), gpu.as_raw(), pp_3d_boost.as_mut_ptr())
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_boost)
+            .map(|pp_3d_boost| unsafe { ThreeDBoost::from_raw(pp_3d_boost) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_image_sharpening/>
+    #[doc(alias = "GetImageSharpening")]
+    pub fn get_image_sharpening(&self, gpu: &Gpu) -> Result<ThreeDImageSharpening> {
+        check_interface::<ThreeDImageSharpening>(thread_runtime_version())?;
+        let mut pp_3d_image_sharpening = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetImageSharpening.unwrap())(
+                self.as_raw(),
+                gpu.as_raw(),
+                pp_3d_image_sharpening.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_image_sharpening).map(
+            |pp_3d_image_sharpening| unsafe {
+                ThreeDImageSharpening::from_raw(pp_3d_image_sharpening)
+            },
+        )
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_enhanced_sync/>
+    #[doc(alias = "GetEnhancedSync")]
+    pub fn get_enhanced_sync(&self, gpu: &Gpu) -> Result<ThreeDEnhancedSync> {
+        check_interface::<ThreeDEnhancedSync>(thread_runtime_version())?;
+        let mut pp_3d_enhanced_sync = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetEnhancedSync.unwrap())(
+                self.as_raw(),
+                gpu.as_raw(),
+                pp_3d_enhanced_sync.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_enhanced_sync)
+            .map(|pp_3d_enhanced_sync| unsafe { ThreeDEnhancedSync::from_raw(pp_3d_enhanced_sync) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_anti_aliasing/>
+    #[doc(alias = "GetAntiAliasing")]
+    pub fn get_anti_aliasing(&self, gpu: &Gpu) -> Result<ThreeDAntiAliasing> {
+        check_interface::<ThreeDAntiAliasing>(thread_runtime_version())?;
+        let mut pp_3d_anti_aliasing = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetAntiAliasing.unwrap())(
+                self.as_raw(),
+                gpu.as_raw(),
+                pp_3d_anti_aliasing.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_anti_aliasing)
+            .map(|pp_3d_anti_aliasing| unsafe { ThreeDAntiAliasing::from_raw(pp_3d_anti_aliasing) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_morphological_anti_aliasing/>
+    #[doc(alias = "GetMorphologicalAntiAliasing")]
+    pub fn get_morphological_anti_aliasing(
+        &self,
+        gpu: &Gpu,
+    ) -> Result<ThreeDMorphologicalAntiAliasing> {
+        check_interface::<ThreeDMorphologicalAntiAliasing>(thread_runtime_version())?;
+        let mut pp_3d_morphological_anti_aliasing = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetMorphologicalAntiAliasing.unwrap())(
+                self.as_raw(),
+                gpu.as_raw(),
+                pp_3d_morphological_anti_aliasing.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_morphological_anti_aliasing)
+            .map(|pp_3d_morphological_anti_aliasing| unsafe {
+                ThreeDMorphologicalAntiAliasing::from_raw(pp_3d_morphological_anti_aliasing)
+            })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_anisotropic_filtering/>
+    #[doc(alias = "GetAnisotropicFiltering")]
+    pub fn get_anisotropic_filtering(&self, gpu: &Gpu) -> Result<ThreeDAnisotropicFiltering> {
+        check_interface::<ThreeDAnisotropicFiltering>(thread_runtime_version())?;
+        let mut pp_3d_anisotropic_filtering = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetAnisotropicFiltering.unwrap())(
+                self.as_raw(),
+                gpu.as_raw(),
+                pp_3d_anisotropic_filtering.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_anisotropic_filtering).map(
+            |pp_3d_anisotropic_filtering| unsafe {
+                ThreeDAnisotropicFiltering::from_raw(pp_3d_anisotropic_filtering)
+            },
+        )
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_tessellation/>
+    #[doc(alias = "GetTessellation")]
+    pub fn get_tessellation(&self, gpu: &Gpu) -> Result<ThreeDTessellation> {
+        check_interface::<ThreeDTessellation>(thread_runtime_version())?;
+        let mut pp_3d_tessellation = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetTessellation.unwrap())(
+                self.as_raw(),
+                gpu.as_raw(),
+                pp_3d_tessellation.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_tessellation)
+            .map(|pp_3d_tessellation| unsafe { ThreeDTessellation::from_raw(pp_3d_tessellation) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_radeon_super_resolution/>
+    #[doc(alias = "GetRadeonSuperResolution")]
+    pub fn get_radeon_super_resolution(&self) -> Result<ThreeDRadeonSuperResolution> {
+        check_interface::<ThreeDRadeonSuperResolution>(thread_runtime_version())?;
+        let mut pp_3d_radeon_super_resolution = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetRadeonSuperResolution.unwrap())(
+                self.as_raw(),
+                pp_3d_radeon_super_resolution.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_radeon_super_resolution).map(
+            |pp_3d_radeon_super_resolution| unsafe {
+                ThreeDRadeonSuperResolution::from_raw(pp_3d_radeon_super_resolution)
+            },
+        )
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get_reset_shader_cache/>
+    #[doc(alias = "GetResetShaderCache")]
+    pub fn get_reset_shader_cache(&self, gpu: &Gpu) -> Result<ThreeDResetShaderCache> {
+        check_interface::<ThreeDResetShaderCache>(thread_runtime_version())?;
+        let mut pp_3d_reset_shader_cache = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetResetShaderCache.unwrap())(
+                self.as_raw(),
+                gpu.as_raw(),
+                pp_3d_reset_shader_cache.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_reset_shader_cache).map(
+            |pp_3d_reset_shader_cache| unsafe {
+                ThreeDResetShaderCache::from_raw(pp_3d_reset_shader_cache)
+            },
+        )
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_settings_services__get3_d_settings_changed_handling/>
+    #[doc(alias = "Get3DSettingsChangedHandling")]
+    pub fn get_3d_settings_changed_handling(&self) -> Result<ThreeDSettingsChangedHandling> {
+        check_interface::<ThreeDSettingsChangedHandling>(thread_runtime_version())?;
+        let mut pp_3d_settings_changed_handling = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().Get3DSettingsChangedHandling.unwrap())(
+                self.as_raw(),
+                pp_3d_settings_changed_handling.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pp_3d_settings_changed_handling).map(
+            |pp_3d_settings_changed_handling| unsafe {
+                ThreeDSettingsChangedHandling::from_raw(pp_3d_settings_changed_handling)
+            },
+        )
+    }
+}
+
+adlx_interface! {
+    impl DisplayFreeSync {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_free_sync__set_enabled/>
+        #[doc(alias = "SetEnabled")]
+        fn set_enabled(enabled: bool) = SetEnabled;
+    }
+}
+
+adlx_interface! {
+    impl Display {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__manufacturer_i_d/>
+        #[doc(alias = "ManufacturerID")]
+        fn manufacturer_id() -> u32 = ManufacturerID;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__display_type/>
+        #[doc(alias = "DisplayType")]
+        fn display_type() -> DisplayType = DisplayType;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__connector_type/>
+        #[doc(alias = "ConnectorType")]
+        fn connector_type() -> DisplayConnectorType = ConnectorType;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__refresh_rate/>
+        #[doc(alias = "RefreshRate")]
+        fn refresh_rate() -> f64 = RefreshRate;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__pixel_clock/>
+        #[doc(alias = "PixelClock")]
+        fn pixel_clock() -> u32 = PixelClock;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__scan_type/>
+        #[doc(alias = "ScanType")]
+        fn scan_type() -> DisplayScanType = ScanType;
+
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__unique_id/>
+        #[doc(alias = "UniqueId")]
+        fn unique_id() -> usize = UniqueId;
+    }
+}
+
+impl Display {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__name/>
+    #[doc(alias = "Name")]
+    pub fn name(&self) -> Result<&str> {
+        let mut display_name = MaybeUninit::uninit();
+        let result =
+            unsafe { (self.vtable().Name.unwrap())(self.as_raw(), display_name.as_mut_ptr()) };
+        Error::from_result_with_assume_init_on_success(result, display_name)
+            .map(|display_name| unsafe { CStr::from_ptr(display_name).to_str().unwrap() })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__e_d_i_d/>
+    #[doc(alias = "EDID")]
+    pub fn edid(&self) -> Result<&str> {
+        let mut edid = MaybeUninit::uninit();
+        let result = unsafe { (self.vtable().EDID.unwrap())(self.as_raw(), edid.as_mut_ptr()) };
+        Error::from_result_with_assume_init_on_success(result, edid)
+            .map(|edid| unsafe { CStr::from_ptr(edid).to_str().unwrap() })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display__native_resolution/>
+    #[doc(alias = "NativeResolution")]
+    pub fn native_resolution(&self) -> Result<(i32, i32)> {
+        let mut max_h_resolution = MaybeUninit::uninit();
+        let mut max_v_resolution = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().NativeResolution.unwrap())(
+                self.as_raw(),
+                max_h_resolution.as_mut_ptr(),
+                max_v_resolution.as_mut_ptr(),
+            )
+        };
+        Error::from_result(result).map(|()| unsafe {
+            (
+                max_h_resolution.assume_init(),
+                max_v_resolution.assume_init(),
+            )
+        })
+    }
+}
+
+adlx_interface! {
+    impl DisplayServices {
+        /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_number_of_displays/>
+        #[doc(alias = "GetNumberOfDisplays")]
+        fn get_number_of_displays() -> u32 = GetNumberOfDisplays;
+    }
+}
+
+impl DisplayServices {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_display_changed_handling/>
+    #[doc(alias = "GetDisplayChangedHandling")]
+    pub fn get_display_changed_handling(&self) -> Result<DisplayChangedHandling> {
+        check_interface::<DisplayChangedHandling>(thread_runtime_version())?;
+        let mut display_changed_handling = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetDisplayChangedHandling.unwrap())(
+                self.as_raw(),
+                display_changed_handling.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, display_changed_handling).map(
+            |display_changed_handling| unsafe {
+                DisplayChangedHandling::from_raw(display_changed_handling)
+            },
+        )
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_virtual_super_resolution/>
+    #[doc(alias = "GetVirtualSuperResolution")]
+    pub fn get_virtual_super_resolution(&self, display: &Display) -> Result<DisplayVsr> {
+        check_interface::<DisplayVsr>(thread_runtime_version())?;
+        let mut vsr = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetVirtualSuperResolution.unwrap())(
+                self.as_raw(),
+                display.as_raw(),
+                vsr.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, vsr)
+            .map(|vsr| unsafe { DisplayVsr::from_raw(vsr) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_g_p_u_scaling/>
+    #[doc(alias = "GetGPUScaling")]
+    pub fn get_gpu_scaling(&self, display: &Display) -> Result<DisplayGpuScaling> {
+        check_interface::<DisplayGpuScaling>(thread_runtime_version())?;
+        let mut gpu_scaling = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetGPUScaling.unwrap())(
+                self.as_raw(),
+                display.as_raw(),
+                gpu_scaling.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, gpu_scaling)
+            .map(|gpu_scaling| unsafe { DisplayGpuScaling::from_raw(gpu_scaling) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_scaling_mode/>
+    #[doc(alias = "GetScalingMode")]
+    pub fn get_scaling_mode(&self, display: &Display) -> Result<DisplayScalingMode> {
+        check_interface::<DisplayScalingMode>(thread_runtime_version())?;
+        let mut scaling_mode = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetScalingMode.unwrap())(
+                self.as_raw(),
+                display.as_raw(),
+                scaling_mode.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, scaling_mode)
+            .map(|scaling_mode| unsafe { DisplayScalingMode::from_raw(scaling_mode) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_integer_scaling/>
+    #[doc(alias = "GetIntegerScaling")]
+    pub fn get_integer_scaling(&self, display: &Display) -> Result<DisplayIntegerScaling> {
+        check_interface::<DisplayIntegerScaling>(thread_runtime_version())?;
+        let mut integer_scaling = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetIntegerScaling.unwrap())(
+                self.as_raw(),
+                display.as_raw(),
+                integer_scaling.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, integer_scaling)
+            .map(|integer_scaling| unsafe { DisplayIntegerScaling::from_raw(integer_scaling) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_color_depth/>
+    #[doc(alias = "GetColorDepth")]
+    pub fn get_color_depth(&self, display: &Display) -> Result<DisplayColorDepth> {
+        check_interface::<DisplayColorDepth>(thread_runtime_version())?;
+        let mut color_depth = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetColorDepth.unwrap())(
+                self.as_raw(),
+                display.as_raw(),
+                color_depth.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, color_depth)
+            .map(|color_depth| unsafe { DisplayColorDepth::from_raw(color_depth) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_pixel_format/>
+    #[doc(alias = "GetPixelFormat")]
+    pub fn get_pixel_format(&self, display: &Display) -> Result<DisplayPixelFormat> {
+        check_interface::<DisplayPixelFormat>(thread_runtime_version())?;
+        let mut pixel_format = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetPixelFormat.unwrap())(
+                self.as_raw(),
+                display.as_raw(),
+                pixel_format.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, pixel_format)
+            .map(|pixel_format| unsafe { DisplayPixelFormat::from_raw(pixel_format) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_custom_color/>
+    #[doc(alias = "GetCustomColor")]
+    pub fn get_custom_color(&self, display: &Display) -> Result<DisplayCustomColor> {
+        check_interface::<DisplayCustomColor>(thread_runtime_version())?;
+        let mut custom_color = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetCustomColor.unwrap())(
+                self.as_raw(),
+                display.as_raw(),
+                custom_color.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, custom_color)
+            .map(|custom_color| unsafe { DisplayCustomColor::from_raw(custom_color) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_h_d_c_p/>
+    #[doc(alias = "GetHDCP")]
+    pub fn get_hdcp(&self, display: &Display) -> Result<DisplayHdcp> {
+        check_interface::<DisplayHdcp>(thread_runtime_version())?;
+        let mut hdcp = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetHDCP.unwrap())(self.as_raw(), display.as_raw(), hdcp.as_mut_ptr())
+        };
+        Error::from_result_with_assume_init_on_success(result, hdcp)
+            .map(|hdcp| unsafe { DisplayHdcp::from_raw(hdcp) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_custom_resolution/>
+    #[doc(alias = "GetCustomResolution")]
+    pub fn get_custom_resolution(&self, display: &Display) -> Result<DisplayCustomResolution> {
+        check_interface::<DisplayCustomResolution>(thread_runtime_version())?;
+        let mut custom_resolution = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetCustomResolution.unwrap())(
+                self.as_raw(),
+                display.as_raw(),
+                custom_resolution.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, custom_resolution).map(
+            |custom_resolution| unsafe { DisplayCustomResolution::from_raw(custom_resolution) },
+        )
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_display_services__get_vari_bright/>
+    #[doc(alias = "GetVariBright")]
+    pub fn get_vari_bright(&self, display: &Display) -> Result<DisplayVariBright> {
+        check_interface::<DisplayVariBright>(thread_runtime_version())?;
+        let mut vari_bright = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetVariBright.unwrap())(
+                self.as_raw(),
+                display.as_raw(),
+                vari_bright.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, vari_bright)
+            .map(|vari_bright| unsafe { DisplayVariBright::from_raw(vari_bright) })
+    }
+}
@@ -1,9 +1,14 @@
 use std::{ffi::CStr, fmt, mem::MaybeUninit, ops::Deref};
 
 use super::{
+    application::ApplicationList,
+    bios_info::BiosInfo,
+    driver_version::{DriverVersion, ReleaseDate, SoftwareVersion},
+    enums::{AsicFamilyType, GpuType, MultiGpuMode, PciBusType},
     ffi,
-    interface::{Interface, InterfaceImpl},
+    interface::{inherits, Inherits, Interface, InterfaceImpl},
     result::{Error, Result},
+    version::{check_interface, thread_runtime_version, AdlxVersion},
 };
 
 /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u/>
@@ -28,20 +33,21 @@ impl Gpu {
             .map(|x| unsafe { CStr::from_ptr(x) }.to_str().unwrap())
     }
     #[doc(alias = "ASICFamilyType")]
-    pub fn asic_family_type(&self) -> Result<ffi::ADLX_ASIC_FAMILY_TYPE> {
+    pub fn asic_family_type(&self) -> Result<AsicFamilyType> {
         let mut asic_family_type = MaybeUninit::uninit();
         let result = unsafe {
             (self.vtable().ASICFamilyType.unwrap())(self.as_raw(), asic_family_type.as_mut_ptr())
         };
 
         Error::from_result_with_assume_init_on_success(result, asic_family_type)
+            .map(AsicFamilyType::from_raw)
     }
     #[doc(alias = "Type")]
-    pub fn type_(&self) -> Result<ffi::ADLX_GPU_TYPE> {
+    pub fn type_(&self) -> Result<GpuType> {
         let mut type_ = MaybeUninit::uninit();
         let result = unsafe { (self.vtable().Type.unwrap())(self.as_raw(), type_.as_mut_ptr()) };
 
-        Error::from_result_with_assume_init_on_success(result, type_)
+        Error::from_result_with_assume_init_on_success(result, type_).map(GpuType::from_raw)
     }
     #[doc(alias = "IsExternal")]
     pub fn is_external(&self) -> Result<bool> {
@@ -98,13 +104,28 @@ impl Gpu {
         Error::from_result_with_assume_init_on_success(result, x)
             .map(|x| unsafe { CStr::from_ptr(x) }.to_str().unwrap())
     }
-    // #[doc(alias = "BIOSInfo")]
-    // pub fn BIOSInfo(&self) -> Result<()> {
-    //     let mut x = MaybeUninit::uninit();
-    //     let result = unsafe { (self.vtable().BIOSInfo.unwrap())(self.as_raw(), x.as_mut_ptr()) };
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u__b_i_o_s_info/>
+    #[doc(alias = "BIOSInfo")]
+    pub fn bios_info(&self) -> Result<BiosInfo> {
+        let mut part_number = MaybeUninit::uninit();
+        let mut version = MaybeUninit::uninit();
+        let mut date = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().BIOSInfo.unwrap())(
+                self.as_raw(),
+                part_number.as_mut_ptr(),
+                version.as_mut_ptr(),
+                date.as_mut_ptr(),
+            )
+        };
+
+        Error::from_result(result)?;
 
-    //     Error::from_result_with_assume_init_on_success(result, x)
-    // }
+        let [part_number, version, date] = unsafe {
+            [part_number, version, date].map(|x| CStr::from_ptr(x.assume_init()).to_str().unwrap())
+        };
+        Ok(BiosInfo::new(part_number, version, date))
+    }
     #[doc(alias = "DeviceId")]
     pub fn device_id(&self) -> Result<&str> {
         let mut x = MaybeUninit::uninit();
@@ -176,8 +197,11 @@ unsafe impl Interface for Gpu1 {
     type Impl = ffi::IADLXGPU1;
     type Vtable = ffi::IADLXGPU1Vtbl;
     const IID: &'static str = "IADLXGPU1";
+    const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 1);
 }
 
+inherits!(Gpu1: Gpu);
+
 impl Deref for Gpu1 {
     type Target = Gpu;
 
@@ -188,13 +212,14 @@ impl Deref for Gpu1 {
 
 impl Gpu1 {
     #[doc(alias = "PCIBusType")]
-    pub fn pci_bus_type(&self) -> Result<ffi::ADLX_PCI_BUS_TYPE> {
+    pub fn pci_bus_type(&self) -> Result<PciBusType> {
         let mut pci_bus_type = MaybeUninit::uninit();
         let result = unsafe {
             (self.vtable().PCIBusType.unwrap())(self.as_raw(), pci_bus_type.as_mut_ptr())
         };
 
         Error::from_result_with_assume_init_on_success(result, pci_bus_type)
+            .map(PciBusType::from_raw)
     }
     #[doc(alias = "PCIBusLaneWidth")]
     pub fn pci_bus_lane_width(&self) -> Result<u32> {
@@ -206,13 +231,14 @@ impl Gpu1 {
         Error::from_result_with_assume_init_on_success(result, pci_bus_lane_width)
     }
     #[doc(alias = "MultiGPUMode")]
-    pub fn multi_gpu_mode(&self) -> Result<ffi::ADLX_MGPU_MODE> {
+    pub fn multi_gpu_mode(&self) -> Result<MultiGpuMode> {
         let mut multi_gpu_mode = MaybeUninit::uninit();
         let result = unsafe {
             (self.vtable().MultiGPUMode.unwrap())(self.as_raw(), multi_gpu_mode.as_mut_ptr())
         };
 
         Error::from_result_with_assume_init_on_success(result, multi_gpu_mode)
+            .map(MultiGpuMode::from_raw)
     }
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u1__product_name/>
     #[doc(alias = "ProductName")]
@@ -237,8 +263,11 @@ unsafe impl Interface for Gpu2 {
     type Impl = ffi::IADLXGPU2;
     type Vtable = ffi::IADLXGPU2Vtbl;
     const IID: &'static str = "IADLXGPU2";
+    const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 3);
 }
 
+inherits!(Gpu2: Gpu1, Gpu);
+
 impl Deref for Gpu2 {
     type Target = Gpu1;
 
@@ -266,25 +295,6 @@ impl Gpu2 {
         Error::from_result(result)
     }
 
-    // /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__start_power_off/>
-    // #[doc(alias = "StartPowerOff")]
-    // pub fn start_power_off(
-    //     &self,
-    //     callback: &GPUConnectChangedListener,
-    //     timeout: Duration,
-    // ) -> Result<()> {
-    //     let mut state = MaybeUninit::uninit();
-    //     let result = unsafe {
-    //         (self.vtable().StartPowerOff.unwrap())(
-    //             self.as_raw(),
-    //             callback.as_raw(),
-    //             timeout.unknown(),
-    //         )
-    //     };
-
-    //     Error::from_result(result)
-    // }
-
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__abort_power_off/>
     #[doc(alias = "AbortPowerOff")]
     pub fn abort_power_off(&self) -> Result<()> {
@@ -307,22 +317,21 @@ impl Gpu2 {
         Error::from_result_with_assume_init_on_success(result, supported).map(|state| state != 0)
     }
 
-    // /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__get_applications/>
-    // #[doc(alias = "GetApplications")]
-    // pub fn get_applications(&self) -> Result<ApplicationList> {
-    //     let mut list = MaybeUninit::uninit();
-    //     let result =
-    //         unsafe { (self.vtable().GetApplications.unwrap())(self.as_raw(), list.as_mut_ptr()) };
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__get_applications/>
+    #[doc(alias = "GetApplications")]
+    pub fn get_applications(&self) -> Result<ApplicationList> {
+        check_interface::<ApplicationList>(thread_runtime_version())?;
+        let mut list = MaybeUninit::uninit();
+        let result =
+            unsafe { (self.vtable().GetApplications.unwrap())(self.as_raw(), list.as_mut_ptr()) };
 
-    //     Error::from_result_with_assume_init_on_success(result, list)
-    //         .map(|list| unsafe { ApplicationList::from_raw(list) })
-    // }
+        Error::from_result_with_assume_init_on_success(result, list)
+            .map(|list| unsafe { ApplicationList::from_raw(list) })
+    }
 
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__a_m_d_software_release_date/>
-    ///
-    /// Returns the date as a `(year, month, day)` tuple.
     #[doc(alias = "AMDSoftwareReleaseDate")]
-    pub fn amd_software_release_date(&self) -> Result<(u32, u32, u32)> {
+    pub fn amd_software_release_date(&self) -> Result<ReleaseDate> {
         let mut year = MaybeUninit::uninit();
         let mut month = MaybeUninit::uninit();
         let mut day = MaybeUninit::uninit();
@@ -337,7 +346,13 @@ impl Gpu2 {
 
         Error::from_result(result)?;
 
-        Ok(unsafe { (year.assume_init(), month.assume_init(), day.assume_init()) })
+        Ok(unsafe {
+            ReleaseDate {
+                year: year.assume_init() as u16,
+                month: month.assume_init() as u8,
+                day: day.assume_init() as u8,
+            }
+        })
     }
 
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__a_m_d_software_edition/>
@@ -354,37 +369,37 @@ impl Gpu2 {
 
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__a_m_d_software_version/>
     #[doc(alias = "AMDSoftwareVersion")]
-    pub fn amd_software_version(&self) -> Result<&str> {
+    pub fn amd_software_version(&self) -> Result<SoftwareVersion> {
         let mut version = MaybeUninit::uninit();
         let result = unsafe {
             (self.vtable().AMDSoftwareVersion.unwrap())(self.as_raw(), version.as_mut_ptr())
         };
 
         Error::from_result_with_assume_init_on_success(result, version)
-            .map(|x| unsafe { CStr::from_ptr(x) }.to_str().unwrap())
+            .map(|x| unsafe { CStr::from_ptr(x) }.to_str().unwrap().into())
     }
 
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__driver_version/>
     #[doc(alias = "DriverVersion")]
-    pub fn driver_version(&self) -> Result<&str> {
+    pub fn driver_version(&self) -> Result<DriverVersion> {
         let mut version = MaybeUninit::uninit();
         let result =
             unsafe { (self.vtable().DriverVersion.unwrap())(self.as_raw(), version.as_mut_ptr()) };
 
         Error::from_result_with_assume_init_on_success(result, version)
-            .map(|x| unsafe { CStr::from_ptr(x) }.to_str().unwrap())
+            .map(|x| unsafe { CStr::from_ptr(x) }.to_str().unwrap().into())
     }
 
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__a_m_d_windows_driver_version/>
     #[doc(alias = "AMDWindowsDriverVersion")]
-    pub fn amd_windows_driver_version(&self) -> Result<&str> {
+    pub fn amd_windows_driver_version(&self) -> Result<DriverVersion> {
         let mut version = MaybeUninit::uninit();
         let result = unsafe {
             (self.vtable().AMDWindowsDriverVersion.unwrap())(self.as_raw(), version.as_mut_ptr())
         };
 
         Error::from_result_with_assume_init_on_success(result, version)
-            .map(|x| unsafe { CStr::from_ptr(x) }.to_str().unwrap())
+            .map(|x| unsafe { CStr::from_ptr(x) }.to_str().unwrap().into())
     }
 
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__l_u_i_d>
@@ -397,7 +412,45 @@ impl Gpu2 {
     }
 }
 
+/// The newest GPU interface version that ADLX implements for a GPU, returned by
+/// [`Gpu::newest()`].
+///
+/// Dereferences to [`Gpu`], which every version extends.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum NewestGpu {
+    Gpu(Gpu),
+    Gpu1(Gpu1),
+    Gpu2(Gpu2),
+}
+
+impl Deref for NewestGpu {
+    type Target = Gpu;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Gpu(gpu) => gpu,
+            Self::Gpu1(gpu) => gpu.upcast_ref(),
+            Self::Gpu2(gpu) => gpu.upcast_ref(),
+        }
+    }
+}
+
+impl Gpu {
+    /// Probes for the newest supported interface version, starting at [`Gpu2`].
+    pub fn newest(&self) -> Result<NewestGpu> {
+        if let Some(gpu) = self.try_cast::<Gpu2>()? {
+            return Ok(NewestGpu::Gpu2(gpu));
+        }
+        if let Some(gpu) = self.try_cast::<Gpu1>()? {
+            return Ok(NewestGpu::Gpu1(gpu));
+        }
+        Ok(NewestGpu::Gpu(self.clone()))
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc(alias = "BDF")]
 pub struct PciAddress {
     pub bus: u32,
@@ -0,0 +1,177 @@
+//! An owned description of a GPU, which stays valid after the [`Gpu`] and ADLX are gone.
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    driver_version::{DriverVersion, ReleaseDate, SoftwareVersion},
+    enums::{AsicFamilyType, GpuType, MultiGpuMode, PciBusType},
+    ffi,
+    gpu::{Gpu, Gpu1, Gpu2, PciAddress},
+    interface::Interface,
+    result::Result,
+};
+
+/// A locally unique identifier of a GPU adapter, like the `AdapterLuid` of DXGI.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "ADLX_LUID")]
+pub struct Luid {
+    pub low_part: u32,
+    pub high_part: i32,
+}
+
+impl From<ffi::ADLX_LUID> for Luid {
+    fn from(luid: ffi::ADLX_LUID) -> Self {
+        Self {
+            low_part: luid.lowPart as u32,
+            high_part: luid.highPart as i32,
+        }
+    }
+}
+
+impl Luid {
+    /// Converts the little-endian bytes of a LUID, like `VkPhysicalDeviceIDProperties::deviceLUID`.
+    pub fn from_bytes(bytes: [u8; 8]) -> Self {
+        let [l0, l1, l2, l3, h0, h1, h2, h3] = bytes;
+        Self {
+            low_part: u32::from_le_bytes([l0, l1, l2, l3]),
+            high_part: i32::from_le_bytes([h0, h1, h2, h3]),
+        }
+    }
+}
+
+impl fmt::Display for Luid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08x}-{:08x}", self.high_part, self.low_part)
+    }
+}
+
+/// The values of every identifying getter of a [`Gpu`], returned by [`Gpu::info()`].
+///
+/// Values of [`Gpu1`] and [`Gpu2`] are [`None`] when the GPU does not implement that interface,
+/// or when the getter fails.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GpuInfo {
+    pub name: String,
+    /// Vendor id in hexadecimal, like `1002`
+    pub vendor_id: String,
+    pub device_id: String,
+    pub revision_id: String,
+    pub sub_system_id: String,
+    pub sub_system_vendor_id: String,
+    pub unique_id: i32,
+    /// [`Gpu::pci_address_from_unique_id()`]
+    pub pci_address: Option<PciAddress>,
+    pub gpu_type: GpuType,
+    pub asic_family_type: AsicFamilyType,
+    pub is_external: bool,
+    /// Total VRAM in MB
+    pub total_vram: u32,
+    pub vram_type: String,
+    pub driver_path: String,
+    pub pnp_string: String,
+
+    /// [`Gpu1::product_name()`]
+    pub product_name: Option<String>,
+    /// [`Gpu1::pci_bus_type()`]
+    pub pci_bus_type: Option<PciBusType>,
+    /// [`Gpu1::pci_bus_lane_width()`]
+    pub pci_bus_lane_width: Option<u32>,
+    /// [`Gpu1::multi_gpu_mode()`]
+    pub multi_gpu_mode: Option<MultiGpuMode>,
+
+    /// [`Gpu2::driver_version()`]
+    pub driver_version: Option<DriverVersion>,
+    /// [`Gpu2::amd_windows_driver_version()`]
+    pub amd_windows_driver_version: Option<DriverVersion>,
+    /// [`Gpu2::amd_software_version()`]
+    pub amd_software_version: Option<SoftwareVersion>,
+    /// [`Gpu2::amd_software_edition()`]
+    pub amd_software_edition: Option<String>,
+    /// [`Gpu2::amd_software_release_date()`]
+    pub amd_software_release_date: Option<ReleaseDate>,
+    /// [`Gpu2::luid()`]
+    pub luid: Option<Luid>,
+}
+
+impl GpuInfo {
+    /// Reads all values from `gpu`, failing if any of the [`Gpu`] getters fails.
+    pub fn new(gpu: &Gpu) -> Result<Self> {
+        let gpu1 = gpu.cast::<Gpu1>().ok();
+        let gpu2 = gpu.cast::<Gpu2>().ok();
+        let gpu1 = gpu1.as_ref();
+        let gpu2 = gpu2.as_ref();
+
+        Ok(Self {
+            name: gpu.name()?.to_owned(),
+            vendor_id: gpu.vendor_id()?.to_owned(),
+            device_id: gpu.device_id()?.to_owned(),
+            revision_id: gpu.revision_id()?.to_owned(),
+            sub_system_id: gpu.sub_system_id()?.to_owned(),
+            sub_system_vendor_id: gpu.sub_system_vendor_id()?.to_owned(),
+            unique_id: gpu.unique_id()?,
+            pci_address: gpu.pci_address_from_unique_id(),
+            gpu_type: gpu.type_()?,
+            asic_family_type: gpu.asic_family_type()?,
+            is_external: gpu.is_external()?,
+            total_vram: gpu.total_vram()?,
+            vram_type: gpu.vram_type()?.to_owned(),
+            driver_path: gpu.driver_path()?.to_owned(),
+            pnp_string: gpu.pnp_string()?.to_owned(),
+
+            product_name: gpu1.and_then(|g| g.product_name().ok()).map(str::to_owned),
+            pci_bus_type: gpu1.and_then(|g| g.pci_bus_type().ok()),
+            pci_bus_lane_width: gpu1.and_then(|g| g.pci_bus_lane_width().ok()),
+            multi_gpu_mode: gpu1.and_then(|g| g.multi_gpu_mode().ok()),
+
+            driver_version: gpu2.and_then(|g| g.driver_version().ok()),
+            amd_windows_driver_version: gpu2.and_then(|g| g.amd_windows_driver_version().ok()),
+            amd_software_version: gpu2.and_then(|g| g.amd_software_version().ok()),
+            amd_software_edition: gpu2
+                .and_then(|g| g.amd_software_edition().ok())
+                .map(str::to_owned),
+            amd_software_release_date: gpu2.and_then(|g| g.amd_software_release_date().ok()),
+            luid: gpu2.and_then(|g| g.luid().ok()).map(Luid::from),
+        })
+    }
+}
+
+impl Gpu {
+    /// Shorthand for [`GpuInfo::new()`]
+    pub fn info(&self) -> Result<GpuInfo> {
+        GpuInfo::new(self)
+    }
+}
+
+#[cfg(all(test, feature = "replay"))]
+mod tests {
+    use crate::{replay::tests::replay_gpu2, GpuType, Luid, MultiGpuMode};
+
+    #[test]
+    fn reads_owned_info() {
+        let (_helper, gpu) = replay_gpu2(|_| {});
+        let info = gpu.info().unwrap();
+
+        assert_eq!(info.vendor_id, "1002");
+        assert_eq!(info.gpu_type, GpuType::Discrete);
+        assert_eq!(info.multi_gpu_mode, Some(MultiGpuMode::None));
+        assert_eq!(
+            info.driver_version.as_ref().map(|v| v.as_str()),
+            Some("31.0.24002.92")
+        );
+        assert_eq!(
+            info.luid,
+            Some(Luid {
+                low_part: 0x1234,
+                high_part: 0
+            })
+        );
+
+        let json = serde_json::to_string(&info).unwrap();
+        assert_eq!(serde_json::from_str::<super::GpuInfo>(&json).unwrap(), info);
+    }
+}
@@ -1,9 +1,12 @@
-use std::{mem::MaybeUninit, ops::Deref};
+use std::mem::MaybeUninit;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::{
     ffi,
     interface::{Interface, InterfaceImpl},
-    list::List,
+    list::{list_item, AdlxList},
     result::{Error, Result},
 };
 
@@ -141,52 +144,71 @@ impl GpuMetrics {
 
         Error::from_result_with_assume_init_on_success(result, hotspot_temperature)
     }
-}
-
-/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_metrics_list/>
-#[derive(Clone, Debug)]
-#[repr(transparent)]
-#[doc(alias = "IADLXGPUMetricsList")]
-pub struct GpuMetricsList(List);
-
-unsafe impl Interface for GpuMetricsList {
-    type Impl = ffi::IADLXGPUMetricsList;
-    type Vtable = ffi::IADLXGPUMetricsListVtbl;
-    const IID: &'static str = "IADLXGpuMetricsList";
-}
 
-impl GpuMetricsList {
-    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_list__at/>
-    #[doc(alias = "At_GPUMetricsList")]
-    pub fn at(&self, location: u32) -> Result<GpuMetrics> {
-        let mut metrics = MaybeUninit::uninit();
-        let result = unsafe {
-            (self.vtable().At_GPUMetricsList.unwrap())(
-                self.as_raw(),
-                location,
-                metrics.as_mut_ptr(),
-            )
-        };
-        Error::from_result_with_assume_init_on_success(result, metrics)
-            .map(|metrics| unsafe { GpuMetrics::from_raw(metrics) })
+    /// Retrieves all metrics at once. Only fails if the time stamp cannot be retrieved.
+    pub fn sample(&self) -> Result<GpuMetricsSample> {
+        Ok(GpuMetricsSample {
+            time_stamp: self.time_stamp()?,
+            usage: self.usage().ok(),
+            clock_speed: self.clock_speed().ok(),
+            vram_clock_speed: self.vram_clock_speed().ok(),
+            power: self.power().ok(),
+            total_board_power: self.total_board_power().ok(),
+            voltage: self.voltage().ok(),
+            vram: self.vram().ok(),
+            fan_speed: self.fan_speed().ok(),
+            temperature: self.temperature().ok(),
+            intake_temperature: self.intake_temperature().ok(),
+            hotspot_temperature: self.hotspot_temperature().ok(),
+        })
     }
+}
 
-    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_list__add__back/>
-    #[doc(alias = "Add_Back_GPUMetricsList")]
-    pub fn add_back(&self, gpu_metrics: GpuMetrics) -> Result<()> {
-        let result = unsafe {
-            // TODO: Assume ownership is consumed here?
-            (self.vtable().Add_Back_GPUMetricsList.unwrap())(self.as_raw(), gpu_metrics.into_raw())
-        };
-        Error::from_result(result)
-    }
+/// An owned snapshot of every [`GpuMetrics`] value, with [`None`] for metrics that could not be
+/// retrieved, for example because the GPU does not support them.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GpuMetricsSample {
+    /// Time stamp of the sample in milliseconds
+    pub time_stamp: i64,
+    /// GPU usage in %
+    pub usage: Option<f64>,
+    /// GPU clock speed in MHz
+    pub clock_speed: Option<i32>,
+    /// VRAM clock speed in MHz
+    pub vram_clock_speed: Option<i32>,
+    /// GPU power in W
+    pub power: Option<f64>,
+    /// Total board power in W
+    pub total_board_power: Option<f64>,
+    /// GPU voltage in mV
+    pub voltage: Option<i32>,
+    /// Dedicated VRAM usage in MB
+    pub vram: Option<i32>,
+    /// Fan speed in RPM
+    pub fan_speed: Option<i32>,
+    /// GPU temperature in °C
+    pub temperature: Option<f64>,
+    /// GPU intake temperature in °C
+    pub intake_temperature: Option<f64>,
+    /// GPU hotspot temperature in °C
+    pub hotspot_temperature: Option<f64>,
 }
 
-impl Deref for GpuMetricsList {
-    type Target = List;
+list_item!(GpuMetrics => IADLXGPUMetricsList(IADLXGPUMetricsListVtbl), At_GPUMetricsList, Add_Back_GPUMetricsList);
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_metrics_list/>
+#[doc(alias = "IADLXGPUMetricsList")]
+#[doc(alias = "At_GPUMetricsList")]
+#[doc(alias = "Add_Back_GPUMetricsList")]
+pub type GpuMetricsList = AdlxList<GpuMetrics>;
+
+impl GpuMetricsList {
+    /// Retrieves [`GpuMetrics::sample()`] of every element in this list.
+    pub fn samples(&self) -> Result<Vec<GpuMetricsSample>> {
+        self.try_iter()
+            .map(|metrics| metrics.and_then(|metrics| metrics.sample()))
+            .collect()
     }
 }
 
@@ -2,14 +2,12 @@ use std::{ffi::CStr, mem::MaybeUninit};
 
 use anyhow::{ensure, Context, Result};
 
-use super::{ffi, result::Error, system::System};
-
-// Taken from ADLXDefines.h.
-// Refer to ADLX_MAKE_FULL_VERSION and ADLX_FULL_VERSION.
-const HEADER_VERSION: u64 = ((ffi::ADLX_VER_MAJOR as u64) << 48)
-    | ((ffi::ADLX_VER_MINOR as u64) << 32)
-    | ((ffi::ADLX_VER_RELEASE as u64) << 16)
-    | (ffi::ADLX_VER_BUILD_NUM as u64);
+use super::{
+    ffi,
+    result::Error,
+    system::System,
+    version::{negotiate, AdlxVersion, RuntimeVersion},
+};
 
 // TODO: This should be a singleton
 struct AdlxFunctions {
@@ -66,13 +64,25 @@ impl AdlxFunctions {
     }
 }
 
+/// Where [`AdlxHelper`] obtained its [`System`] from.
+enum Backend {
+    Library(AdlxFunctions),
+    #[cfg(feature = "replay")]
+    Replay {
+        _replay: super::replay::Replay,
+    },
+}
+
 pub struct AdlxHelper {
-    functions: AdlxFunctions,
+    backend: Backend,
 
     system: super::system::System,
 
     full_version: u64,
     version: String,
+    negotiated_version: AdlxVersion,
+    /// Checks interfaces on this thread against [`Self::runtime_version()`]
+    runtime_version: RuntimeVersion,
 }
 
 impl AdlxHelper {
@@ -93,25 +103,58 @@ impl AdlxHelper {
             CStr::from_ptr(version).to_str().unwrap().to_string()
         };
 
+        // Older runtimes reject the version of newer headers with `ADLX_BAD_VER`, so initialize
+        // with the runtime version instead. Interfaces that the runtime is too old for are
+        // rejected by `Interface::cast()`.
+        let runtime_version = AdlxVersion::from_full_version(full_version);
+        let negotiated_version = negotiate(runtime_version);
+
         // TODO: C++ helper does extra things if an ADL context is provided.
         // We don't support this currently because we are only implementing ADLX
         let system = unsafe {
             let mut system = std::ptr::null_mut();
 
-            Error::from_result((functions.init_fn.unwrap())(HEADER_VERSION, &mut system))?;
+            Error::from_result((functions.init_fn.unwrap())(
+                negotiated_version.full_version(),
+                &mut system,
+            ))?;
 
-            System::from_raw(system)
+            System::from_raw(system, runtime_version)
         };
 
         Ok(AdlxHelper {
-            functions,
+            backend: Backend::Library(functions),
 
             full_version,
             version,
+            negotiated_version,
+            runtime_version: RuntimeVersion::register(runtime_version),
             system,
         })
     }
 
+    /// Serves a [`Session`][super::session::Session] recorded with
+    /// [`Recorder`][super::session::Recorder] through [`Self::system()`], without loading the ADLX
+    /// library.
+    #[cfg(feature = "replay")]
+    pub fn replay(session: super::session::Session) -> Self {
+        let full_version = session.full_version;
+        let version = session.version.as_str().to_owned();
+        let runtime_version = AdlxVersion::from_full_version(full_version);
+        let replay = super::replay::Replay::new(session);
+        let system = unsafe { System::from_raw(replay.system(), runtime_version) };
+
+        AdlxHelper {
+            backend: Backend::Replay { _replay: replay },
+
+            full_version,
+            version,
+            negotiated_version: negotiate(runtime_version),
+            runtime_version: RuntimeVersion::register(runtime_version),
+            system,
+        }
+    }
+
     pub fn system(&self) -> &System {
         &self.system
     }
@@ -123,14 +166,31 @@ impl AdlxHelper {
     pub fn version(&self) -> &str {
         &self.version
     }
+
+    /// [`Self::full_version()`] as a comparable version
+    pub fn runtime_version(&self) -> AdlxVersion {
+        self.runtime_version.version()
+    }
+
+    /// The version that ADLX was initialized with: [`AdlxVersion::HEADER`], or
+    /// [`Self::runtime_version()`] if the runtime is older.
+    pub fn negotiated_version(&self) -> AdlxVersion {
+        self.negotiated_version
+    }
 }
 
 impl Drop for AdlxHelper {
     fn drop(&mut self) {
-        // SAFETY: Nullity checked at load-time
-        let result = unsafe { (self.functions.terminate_fn.unwrap_unchecked())() };
-        if let Err(e) = Error::from_result(result) {
-            eprintln!("Terminate failed with {e:?}")
+        match &self.backend {
+            Backend::Library(functions) => {
+                // SAFETY: Nullity checked at load-time
+                let result = unsafe { (functions.terminate_fn.unwrap_unchecked())() };
+                if let Err(e) = Error::from_result(result) {
+                    eprintln!("Terminate failed with {e:?}")
+                }
+            }
+            #[cfg(feature = "replay")]
+            Backend::Replay { .. } => {}
         }
     }
 }
@@ -0,0 +1,369 @@
+//! A compact, lossless binary format for metrics history.
+//!
+//! Samples are stored column by column. Time stamps and integer metrics are stored as
+//! zigzag-encoded varint deltas to the previous value, floating-point metrics as the XOR with the
+//! previous value with its trailing zero bits stripped. Metrics that are [`None`] only cost a bit
+//! in a presence bitmap, so a metric that is not supported by the GPU takes a few bytes in total.
+//!
+//! ```text
+//! magic "ADLXH" | version: u8 | kind: u8 | count: varint | columns...
+//! ```
+
+use anyhow::{bail, ensure, Context};
+
+use super::{fps::FpsSample, gpu_metrics::GpuMetricsSample};
+
+const MAGIC: &[u8; 5] = b"ADLXH";
+/// Version of the format written by [`encode_gpu_metrics()`] and [`encode_fps()`]
+pub const HISTORY_FORMAT_VERSION: u8 = 1;
+
+const KIND_GPU_METRICS: u8 = 0;
+const KIND_FPS: u8 = 1;
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+struct Encoder {
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    fn new(kind: u8, count: usize) -> Self {
+        let mut encoder = Self {
+            bytes: MAGIC.to_vec(),
+        };
+        encoder.bytes.push(HISTORY_FORMAT_VERSION);
+        encoder.bytes.push(kind);
+        encoder.varint(count as u64);
+        encoder
+    }
+
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.bytes.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    fn presence<T>(&mut self, values: &[Option<T>]) {
+        for chunk in values.chunks(8) {
+            let byte = chunk
+                .iter()
+                .enumerate()
+                .fold(0, |byte, (i, v)| byte | (u8::from(v.is_some()) << i));
+            self.bytes.push(byte);
+        }
+    }
+
+    fn int_column(&mut self, values: impl IntoIterator<Item = i64>) {
+        let mut previous = 0i64;
+        for value in values {
+            self.varint(zigzag(value.wrapping_sub(previous)));
+            previous = value;
+        }
+    }
+
+    fn float_column(&mut self, values: impl IntoIterator<Item = f64>) {
+        let mut previous = 0u64;
+        for value in values {
+            let xor = value.to_bits() ^ previous;
+            // Trailing zeros in 0..=64, the stripped bits follow unless the value repeats
+            let trailing_zeros = xor.trailing_zeros();
+            self.bytes.push(trailing_zeros as u8);
+            if xor != 0 {
+                self.varint(xor >> trailing_zeros);
+            }
+            previous = value.to_bits();
+        }
+    }
+
+    fn optional_int_column(&mut self, values: &[Option<i32>]) {
+        self.presence(values);
+        self.int_column(values.iter().flatten().map(|&v| i64::from(v)));
+    }
+
+    fn optional_float_column(&mut self, values: &[Option<f64>]) {
+        self.presence(values);
+        self.float_column(values.iter().flatten().copied());
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    /// Checks the header and returns the number of samples.
+    fn new(bytes: &'a [u8], kind: u8) -> anyhow::Result<(Self, usize)> {
+        let Some(rest) = bytes.strip_prefix(MAGIC) else {
+            bail!("Not an ADLX metrics history");
+        };
+        let mut decoder = Self { bytes: rest };
+        let version = decoder.byte()?;
+        ensure!(
+            version == HISTORY_FORMAT_VERSION,
+            "ADLX metrics history has format version {version}, but only version \
+             {HISTORY_FORMAT_VERSION} is supported"
+        );
+        let actual_kind = decoder.byte()?;
+        ensure!(
+            actual_kind == kind,
+            "Expected ADLX metrics history of kind {kind}, found {actual_kind}"
+        );
+        let count = usize::try_from(decoder.varint()?)?;
+        // Every sample takes at least one byte, which guards against huge allocations
+        ensure!(
+            count <= decoder.bytes.len(),
+            "ADLX metrics history is truncated"
+        );
+        Ok((decoder, count))
+    }
+
+    fn byte(&mut self) -> anyhow::Result<u8> {
+        let (&byte, rest) = self
+            .bytes
+            .split_first()
+            .context("ADLX metrics history is truncated")?;
+        self.bytes = rest;
+        Ok(byte)
+    }
+
+    fn varint(&mut self) -> anyhow::Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        bail!("Invalid varint in ADLX metrics history")
+    }
+
+    fn presence(&mut self, count: usize) -> anyhow::Result<Vec<bool>> {
+        let mut present = Vec::with_capacity(count);
+        while present.len() < count {
+            let byte = self.byte()?;
+            for i in 0..8.min(count - present.len()) {
+                present.push(byte & (1 << i) != 0);
+            }
+        }
+        Ok(present)
+    }
+
+    fn int_column(&mut self, count: usize) -> anyhow::Result<Vec<i64>> {
+        let mut previous = 0i64;
+        (0..count)
+            .map(|_| {
+                previous = previous.wrapping_add(unzigzag(self.varint()?));
+                Ok(previous)
+            })
+            .collect()
+    }
+
+    fn float_column(&mut self, count: usize) -> anyhow::Result<Vec<f64>> {
+        let mut previous = 0u64;
+        (0..count)
+            .map(|_| {
+                let trailing_zeros = u32::from(self.byte()?);
+                ensure!(
+                    trailing_zeros <= 64,
+                    "Invalid float in ADLX metrics history"
+                );
+                if trailing_zeros < 64 {
+                    previous ^= self.varint()? << trailing_zeros;
+                }
+                Ok(f64::from_bits(previous))
+            })
+            .collect()
+    }
+
+    fn optional_int_column(&mut self, count: usize) -> anyhow::Result<Vec<Option<i32>>> {
+        let present = self.presence(count)?;
+        let mut values = self
+            .int_column(present.iter().filter(|&&p| p).count())?
+            .into_iter();
+        present
+            .into_iter()
+            .map(|p| {
+                p.then(|| values.next().unwrap().try_into())
+                    .transpose()
+                    .context("Invalid integer in ADLX metrics history")
+            })
+            .collect()
+    }
+
+    fn optional_float_column(&mut self, count: usize) -> anyhow::Result<Vec<Option<f64>>> {
+        let present = self.presence(count)?;
+        let mut values = self
+            .float_column(present.iter().filter(|&&p| p).count())?
+            .into_iter();
+        Ok(present
+            .into_iter()
+            .map(|p| p.then(|| values.next().unwrap()))
+            .collect())
+    }
+
+    fn finish(self) -> anyhow::Result<()> {
+        ensure!(
+            self.bytes.is_empty(),
+            "Trailing data after ADLX metrics history"
+        );
+        Ok(())
+    }
+}
+
+/// Applies `$m!(field, optional_float_column | optional_int_column)` to every metric of
+/// [`GpuMetricsSample`].
+macro_rules! for_each_metric {
+    ($m:ident) => {
+        $m!(usage, optional_float_column);
+        $m!(clock_speed, optional_int_column);
+        $m!(vram_clock_speed, optional_int_column);
+        $m!(power, optional_float_column);
+        $m!(total_board_power, optional_float_column);
+        $m!(voltage, optional_int_column);
+        $m!(vram, optional_int_column);
+        $m!(fan_speed, optional_int_column);
+        $m!(temperature, optional_float_column);
+        $m!(intake_temperature, optional_float_column);
+        $m!(hotspot_temperature, optional_float_column);
+    };
+}
+
+/// Encodes `samples`, for example from [`GpuMetricsList::samples()`].
+///
+/// [`GpuMetricsList::samples()`]: super::GpuMetricsList::samples()
+pub fn encode_gpu_metrics(samples: &[GpuMetricsSample]) -> Vec<u8> {
+    let mut encoder = Encoder::new(KIND_GPU_METRICS, samples.len());
+    encoder.int_column(samples.iter().map(|s| s.time_stamp));
+    macro_rules! encode {
+        ($field:ident, $column:ident) => {
+            encoder.$column(&samples.iter().map(|s| s.$field).collect::<Vec<_>>())
+        };
+    }
+    for_each_metric!(encode);
+    encoder.bytes
+}
+
+/// Decodes samples written by [`encode_gpu_metrics()`].
+pub fn decode_gpu_metrics(bytes: &[u8]) -> anyhow::Result<Vec<GpuMetricsSample>> {
+    let (mut decoder, count) = Decoder::new(bytes, KIND_GPU_METRICS)?;
+    let mut samples = decoder
+        .int_column(count)?
+        .into_iter()
+        .map(|time_stamp| GpuMetricsSample {
+            time_stamp,
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+    macro_rules! decode {
+        ($field:ident, $column:ident) => {
+            for (sample, value) in samples.iter_mut().zip(decoder.$column(count)?) {
+                sample.$field = value;
+            }
+        };
+    }
+    for_each_metric!(decode);
+    decoder.finish()?;
+    Ok(samples)
+}
+
+/// Encodes `samples`, for example from [`FpsList::samples()`].
+///
+/// [`FpsList::samples()`]: super::FpsList::samples()
+pub fn encode_fps(samples: &[FpsSample]) -> Vec<u8> {
+    let mut encoder = Encoder::new(KIND_FPS, samples.len());
+    encoder.int_column(samples.iter().map(|s| s.time_stamp));
+    encoder.int_column(samples.iter().map(|s| i64::from(s.fps)));
+    encoder.bytes
+}
+
+/// Decodes samples written by [`encode_fps()`].
+pub fn decode_fps(bytes: &[u8]) -> anyhow::Result<Vec<FpsSample>> {
+    let (mut decoder, count) = Decoder::new(bytes, KIND_FPS)?;
+    let time_stamps = decoder.int_column(count)?;
+    let fps = decoder.int_column(count)?;
+    decoder.finish()?;
+    time_stamps
+        .into_iter()
+        .zip(fps)
+        .map(|(time_stamp, fps)| {
+            Ok(FpsSample {
+                time_stamp,
+                fps: fps
+                    .try_into()
+                    .context("Invalid FPS in ADLX metrics history")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_fps, decode_gpu_metrics, encode_fps, encode_gpu_metrics};
+    use crate::{FpsSample, GpuMetricsSample};
+
+    fn samples() -> Vec<GpuMetricsSample> {
+        (0..1000)
+            .map(|i| GpuMetricsSample {
+                time_stamp: 1_700_000_000_000 + i * 100,
+                usage: Some(f64::from(i as i32 % 100) * 0.5),
+                clock_speed: Some(2100 - (i as i32 % 7) * 13),
+                vram_clock_speed: (i % 3 != 0).then_some(1250),
+                power: Some(200.0 + (i as f64).sin() * 50.0),
+                total_board_power: None,
+                voltage: Some(-5 + i as i32 % 11),
+                vram: Some(i32::MAX - i as i32),
+                fan_speed: Some(i32::MIN + i as i32),
+                temperature: Some(65.0),
+                intake_temperature: Some(f64::INFINITY),
+                hotspot_temperature: (i % 2 == 0).then_some(-0.0),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn gpu_metrics_round_trip() {
+        let samples = samples();
+        let encoded = encode_gpu_metrics(&samples);
+        assert_eq!(decode_gpu_metrics(&encoded).unwrap(), samples);
+        // Smaller than the in-memory representation by a wide margin
+        assert!(encoded.len() < samples.len() * std::mem::size_of::<GpuMetricsSample>() / 4);
+
+        assert_eq!(
+            decode_gpu_metrics(&encode_gpu_metrics(&[])).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn fps_round_trip() {
+        let samples = [
+            FpsSample {
+                time_stamp: 10,
+                fps: 144,
+            },
+            FpsSample {
+                time_stamp: 5,
+                fps: 0,
+            },
+        ];
+        assert_eq!(decode_fps(&encode_fps(&samples)).unwrap(), samples);
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        let encoded = encode_gpu_metrics(&samples());
+        assert!(decode_gpu_metrics(&encoded[..encoded.len() - 1]).is_err());
+        assert!(decode_gpu_metrics(&[encoded.as_slice(), &[0]].concat()).is_err());
+        assert!(decode_fps(&encoded).is_err());
+        assert!(decode_gpu_metrics(b"ADLXH\x02").is_err());
+    }
+}
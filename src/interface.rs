@@ -1,8 +1,11 @@
 //! `ADLX` interfaces look identical to COM objects.
 
+use std::borrow::Cow;
+
 use super::{
     ffi,
     result::{Error, Result},
+    version::{check_interface, thread_runtime_version, AdlxVersion},
 };
 
 /// # Safety
@@ -15,6 +18,21 @@ pub unsafe trait Interface: Sized {
     type Impl;
     type Vtable;
     const IID: &'static str;
+    /// [`Self::IID`] as a nul-terminated UTF-16 string, encoded at compile time by
+    /// `adlx_interface!`. When [`None`], casting encodes [`Self::IID`] on every call.
+    #[doc(hidden)]
+    const WIDE_IID: Option<&'static [u16]> = None;
+    /// The first ADLX version that declares this interface. Casting to it, and generated getters
+    /// that return it, fail with [`Error::required_version()`] on older runtimes without calling
+    /// into ADLX. Interfaces are checked against the
+    /// [runtime version][crate::AdlxHelper::runtime_version()] of the newest helper that is
+    /// alive on the current thread, and not at all on threads without one.
+    ///
+    /// Defaults to 1.0, which is left in place by the interfaces that were part of ADLX 1.0. Of
+    /// the hand-written wrappers, only `Gpu1`, `Gpu2`, `System1`, `System2`, `Application` and
+    /// `GpuAppsListChangedHandling` were added later and override it. The generated wrappers take
+    /// it from the `MIN_VERSIONS` table in `api_gen`, and typed lists from their items.
+    const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 0);
 
     /// A reference to the interface's vtable
     #[doc(hidden)]
@@ -70,8 +88,243 @@ pub unsafe trait Interface: Sized {
         let interface: &InterfaceImpl = unsafe { std::mem::transmute(self) };
         interface.cast()
     }
+
+    /// Like [`Self::cast()`], but returns [`None`] instead of an [`Error`] when the object does
+    /// not implement `I`, like an older driver that lacks a newer interface version.
+    #[doc(alias = "QueryInterface")]
+    fn try_cast<I: Interface>(&self) -> Result<Option<I>> {
+        unknown_interface_as_none(self.cast())
+    }
+}
+
+/// Maps [`ffi::ADLX_RESULT_ADLX_UNKNOWN_INTERFACE`] returned by `QueryInterface`, and interfaces
+/// that the runtime is too old for, to [`None`].
+pub(crate) fn unknown_interface_as_none<I>(result: Result<I>) -> Result<Option<I>> {
+    match result {
+        Ok(interface) => Ok(Some(interface)),
+        Err(e) if e.adlx_result() == ffi::ADLX_RESULT_ADLX_UNKNOWN_INTERFACE => Ok(None),
+        Err(e) if e.required_version().is_some() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// The nul-terminated UTF-16 IID that `QueryInterface` takes, preferably the one that was encoded
+/// at compile time.
+pub(crate) fn wide_iid<I: Interface>() -> Cow<'static, [u16]> {
+    match I::WIDE_IID {
+        Some(iid) => Cow::Borrowed(iid),
+        None => I::IID.encode_utf16().chain(std::iter::once(0u16)).collect(),
+    }
+}
+
+/// Encodes an ASCII IID as a nul-terminated UTF-16 string of length `N`, in a constant.
+pub(crate) const fn encode_wide_iid<const N: usize>(iid: &str) -> [u16; N] {
+    let bytes = iid.as_bytes();
+    assert!(bytes.len() + 1 == N, "N must include the nul terminator");
+    let mut wide = [0; N];
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(bytes[i].is_ascii(), "IIDs must be ASCII");
+        wide[i] = bytes[i] as u16;
+        i += 1;
+    }
+    wide
+}
+
+/// Converts between a Rust type and the raw type of an ADLX getter or setter, for the methods
+/// that `adlx_interface!` generates.
+pub(crate) trait FfiValue {
+    type Raw;
+
+    fn from_ffi(raw: Self::Raw) -> Self;
+    fn into_ffi(self) -> Self::Raw;
 }
 
+impl FfiValue for bool {
+    type Raw = ffi::adlx_bool;
+
+    fn from_ffi(raw: Self::Raw) -> Self {
+        raw != 0
+    }
+
+    fn into_ffi(self) -> Self::Raw {
+        self.into()
+    }
+}
+
+/// Implements [`FfiValue`] for types that ADLX takes and returns as-is.
+macro_rules! ffi_value_as_is {
+    ($($ty:ty),+) => {
+        $(impl FfiValue for $ty {
+            type Raw = Self;
+
+            fn from_ffi(raw: Self::Raw) -> Self {
+                raw
+            }
+
+            fn into_ffi(self) -> Self::Raw {
+                self
+            }
+        })+
+    };
+}
+
+ffi_value_as_is!(u8, u16, i32, u32, i64, u64, usize, f64, ffi::ADLX_IntRange);
+
+/// Declares a wrapper around an ADLX interface, with getters and setters for its vtable
+/// functions:
+///
+/// ```ignore
+/// adlx_interface! {
+///     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_chill/>
+///     #[doc(alias = "IADLX3DChill")]
+///     pub struct ThreeDChill: IADLX3DChill(IADLX3DChillVtbl) {
+///         /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_chill__is_enabled/>
+///         #[doc(alias = "IsEnabled")]
+///         fn is_enabled() -> bool = IsEnabled;
+///
+///         /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x3_d_chill__set_enabled/>
+///         #[doc(alias = "SetEnabled")]
+///         fn set_enabled(enable: bool) = SetEnabled;
+///     }
+/// }
+/// ```
+///
+/// A getter returns the value of the single output argument of its function, and a setter passes
+/// its single argument. Both convert with [`FfiValue`]. An interface that extends another one
+/// lists it and its bases after `:`, like `pub struct Gpu2: IADLXGPU2(IADLXGPU2Vtbl): Gpu1, Gpu`,
+/// and dereferences to the first. Doc aliases can not be derived from the identifiers, so they are
+/// passed as attributes.
+///
+/// An interface that was added after ADLX 1.0 starts its body with
+/// `const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 2);`, which becomes its
+/// [`Interface::MIN_VERSION`]. `adlx_interface! { impl Name { .. } }` adds getters and setters to an
+/// interface that is declared elsewhere.
+macro_rules! adlx_interface {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident: $ffi:ident($vtable:ident) $(: $parent:ty $(, $ancestor:ty)*)? {
+            $(const MIN_VERSION: AdlxVersion = $min_version:expr;)?
+            $(
+                $(#[$method_meta:meta])*
+                fn $method:ident($($arg:ident: $arg_ty:ty)?) $(-> $ret:ty)? = $function:ident;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug)]
+        #[repr(transparent)]
+        pub struct $name($crate::interface::adlx_interface!(@inner $($parent)?));
+
+        unsafe impl $crate::interface::Interface for $name {
+            type Impl = $crate::ffi::$ffi;
+            type Vtable = $crate::ffi::$vtable;
+            const IID: &'static str = stringify!($ffi);
+            const WIDE_IID: Option<&'static [u16]> = Some(
+                &$crate::interface::encode_wide_iid::<{ stringify!($ffi).len() + 1 }>(
+                    stringify!($ffi),
+                ),
+            );
+            $(const MIN_VERSION: $crate::version::AdlxVersion = $min_version;)?
+        }
+
+        $(
+            $crate::interface::inherits!($name: $parent $(, $ancestor)*);
+
+            impl ::std::ops::Deref for $name {
+                type Target = $parent;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+        )?
+
+        $crate::interface::adlx_interface! {
+            impl $name {
+                $(
+                    $(#[$method_meta])*
+                    fn $method($($arg: $arg_ty)?) $(-> $ret)? = $function;
+                )*
+            }
+        }
+    };
+    (
+        impl $name:ident {
+            $(
+                $(#[$method_meta:meta])*
+                fn $method:ident($($arg:ident: $arg_ty:ty)?) $(-> $ret:ty)? = $function:ident;
+            )*
+        }
+    ) => {
+        impl $name {
+            $(
+                $crate::interface::adlx_interface!(
+                    @method $(#[$method_meta])* $method($($arg: $arg_ty)?) $(-> $ret)? = $function
+                );
+            )*
+        }
+    };
+    (@inner) => { $crate::interface::InterfaceImpl };
+    (@inner $parent:ty) => { $parent };
+    (@method $(#[$meta:meta])* $method:ident() -> $ret:ty = $function:ident) => {
+        $(#[$meta])*
+        pub fn $method(&self) -> $crate::result::Result<$ret> {
+            use $crate::interface::{FfiValue, Interface};
+
+            let mut value = ::std::mem::MaybeUninit::uninit();
+            let result =
+                unsafe { (self.vtable().$function.unwrap())(self.as_raw(), value.as_mut_ptr()) };
+
+            $crate::result::Error::from_result_with_assume_init_on_success(result, value)
+                .map(<$ret as FfiValue>::from_ffi)
+        }
+    };
+    (@method $(#[$meta:meta])* $method:ident($arg:ident: $arg_ty:ty) = $function:ident) => {
+        $(#[$meta])*
+        pub fn $method(&mut self, $arg: $arg_ty) -> $crate::result::Result<()> {
+            use $crate::interface::{FfiValue, Interface};
+
+            let result =
+                unsafe { (self.vtable().$function.unwrap())(self.as_raw(), $arg.into_ffi()) };
+
+            $crate::result::Error::from_result(result)
+        }
+    };
+}
+pub(crate) use adlx_interface;
+
+/// Marks an interface that extends `Base`, like `IADLXGPU2` extends `IADLXGPU1`, so that it can
+/// be used as `Base` without calling `QueryInterface`.
+///
+/// Every interface trivially inherits from itself.
+///
+/// # Safety
+/// `Self` must have the same layout as `Base`, and [`Interface::Vtable`] of `Self` must begin with
+/// the vtable of `Base`.
+pub unsafe trait Inherits<Base: Interface>: Interface {
+    /// Borrows this interface as `Base`.
+    fn upcast_ref(&self) -> &Base {
+        // SAFETY: the implementor guarantees that `Self` has the layout of `Base`
+        unsafe { &*<*const Self>::cast(self) }
+    }
+
+    /// Converts this interface into `Base`, keeping its reference.
+    fn upcast(self) -> Base {
+        unsafe { Base::from_raw(self.into_raw().cast()) }
+    }
+}
+
+unsafe impl<T: Interface> Inherits<T> for T {}
+
+/// Implements [`Inherits`] for an interface and every one of its (indirect) bases.
+macro_rules! inherits {
+    ($derived:ty: $($base:ty),+) => {
+        $(unsafe impl $crate::interface::Inherits<$base> for $derived {})+
+    };
+}
+pub(crate) use inherits;
+
 /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_interface/>
 ///
 /// All `IADLX*` types are expected to own this object, and access the vtable by implementing
@@ -80,7 +333,10 @@ pub unsafe trait Interface: Sized {
 ///
 /// Owning this type means proper [`Drop`] and [`Clone`] semantics, and less manual conversions like
 /// e.g. [`Interface::as_raw()`].
-// TODO(Marijn): We could also achieve that by creating a little macro that provides the conversions, while being more type-safe!
+///
+/// Interfaces are neither [`Send`] nor [`Sync`], because AMD does not document whether ADLX
+/// objects may be used from other threads. Call ADLX from multiple threads through an
+/// [`AdlxExecutor`][crate::AdlxExecutor] instead.
 #[derive(Debug)]
 #[repr(transparent)]
 #[doc(alias = "IADLXInterface")]
@@ -96,11 +352,8 @@ impl InterfaceImpl {
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_interface__query_interface/>
     #[doc(alias = "QueryInterface")]
     pub fn cast<I: Interface>(&self) -> Result<I> {
-        let interface_name = I::IID
-            // TODO: Use windows-rs' helpers to create static wchars?
-            .encode_utf16()
-            .chain(std::iter::once(0u16))
-            .collect::<Vec<_>>();
+        check_interface::<I>(thread_runtime_version())?;
+        let interface_name = wide_iid::<I>();
         let mut interface = std::mem::MaybeUninit::uninit();
         let result = unsafe {
             (self.vtable().QueryInterface.unwrap())(
@@ -130,5 +383,76 @@ impl Clone for InterfaceImpl {
     }
 }
 
-unsafe impl Send for InterfaceImpl {}
-unsafe impl Sync for InterfaceImpl {}
+#[cfg(all(test, feature = "replay"))]
+mod tests {
+    use super::{wide_iid, Inherits, Interface};
+    use crate::{
+        replay::tests::{replay_gpu, replay_gpu2, session},
+        AdlxHelper, AdlxVersion, Gpu, Gpu1, Gpu2, NewestGpu, System2, ThreeDChill,
+    };
+
+    #[test]
+    fn encodes_iids_at_compile_time() {
+        let expected = "IADLX3DChill\0".encode_utf16().collect::<Vec<_>>();
+        assert_eq!(ThreeDChill::WIDE_IID, Some(&expected[..]));
+        assert_eq!(*wide_iid::<ThreeDChill>(), expected);
+
+        // Hand-written impls are encoded when casting
+        assert_eq!(Gpu::WIDE_IID, None);
+        assert_eq!(
+            *wide_iid::<Gpu>(),
+            "IADLXGPU\0".encode_utf16().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn upcasts_and_probes_versions() {
+        let (helper, gpu) = replay_gpu(|_| {});
+        let gpus = helper.system().gpus().unwrap();
+
+        assert!(gpu.try_cast::<Gpu2>().unwrap().is_none());
+        let gpu1 = gpu.try_cast::<Gpu1>().unwrap().unwrap();
+        assert_eq!(
+            Inherits::<Gpu>::upcast_ref(&gpu1).name().unwrap(),
+            "AMD Radeon RX 7900 XTX"
+        );
+        assert!(matches!(gpu.newest(), Ok(NewestGpu::Gpu1(_))));
+
+        gpus.add_back(gpu1).unwrap();
+        assert_eq!(gpus.size(), 2);
+        assert_eq!(gpus.at(1).unwrap().vendor_id().unwrap(), "1002");
+
+        let (_helper, gpu2) = replay_gpu2(|_| {});
+        let gpu: Gpu = gpu2.upcast();
+        assert_eq!(gpu.device_id().unwrap(), "744C");
+        let newest = gpu.newest();
+        assert!(
+            matches!(newest, Ok(NewestGpu::Gpu2(_))),
+            "expected Gpu2, got {newest:?}"
+        );
+    }
+
+    #[test]
+    fn checks_the_runtime_of_each_helper() {
+        let (_helper, gpu) = replay_gpu2(|_| {});
+        let mut old = session();
+        old.full_version = AdlxVersion::new(1, 2).full_version();
+        let old = AdlxHelper::replay(old);
+
+        // Interfaces are checked against the newest helper on this thread, the system against its
+        // own helper
+        let required = Some(AdlxVersion::new(1, 3));
+        assert_eq!(gpu.cast::<Gpu2>().unwrap_err().required_version(), required);
+        assert_eq!(
+            old.system()
+                .cast::<System2>()
+                .unwrap_err()
+                .required_version(),
+            required
+        );
+
+        // Dropping a helper does not affect the others
+        drop(old);
+        assert!(gpu.cast::<Gpu2>().is_ok());
+    }
+}
@@ -8,38 +8,86 @@
 )]
 pub mod ffi;
 
+pub mod adapter;
+pub mod application;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod bios_info;
+pub mod capabilities;
+pub mod chart;
 pub mod display;
 pub mod display_free_sync;
 pub mod display_list;
 pub mod display_services;
+pub mod downsample;
+pub mod driver_version;
+pub mod enums;
+pub mod executor;
+pub mod fps;
+pub mod generated;
 pub mod gpu;
+pub mod gpu_info;
 pub mod gpu_list;
 pub mod gpu_metrics;
 pub mod helper;
+pub mod history;
 pub mod interface;
 pub mod list;
+pub mod pci_ids;
 pub mod performance_monitoring_services;
+pub mod power_off;
+#[cfg(feature = "replay")]
+mod replay;
 pub mod result;
+pub mod sampler;
+#[cfg(feature = "replay")]
+pub mod session;
 pub mod system;
+pub mod system_gpu_metrics;
 pub mod three_d_chill;
 pub mod three_d_frame_rate_target_control;
 pub mod three_d_settings_services;
 pub mod three_d_wait_for_vertical_refresh;
+pub mod throttling;
+pub mod trace;
+pub mod version;
 
+pub use adapter::*;
+pub use application::*;
+#[cfg(feature = "arrow")]
+pub use arrow::*;
+pub use bios_info::*;
+pub use capabilities::*;
+pub use chart::*;
 pub use display::*;
 pub use display_free_sync::*;
 pub use display_list::*;
 pub use display_services::*;
+pub use downsample::*;
+pub use driver_version::*;
+pub use enums::*;
+pub use executor::*;
+pub use fps::*;
+pub use generated::*;
 pub use gpu::*;
+pub use gpu_info::*;
 pub use gpu_list::*;
 pub use gpu_metrics::*;
 pub use helper::*;
+pub use history::*;
 pub use interface::*;
 pub use list::*;
+pub use pci_ids::*;
 pub use performance_monitoring_services::*;
+pub use power_off::*;
 pub use result::*;
+pub use sampler::*;
 pub use system::*;
+pub use system_gpu_metrics::*;
 pub use three_d_chill::*;
 pub use three_d_frame_rate_target_control::*;
 pub use three_d_settings_services::*;
 pub use three_d_wait_for_vertical_refresh::*;
+pub use throttling::*;
+pub use trace::*;
+pub use version::*;
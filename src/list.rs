@@ -1,6 +1,14 @@
+use std::{
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::{Deref, Range},
+};
+
 use super::{
     ffi,
-    interface::{Interface, InterfaceImpl},
+    interface::{Inherits, Interface, InterfaceImpl},
+    result::{Error, Result},
+    version::AdlxVersion,
 };
 
 /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_list/>
@@ -21,4 +29,372 @@ impl List {
     pub fn size(&self) -> u32 {
         unsafe { (self.vtable().Size.unwrap())(self.as_raw()) }
     }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_list__empty/>
+    #[doc(alias = "Empty")]
+    pub fn empty(&self) -> bool {
+        unsafe { (self.vtable().Empty.unwrap())(self.as_raw()) != 0 }
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_list__begin/>
+    ///
+    /// Location of the first element.
+    #[doc(alias = "Begin")]
+    pub fn begin(&self) -> u32 {
+        unsafe { (self.vtable().Begin.unwrap())(self.as_raw()) }
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_list__end/>
+    ///
+    /// Location just after the last element.
+    #[doc(alias = "End")]
+    pub fn end(&self) -> u32 {
+        unsafe { (self.vtable().End.unwrap())(self.as_raw()) }
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_list__clear/>
+    #[doc(alias = "Clear")]
+    pub fn clear(&self) -> Result<()> {
+        Error::from_result(unsafe { (self.vtable().Clear.unwrap())(self.as_raw()) })
+    }
+
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_list__remove__back/>
+    #[doc(alias = "Remove_Back")]
+    pub fn remove_back(&self) -> Result<()> {
+        Error::from_result(unsafe { (self.vtable().Remove_Back.unwrap())(self.as_raw()) })
+    }
+}
+
+#[doc(hidden)]
+pub type AtFn<T> = unsafe extern "C" fn(
+    *mut <T as ListItem>::ListImpl,
+    ffi::adlx_uint,
+    *mut *mut <T as Interface>::Impl,
+) -> ffi::ADLX_RESULT;
+
+#[doc(hidden)]
+pub type AddBackFn<T> = unsafe extern "C" fn(
+    *mut <T as ListItem>::ListImpl,
+    *mut <T as Interface>::Impl,
+) -> ffi::ADLX_RESULT;
+
+/// An element of a typed ADLX list like `IADLXGPUList`, which is wrapped by [`AdlxList<Self>`].
+///
+/// # Safety
+/// [`Self::ListImpl`] and [`Self::ListVtable`] must describe the list interface holding elements
+/// of `Self`, whose vtable starts with [`ffi::IADLXListVtbl`].
+pub unsafe trait ListItem: Interface {
+    type ListImpl;
+    type ListVtable;
+    const LIST_IID: &'static str;
+
+    /// The `At_*List` function of the list vtable
+    #[doc(hidden)]
+    fn at_fn(vtable: &Self::ListVtable) -> Option<AtFn<Self>>;
+
+    /// The `Add_Back_*List` function of the list vtable
+    #[doc(hidden)]
+    fn add_back_fn(vtable: &Self::ListVtable) -> Option<AddBackFn<Self>>;
+}
+
+/// Implements [`ListItem`] for an element type, given its list interface and the names of the
+/// typed `At` and `Add_Back` functions in the list vtable.
+macro_rules! list_item {
+    ($item:ty => $list:ident($vtable:ident), $at:ident, $add_back:ident) => {
+        unsafe impl $crate::list::ListItem for $item {
+            type ListImpl = $crate::ffi::$list;
+            type ListVtable = $crate::ffi::$vtable;
+            const LIST_IID: &'static str = stringify!($list);
+
+            fn at_fn(vtable: &Self::ListVtable) -> Option<$crate::list::AtFn<Self>> {
+                vtable.$at
+            }
+
+            fn add_back_fn(vtable: &Self::ListVtable) -> Option<$crate::list::AddBackFn<Self>> {
+                vtable.$add_back
+            }
+        }
+    };
+}
+pub(crate) use list_item;
+
+/// A typed ADLX list like `IADLXGPUList`, holding elements of type `T`.
+///
+/// Lists dereference to [`List`] for the untyped functions like [`List::size()`]. Iterating with
+/// [`Self::iter()`] or a `for` loop stops at the first element that ADLX fails to return, use
+/// [`Self::try_iter()`] to handle every error instead.
+#[repr(transparent)]
+pub struct AdlxList<T>(List, PhantomData<T>);
+
+unsafe impl<T: ListItem> Interface for AdlxList<T> {
+    type Impl = T::ListImpl;
+    type Vtable = T::ListVtable;
+    const IID: &'static str = T::LIST_IID;
+    const MIN_VERSION: AdlxVersion = T::MIN_VERSION;
+}
+
+unsafe impl<T: ListItem> Inherits<List> for AdlxList<T> {}
+
+impl<T> Clone for AdlxList<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T> std::fmt::Debug for AdlxList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AdlxList").field(&self.0).finish()
+    }
+}
+
+impl<T> Deref for AdlxList<T> {
+    type Target = List;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ListItem> AdlxList<T> {
+    /// The typed `At_*List` function, like
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_list__at/>
+    pub fn at(&self, location: u32) -> Result<T> {
+        let mut item = MaybeUninit::uninit();
+        let result = unsafe {
+            (T::at_fn(self.vtable()).unwrap())(self.as_raw(), location, item.as_mut_ptr())
+        };
+        Error::from_result_with_assume_init_on_success(result, item)
+            .map(|item| unsafe { T::from_raw(item) })
+    }
+
+    /// The typed `Add_Back_*List` function, like
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_list__add__back/>
+    ///
+    /// Accepts derived interfaces too, like a [`Gpu1`][crate::Gpu1] for a
+    /// [`GpuList`][crate::GpuList].
+    pub fn add_back(&self, item: impl Inherits<T>) -> Result<()> {
+        let item = item.upcast();
+        // The list acquires its own reference to the item, ours is released when `item` is dropped
+        let result =
+            unsafe { (T::add_back_fn(self.vtable()).unwrap())(self.as_raw(), item.as_raw()) };
+        Error::from_result(result)
+    }
+
+    fn locations(&self) -> Range<u32> {
+        let begin = self.begin();
+        begin..self.end().max(begin)
+    }
+
+    /// Iterates over all elements, yielding the error of any element that ADLX fails to return.
+    pub fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter {
+            list: self,
+            locations: self.locations(),
+        }
+    }
+
+    /// Iterates over all elements, stopping at the first one that ADLX fails to return. Its error
+    /// is then available from [`Iter::error()`], see [`Self::try_iter()`] to get all errors.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.try_iter(),
+            error: None,
+        }
+    }
+}
+
+/// Iterator returned by [`AdlxList::try_iter()`].
+#[derive(Debug)]
+pub struct TryIter<'a, T> {
+    list: &'a AdlxList<T>,
+    locations: Range<u32>,
+}
+
+impl<T: ListItem> Iterator for TryIter<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.locations.next().map(|i| self.list.at(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.locations.size_hint()
+    }
+}
+
+impl<T: ListItem> DoubleEndedIterator for TryIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.locations.next_back().map(|i| self.list.at(i))
+    }
+}
+
+impl<T: ListItem> ExactSizeIterator for TryIter<'_, T> {}
+
+/// Iterator returned by [`AdlxList::iter()`].
+///
+/// Stops at the first element that ADLX fails to return, whose error is then available from
+/// [`Self::error()`].
+#[derive(Debug)]
+pub struct Iter<'a, T> {
+    inner: TryIter<'a, T>,
+    error: Option<Error>,
+}
+
+impl<T> Iter<'_, T> {
+    /// The error that ended the iteration early, if ADLX failed to return an element.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+}
+
+impl<T: ListItem> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+        stop_at_error(item, &mut self.inner.locations, &mut self.error)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.inner.locations.len()))
+    }
+}
+
+impl<T: ListItem> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next_back()?;
+        stop_at_error(item, &mut self.inner.locations, &mut self.error)
+    }
+}
+
+impl<'a, T: ListItem> IntoIterator for &'a AdlxList<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator returned by [`AdlxList::into_iter()`], which keeps the list alive.
+///
+/// Stops at the first element that ADLX fails to return, like [`Iter`].
+#[derive(Debug)]
+pub struct IntoIter<T> {
+    list: AdlxList<T>,
+    locations: Range<u32>,
+    error: Option<Error>,
+}
+
+impl<T> IntoIter<T> {
+    /// The error that ended the iteration early, if ADLX failed to return an element.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+}
+
+impl<T: ListItem> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.list.at(self.locations.next()?);
+        stop_at_error(item, &mut self.locations, &mut self.error)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.locations.len()))
+    }
+}
+
+impl<T: ListItem> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.list.at(self.locations.next_back()?);
+        stop_at_error(item, &mut self.locations, &mut self.error)
+    }
+}
+
+impl<T: ListItem> IntoIterator for AdlxList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            locations: self.locations(),
+            list: self,
+            error: None,
+        }
+    }
+}
+
+/// Returns the element, or records its error and skips the remaining `locations`.
+fn stop_at_error<T>(
+    item: Result<T>,
+    locations: &mut Range<u32>,
+    error: &mut Option<Error>,
+) -> Option<T> {
+    item.map_err(|e| {
+        *locations = locations.end..locations.end;
+        *error = Some(e);
+    })
+    .ok()
+}
+
+#[cfg(all(test, feature = "replay"))]
+mod tests {
+    use crate::{replay::tests::session, AdlxHelper};
+
+    #[test]
+    fn iterates_from_both_ends() {
+        let helper = AdlxHelper::replay(session());
+        let gpus = helper.system().gpus().unwrap();
+        let gpu = gpus.at(0).unwrap();
+        gpus.add_back(gpu.clone()).unwrap();
+        gpus.add_back(gpu).unwrap();
+
+        assert_eq!((gpus.begin(), gpus.end()), (0, 3));
+        assert_eq!(gpus.iter().count(), 3);
+        assert_eq!(gpus.iter().rev().count(), 3);
+        assert!(gpus.try_iter().all(|gpu| gpu.is_ok()));
+        assert!(gpus.at(3).is_err());
+
+        gpus.remove_back().unwrap();
+        assert_eq!(gpus.clone().into_iter().count(), 2);
+        let mut iter = (&gpus).into_iter();
+        assert!(iter.next_back().is_some());
+        assert!(iter.next().is_some());
+        assert!(iter.next().is_none());
+        assert!(iter.error().is_none());
+
+        gpus.clear().unwrap();
+        assert!(gpus.empty());
+        assert!(gpus.remove_back().is_err());
+    }
+
+    #[test]
+    fn stops_at_the_first_error() {
+        let helper = AdlxHelper::replay(session());
+        let gpus = helper.system().gpus().unwrap();
+        gpus.add_back(gpus.at(0).unwrap()).unwrap();
+
+        // Shrinking the list makes `at()` fail for locations the iterators already took
+        let mut iter = gpus.iter();
+        let mut into_iter = gpus.clone().into_iter();
+        let try_iter = gpus.try_iter();
+        gpus.remove_back().unwrap();
+
+        assert!(iter.next_back().is_none());
+        assert!(iter.error().is_some());
+        assert!(iter.next().is_none());
+
+        assert!(into_iter.next().is_some());
+        assert!(into_iter.error().is_none());
+        assert!(into_iter.next().is_none());
+        assert!(into_iter.error().is_some());
+        assert!(into_iter.next_back().is_none());
+
+        assert_eq!(
+            try_iter.map(|gpu| gpu.is_ok()).collect::<Vec<_>>(),
+            [true, false]
+        );
+    }
 }
@@ -0,0 +1,443 @@
+//! Numeric PCI identifiers of a [`Gpu`], and marketing names for them from a `pci.ids` database.
+
+use std::{collections::HashMap, fmt, fs, io, path::Path, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{gpu::Gpu, result::Error};
+
+/// PCI vendor id of AMD/ATI
+pub const AMD_VENDOR_ID: u16 = 0x1002;
+
+/// The PCI identifiers of a GPU, as returned by [`Gpu::pci_ids()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PciIds {
+    pub vendor_id: u16,
+    pub device_id: u16,
+    pub sub_system_vendor_id: u16,
+    pub sub_system_id: u16,
+    pub revision_id: u8,
+}
+
+/// A PCI id or PNP string passed to [`PciIds`] is not valid hexadecimal or is missing a field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsePciIdsError(String);
+
+impl fmt::Display for ParsePciIdsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid PCI id `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParsePciIdsError {}
+
+/// Parses a hexadecimal id with an optional `0x` prefix.
+pub(crate) fn parse_hex<T: TryFrom<u32>>(s: &str) -> Result<T, ParsePciIdsError> {
+    parse_hex_digits(s).map(|(value, _)| value)
+}
+
+/// Like [`parse_hex()`], but also returns the number of digits after the prefix.
+fn parse_hex_digits<T: TryFrom<u32>>(s: &str) -> Result<(T, usize), ParsePciIdsError> {
+    let err = || ParsePciIdsError(s.to_owned());
+    let digits = s.trim();
+    let digits = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+        .unwrap_or(digits);
+    if digits.is_empty() || digits.len() > 2 * std::mem::size_of::<T>() {
+        return Err(err());
+    }
+    let value = u32::from_str_radix(digits, 16).map_err(|_| err())?;
+    let value = T::try_from(value).map_err(|_| err())?;
+    Ok((value, digits.len()))
+}
+
+impl PciIds {
+    /// Parses the hexadecimal strings returned by [`Gpu::vendor_id()`], [`Gpu::device_id()`],
+    /// [`Gpu::sub_system_vendor_id()`], [`Gpu::sub_system_id()`] and [`Gpu::revision_id()`].
+    ///
+    /// A `0x` prefix is accepted. `sub_system_id` may also be given in the 8 digit `SUBSYS` form
+    /// of a PNP string, whose last 4 digits must then match `sub_system_vendor_id`.
+    pub fn from_hex_strings(
+        vendor_id: &str,
+        device_id: &str,
+        sub_system_vendor_id: &str,
+        sub_system_id: &str,
+        revision_id: &str,
+    ) -> Result<Self, ParsePciIdsError> {
+        let sub_system_vendor_id = parse_hex(sub_system_vendor_id)?;
+        let sub_system_id = match parse_hex_digits::<u32>(sub_system_id)? {
+            (subsys, digits) if digits > 4 => {
+                if subsys as u16 != sub_system_vendor_id {
+                    return Err(ParsePciIdsError(sub_system_id.to_owned()));
+                }
+                (subsys >> 16) as u16
+            }
+            (subsys, _) => subsys as u16,
+        };
+        Ok(Self {
+            vendor_id: parse_hex(vendor_id)?,
+            device_id: parse_hex(device_id)?,
+            sub_system_vendor_id,
+            sub_system_id,
+            revision_id: parse_hex(revision_id)?,
+        })
+    }
+
+    /// Parses a PNP string as returned by [`Gpu::pnp_string()`], like
+    /// `PCI\VEN_1002&DEV_744C&SUBSYS_0E3B1002&REV_C8`. Any instance path after the hardware id
+    /// is ignored.
+    pub fn from_pnp_string(pnp_string: &str) -> Result<Self, ParsePciIdsError> {
+        let err = || ParsePciIdsError(pnp_string.to_owned());
+        let hardware_id = pnp_string
+            .trim()
+            .split('\\')
+            .nth(1)
+            .filter(|_| pnp_string.trim().to_ascii_uppercase().starts_with("PCI\\"))
+            .ok_or_else(err)?;
+
+        let (mut vendor_id, mut device_id, mut subsys, mut revision_id) = (None, None, None, None);
+        for field in hardware_id.split('&') {
+            let (key, value) = field.split_once('_').ok_or_else(err)?;
+            let slot = match key.to_ascii_uppercase().as_str() {
+                "VEN" => &mut vendor_id,
+                "DEV" => &mut device_id,
+                "SUBSYS" => &mut subsys,
+                "REV" => &mut revision_id,
+                // Like `CC_030000`
+                _ => continue,
+            };
+            *slot = Some(value);
+        }
+
+        let (Some(vendor_id), Some(device_id), Some(subsys), Some(revision_id)) =
+            (vendor_id, device_id, subsys, revision_id)
+        else {
+            return Err(err());
+        };
+        // SUBSYS is the subsystem id followed by the subsystem vendor id
+        let subsys = parse_hex::<u32>(subsys).map_err(|_| err())?;
+        Ok(Self {
+            vendor_id: parse_hex(vendor_id).map_err(|_| err())?,
+            device_id: parse_hex(device_id).map_err(|_| err())?,
+            sub_system_vendor_id: subsys as u16,
+            sub_system_id: (subsys >> 16) as u16,
+            revision_id: parse_hex(revision_id).map_err(|_| err())?,
+        })
+    }
+
+    pub fn is_amd(&self) -> bool {
+        self.vendor_id == AMD_VENDOR_ID
+    }
+}
+
+impl FromStr for PciIds {
+    type Err = ParsePciIdsError;
+
+    /// Shorthand for [`PciIds::from_pnp_string()`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_pnp_string(s)
+    }
+}
+
+impl fmt::Display for PciIds {
+    /// Formats the ids in the same form as a PNP string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PCI\\VEN_{:04X}&DEV_{:04X}&SUBSYS_{:04X}{:04X}&REV_{:02X}",
+            self.vendor_id,
+            self.device_id,
+            self.sub_system_id,
+            self.sub_system_vendor_id,
+            self.revision_id
+        )
+    }
+}
+
+/// Error returned by [`Gpu::pci_ids()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PciIdsError {
+    /// One of the [`Gpu`] getters failed
+    Adlx(Error),
+    Parse(ParsePciIdsError),
+    /// The ids returned by the individual getters differ from those in the PNP string
+    Mismatch {
+        getters: PciIds,
+        pnp_string: PciIds,
+    },
+}
+
+impl fmt::Display for PciIdsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Adlx(e) => e.fmt(f),
+            Self::Parse(e) => e.fmt(f),
+            Self::Mismatch {
+                getters,
+                pnp_string,
+            } => write!(
+                f,
+                "PCI ids `{getters}` do not match PNP string `{pnp_string}`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PciIdsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Adlx(e) => Some(e),
+            Self::Parse(e) => Some(e),
+            Self::Mismatch { .. } => None,
+        }
+    }
+}
+
+impl From<Error> for PciIdsError {
+    fn from(e: Error) -> Self {
+        Self::Adlx(e)
+    }
+}
+
+impl From<ParsePciIdsError> for PciIdsError {
+    fn from(e: ParsePciIdsError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl Gpu {
+    /// Parses the PCI ids from both the individual hexadecimal getters and [`Self::pnp_string()`],
+    /// failing with [`PciIdsError::Mismatch`] if they disagree.
+    pub fn pci_ids(&self) -> Result<PciIds, PciIdsError> {
+        let getters = PciIds::from_hex_strings(
+            self.vendor_id()?,
+            self.device_id()?,
+            self.sub_system_vendor_id()?,
+            self.sub_system_id()?,
+            self.revision_id()?,
+        )?;
+        let pnp_string = PciIds::from_pnp_string(self.pnp_string()?)?;
+        if getters != pnp_string {
+            return Err(PciIdsError::Mismatch {
+                getters,
+                pnp_string,
+            });
+        }
+        Ok(getters)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Vendor {
+    name: String,
+    devices: HashMap<u16, Device>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Device {
+    name: String,
+    /// Names by `(sub_system_vendor_id, sub_system_id)`
+    subsystems: HashMap<(u16, u16), String>,
+}
+
+/// Vendor, device and subsystem names from a database in the format of
+/// <https://pci-ids.ucw.cz/>, to look up marketing names for [`PciIds`].
+///
+/// No copy of the database is bundled with this crate, and Windows does not ship one. Pass the
+/// path of a `pci.ids` downloaded ahead of time to [`Self::load()`], or its contents to
+/// [`Self::parse()`].
+#[derive(Clone, Debug, Default)]
+pub struct PciIdsDatabase {
+    vendors: HashMap<u16, Vendor>,
+}
+
+impl PciIdsDatabase {
+    /// Parses the contents of a `pci.ids` file, skipping lines that are not understood.
+    ///
+    /// Only the vendor section is read, the device classes at the end of the file are ignored.
+    pub fn parse(contents: &str) -> Self {
+        let mut vendors = HashMap::<u16, Vendor>::new();
+        let mut vendor_id = None;
+        let mut device_id = None;
+
+        for line in contents.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with("C ") {
+                break;
+            }
+
+            let depth = line.len() - line.trim_start_matches('\t').len();
+            let Some((ids, name)) = line.trim_start_matches('\t').split_once("  ") else {
+                continue;
+            };
+            let name = name.trim().to_owned();
+            match depth {
+                0 => {
+                    device_id = None;
+                    vendor_id = parse_hex(ids).ok();
+                    if let Some(id) = vendor_id {
+                        vendors.entry(id).or_default().name = name;
+                    }
+                }
+                1 => {
+                    device_id = parse_hex(ids).ok();
+                    let (Some(vendor_id), Some(device_id)) = (vendor_id, device_id) else {
+                        continue;
+                    };
+                    let vendor = vendors.get_mut(&vendor_id).unwrap();
+                    vendor.devices.entry(device_id).or_default().name = name;
+                }
+                2 => {
+                    // `sub_system_vendor_id sub_system_id  name`
+                    let (Some(vendor_id), Some(device_id)) = (vendor_id, device_id) else {
+                        continue;
+                    };
+                    let Some((sub_vendor, sub_device)) = ids.split_once(' ') else {
+                        continue;
+                    };
+                    let (Ok(sub_vendor), Ok(sub_device)) =
+                        (parse_hex(sub_vendor), parse_hex(sub_device))
+                    else {
+                        continue;
+                    };
+                    let device = vendors
+                        .get_mut(&vendor_id)
+                        .unwrap()
+                        .devices
+                        .get_mut(&device_id);
+                    device
+                        .unwrap()
+                        .subsystems
+                        .insert((sub_vendor, sub_device), name);
+                }
+                _ => {}
+            }
+        }
+
+        Self { vendors }
+    }
+
+    /// Reads and [parses][Self::parse()] the `pci.ids` file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+
+    pub fn vendor_name(&self, vendor_id: u16) -> Option<&str> {
+        Some(&self.vendors.get(&vendor_id)?.name)
+    }
+
+    pub fn device_name(&self, vendor_id: u16, device_id: u16) -> Option<&str> {
+        Some(&self.vendors.get(&vendor_id)?.devices.get(&device_id)?.name)
+    }
+
+    pub fn subsystem_name(&self, ids: &PciIds) -> Option<&str> {
+        self.vendors
+            .get(&ids.vendor_id)?
+            .devices
+            .get(&ids.device_id)?
+            .subsystems
+            .get(&(ids.sub_system_vendor_id, ids.sub_system_id))
+            .map(String::as_str)
+    }
+
+    /// The name of the exact board if the database knows the subsystem, otherwise the name of
+    /// the chip, like `Navi 31 [Radeon RX 7900 XT/7900 XTX/7900M]`.
+    pub fn marketing_name(&self, ids: &PciIds) -> Option<&str> {
+        self.subsystem_name(ids)
+            .or_else(|| self.device_name(ids.vendor_id, ids.device_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PciIds, PciIdsDatabase};
+
+    const NAVI31: PciIds = PciIds {
+        vendor_id: 0x1002,
+        device_id: 0x744c,
+        sub_system_vendor_id: 0x1002,
+        sub_system_id: 0x0e3b,
+        revision_id: 0xc8,
+    };
+
+    #[test]
+    fn parses_both_sources() {
+        let pnp = "PCI\\VEN_1002&DEV_744C&SUBSYS_0E3B1002&REV_C8\\6&1A2B3C4D&0&00000019";
+        assert_eq!(PciIds::from_pnp_string(pnp), Ok(NAVI31));
+        assert_eq!(
+            PciIds::from_hex_strings("1002", "744C", "1002", "0E3B", "C8"),
+            Ok(NAVI31)
+        );
+        assert_eq!(
+            PciIds::from_hex_strings("0x1002", "744c", "1002", "0E3B1002", "c8"),
+            Ok(NAVI31)
+        );
+        // The prefix does not count towards the 8 digits of the `SUBSYS` form
+        assert_eq!(
+            PciIds::from_hex_strings("0x1002", "0x744C", "0x1002", "0x0E3B", "0xC8"),
+            Ok(NAVI31)
+        );
+        assert_eq!(
+            PciIds::from_hex_strings("1002", "744C", "1002", "0x0E3B1002", "C8"),
+            Ok(NAVI31)
+        );
+        assert_eq!(NAVI31.to_string().parse(), Ok(NAVI31));
+
+        assert!(PciIds::from_hex_strings("1002", "744C", "1002", "0E3B1EAE", "C8").is_err());
+        assert!(PciIds::from_hex_strings("10020", "744C", "1002", "0E3B", "C8").is_err());
+        assert!(PciIds::from_pnp_string("PCI\\VEN_1002&DEV_744C").is_err());
+        assert!(PciIds::from_pnp_string("USB\\VID_1002&PID_744C").is_err());
+    }
+
+    #[test]
+    fn looks_up_names() {
+        let db = PciIdsDatabase::parse(
+            "# pci.ids\n\
+             1002  Advanced Micro Devices, Inc. [AMD/ATI]\n\
+             \t744c  Navi 31 [Radeon RX 7900 XT/7900 XTX/7900M]\n\
+             \t\t1002 0e3b  Radeon RX 7900 XTX\n\
+             \t\t1eae 7905  Speedster MERC 310 RX 7900 XTX\n\
+             \t7480  Navi 33 [Radeon RX 7700S/7600/7600S/7600M XT/PRO W7600]\n\
+             10de  NVIDIA Corporation\n\
+             C 03  Display controller\n\
+             \t00  VGA compatible controller\n",
+        );
+
+        assert_eq!(
+            db.vendor_name(0x1002),
+            Some("Advanced Micro Devices, Inc. [AMD/ATI]")
+        );
+        assert_eq!(db.marketing_name(&NAVI31), Some("Radeon RX 7900 XTX"));
+        let other_board = PciIds {
+            sub_system_vendor_id: 0x1da2,
+            ..NAVI31
+        };
+        assert_eq!(
+            db.marketing_name(&other_board),
+            Some("Navi 31 [Radeon RX 7900 XT/7900 XTX/7900M]")
+        );
+        assert_eq!(db.device_name(0x10de, 0x2684), None);
+        assert_eq!(db.vendor_name(0x03), None);
+    }
+
+    #[cfg(feature = "replay")]
+    #[test]
+    fn cross_checks_gpu_ids() {
+        use crate::{replay::tests::replay_gpu, PciIdsError};
+
+        let (_helper, gpu) = replay_gpu(|_| {});
+        assert_eq!(gpu.pci_ids(), Ok(NAVI31));
+
+        let (_helper, gpu) = replay_gpu(|gpu| gpu.revision_id = Ok("C1".into()));
+        assert!(matches!(
+            gpu.pci_ids(),
+            Err(PciIdsError::Mismatch { getters, pnp_string })
+                if getters.revision_id == 0xc1 && pnp_string == NAVI31
+        ));
+    }
+}
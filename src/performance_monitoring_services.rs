@@ -2,6 +2,7 @@ use std::mem::MaybeUninit;
 
 use super::{
     ffi,
+    fps::{Fps, FpsList},
     gpu::Gpu,
     gpu_metrics::{GpuMetrics, GpuMetricsList, GpuMetricsSupport},
     interface::{Interface, InterfaceImpl},
@@ -175,19 +176,20 @@ impl PerformanceMonitoringServices {
     //         .map(|metrics| unsafe { GpuMetrics::from_raw(metrics) })
     // }
 
-    // #[doc(alias = "GetFPSHistory")]
-    // pub fn GetFPSHistory(&self, start_in_ms: i32, stop_in_ms: i32, IADLXFPSList** ppMetricsList) -> Result<!> {
-    //     let mut metrics = MaybeUninit::uninit();
-    //     let result = unsafe {
-    //         (self.vtable().GetCurrentGPUMetrics.unwrap())(
-    //             self.as_raw(),
-    //             gpu.as_raw(),
-    //             metrics.as_mut_ptr(),
-    //         )
-    //     };
-    //     Error::from_result_with_assume_init_on_success(result, metrics)
-    //         .map(|metrics| unsafe { GpuMetrics::from_raw(metrics) })
-    // }
+    #[doc(alias = "GetFPSHistory")]
+    pub fn fps_history(&self, start_in_ms: i32, stop_in_ms: i32) -> Result<FpsList> {
+        let mut fps_list = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetFPSHistory.unwrap())(
+                self.as_raw(),
+                start_in_ms,
+                stop_in_ms,
+                fps_list.as_mut_ptr(),
+            )
+        };
+        Error::from_result_with_assume_init_on_success(result, fps_list)
+            .map(|fps_list| unsafe { FpsList::from_raw(fps_list) })
+    }
 
     // #[doc(alias = "GetCurrentAllMetrics")]
     // pub fn GetCurrentAllMetrics(&self, IADLXAllMetrics** ppMetrics) -> Result<!> {
@@ -217,19 +219,14 @@ impl PerformanceMonitoringServices {
     //         .map(|metrics| unsafe { GpuMetrics::from_raw(metrics) })
     // }
 
-    // #[doc(alias = "GetCurrentFPS")]
-    // pub fn GetCurrentFPS(&self, IADLXFPS** ppMetrics) -> Result<!> {
-    //     let mut metrics = MaybeUninit::uninit();
-    //     let result = unsafe {
-    //         (self.vtable().GetCurrentGPUMetrics.unwrap())(
-    //             self.as_raw(),
-    //             gpu.as_raw(),
-    //             metrics.as_mut_ptr(),
-    //         )
-    //     };
-    //     Error::from_result_with_assume_init_on_success(result, metrics)
-    //         .map(|metrics| unsafe { GpuMetrics::from_raw(metrics) })
-    // }
+    #[doc(alias = "GetCurrentFPS")]
+    pub fn current_fps(&self) -> Result<Fps> {
+        let mut fps = MaybeUninit::uninit();
+        let result =
+            unsafe { (self.vtable().GetCurrentFPS.unwrap())(self.as_raw(), fps.as_mut_ptr()) };
+        Error::from_result_with_assume_init_on_success(result, fps)
+            .map(|fps| unsafe { Fps::from_raw(fps) })
+    }
 
     // #[doc(alias = "GetSupportedGPUMetrics")]
     pub fn supported_gpu_metrics(&self, gpu: &Gpu) -> Result<GpuMetricsSupport> {
@@ -0,0 +1,353 @@
+//! Powering off the discrete GPU of a hybrid graphics system with [`Gpu2::start_power_off()`],
+//! which completes asynchronously.
+
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    time::Duration,
+};
+
+use super::{
+    ffi,
+    gpu::Gpu2,
+    interface::Interface,
+    result::{Error, Result},
+};
+
+static LISTENER_VTBL: ffi::IADLXGPUConnectChangedListenerVtbl =
+    ffi::IADLXGPUConnectChangedListenerVtbl {
+        OnGPUConnectChanged: Some(on_gpu_connect_changed),
+        OnGPUConnectChangedError: Some(on_gpu_connect_changed_error),
+    };
+
+type Callback = Box<dyn FnOnce(Result<()>) + Send>;
+
+struct Shared {
+    outcome: Mutex<Option<Result<()>>>,
+    completed: Condvar,
+    callback: Mutex<Option<Callback>>,
+}
+
+impl Shared {
+    fn new(callback: Callback) -> Self {
+        Self {
+            outcome: Mutex::new(None),
+            completed: Condvar::new(),
+            callback: Mutex::new(Some(callback)),
+        }
+    }
+
+    /// Records the first outcome and hands it to the callback, ignoring any later ones.
+    fn complete(&self, outcome: Result<()>) {
+        {
+            let mut current = self.outcome.lock().unwrap_or_else(|e| e.into_inner());
+            if current.is_some() {
+                return;
+            }
+            *current = Some(outcome);
+            self.completed.notify_all();
+        }
+        let callback = self
+            .callback
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        if let Some(callback) = callback {
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| callback(outcome)));
+        }
+    }
+}
+
+/// The `IADLXGPUConnectChangedListener` object handed to ADLX, which starts with its vtable.
+#[repr(C)]
+struct ListenerImpl {
+    vtbl: &'static ffi::IADLXGPUConnectChangedListenerVtbl,
+    shared: Shared,
+    /// Set once a callback has reported the outcome and no longer accesses the listener
+    reported: AtomicBool,
+}
+
+impl ListenerImpl {
+    /// Completes the power-off of the listener at `this` with `outcome`, from within one of its
+    /// callbacks.
+    unsafe fn report(this: *mut ffi::IADLXGPUConnectChangedListener, outcome: Result<()>) {
+        let listener = &*this.cast::<ListenerImpl>();
+        listener.shared.complete(outcome);
+        // Last access, the listener may be freed from here on
+        listener.reported.store(true, Ordering::Release);
+    }
+}
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_connect_changed_listener__on_g_p_u_connect_changed/>
+unsafe extern "C" fn on_gpu_connect_changed(
+    this: *mut ffi::IADLXGPUConnectChangedListener,
+    _gpu: *mut ffi::IADLXGPU2,
+    is_connect: ffi::adlx_bool,
+) -> ffi::adlx_bool {
+    ListenerImpl::report(
+        this,
+        if is_connect == 0 {
+            Ok(())
+        } else {
+            // The GPU came back before it was powered off, for example after an abort
+            Err(Error::new(ffi::ADLX_RESULT_ADLX_GPU_IN_USE))
+        },
+    );
+    true as _
+}
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u_connect_changed_listener__on_g_p_u_connect_changed_error/>
+unsafe extern "C" fn on_gpu_connect_changed_error(
+    this: *mut ffi::IADLXGPUConnectChangedListener,
+    _gpu: *mut ffi::IADLXGPU2,
+    error: ffi::ADLX_RESULT,
+) -> ffi::adlx_bool {
+    ListenerImpl::report(
+        this,
+        match Error::from_result(error) {
+            // Report errors, even if ADLX does not pass a failure code
+            Ok(()) => Err(Error::new(ffi::ADLX_RESULT_ADLX_FAIL)),
+            Err(e) => Err(e),
+        },
+    );
+    true as _
+}
+
+/// Owns the [`ListenerImpl`] handed to ADLX, shared by the clones of a [`PowerOff`].
+///
+/// The listener is never freed from within its callbacks. It is freed with the last handle once a
+/// callback has reported the outcome, and leaked if that handle is dropped while ADLX may still
+/// call it.
+struct Listener {
+    raw: *mut ListenerImpl,
+    /// Whether ADLX kept the listener, which it does not when `StartPowerOff` fails
+    registered: bool,
+}
+
+// SAFETY: The listener is only accessed through shared references, and `ListenerImpl` is `Send`
+// and `Sync`
+unsafe impl Send for Listener {}
+unsafe impl Sync for Listener {}
+
+impl Listener {
+    fn new(callback: Callback) -> Self {
+        let listener = Box::new(ListenerImpl {
+            vtbl: &LISTENER_VTBL,
+            shared: Shared::new(callback),
+            reported: AtomicBool::new(false),
+        });
+        Self {
+            raw: Box::into_raw(listener),
+            registered: false,
+        }
+    }
+
+    fn shared(&self) -> &Shared {
+        unsafe { &(*self.raw).shared }
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        let reported = unsafe { (*self.raw).reported.load(Ordering::Acquire) };
+        if !self.registered || reported {
+            drop(unsafe { Box::from_raw(self.raw) });
+        }
+    }
+}
+
+/// A power-off started with [`Gpu2::start_power_off()`], to wait for its outcome.
+///
+/// The outcome is [`Ok`] once the GPU is powered off. It is an [`Error`] if ADLX reports a
+/// failure like [`ffi::ADLX_RESULT_ADLX_TIMEOUT_OPERATION`], or
+/// [`ffi::ADLX_RESULT_ADLX_GPU_IN_USE`] if the GPU reconnects first.
+///
+/// Dropping this handle does not abort the power-off. The listener that ADLX reports the outcome
+/// to is freed with the last clone of this handle, or leaked if that is dropped before the outcome
+/// is reported.
+#[derive(Clone)]
+pub struct PowerOff {
+    gpu: Gpu2,
+    listener: Arc<Listener>,
+}
+
+impl fmt::Debug for PowerOff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PowerOff")
+            .field("gpu", &self.gpu)
+            .field("outcome", &self.try_outcome())
+            .finish()
+    }
+}
+
+impl PowerOff {
+    /// The outcome, or [`None`] if the power-off is still in progress.
+    pub fn try_outcome(&self) -> Option<Result<()>> {
+        *self
+            .listener
+            .shared()
+            .outcome
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Blocks until ADLX reports the outcome.
+    pub fn wait(&self) -> Result<()> {
+        let outcome = self
+            .listener
+            .shared()
+            .outcome
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let outcome = self
+            .listener
+            .shared()
+            .completed
+            .wait_while(outcome, |outcome| outcome.is_none())
+            .unwrap_or_else(|e| e.into_inner());
+        outcome.unwrap()
+    }
+
+    /// Blocks until ADLX reports the outcome or `timeout` elapses, returning [`None`] in the
+    /// latter case.
+    pub fn wait_timeout(&self, timeout: Duration) -> Option<Result<()>> {
+        let outcome = self
+            .listener
+            .shared()
+            .outcome
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let (outcome, _) = self
+            .listener
+            .shared()
+            .completed
+            .wait_timeout_while(outcome, timeout, |outcome| outcome.is_none())
+            .unwrap_or_else(|e| e.into_inner());
+        *outcome
+    }
+
+    /// Shorthand for [`Gpu2::abort_power_off()`] on the GPU being powered off.
+    pub fn abort(&self) -> Result<()> {
+        self.gpu.abort_power_off()
+    }
+}
+
+impl Gpu2 {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_g_p_u2__start_power_off/>
+    ///
+    /// Starts powering off this GPU, giving up after `timeout` with millisecond precision. Use
+    /// [`PowerOff::wait()`] to block until it completes, or [`Self::start_power_off_with()`] to be
+    /// called back instead.
+    #[doc(alias = "StartPowerOff")]
+    pub fn start_power_off(&self, timeout: Duration) -> Result<PowerOff> {
+        self.start_power_off_with(timeout, |_| {})
+    }
+
+    /// Like [`Self::start_power_off()`], but also calls `callback` with the outcome. `callback`
+    /// is called on an ADLX thread, and panics inside of it are discarded.
+    #[doc(alias = "StartPowerOff")]
+    pub fn start_power_off_with(
+        &self,
+        timeout: Duration,
+        callback: impl FnOnce(Result<()>) + Send + 'static,
+    ) -> Result<PowerOff> {
+        let mut listener = Listener::new(Box::new(callback));
+        let timeout = timeout.as_millis().try_into().unwrap_or(i32::MAX);
+        let result = unsafe {
+            (self.vtable().StartPowerOff.unwrap())(self.as_raw(), listener.raw.cast(), timeout)
+        };
+        Error::from_result(result)?;
+
+        listener.registered = true;
+        Ok(PowerOff {
+            gpu: self.clone(),
+            listener: Arc::new(listener),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "replay"))]
+mod tests {
+    use std::{
+        sync::{atomic::Ordering, Arc, Mutex},
+        time::Duration,
+    };
+
+    use super::{
+        on_gpu_connect_changed, on_gpu_connect_changed_error, Callback, Listener, PowerOff,
+    };
+    use crate::{ffi, replay::tests::replay_gpu2, Error, Gpu2, Interface};
+
+    #[test]
+    fn replay_does_not_power_off() {
+        let (_helper, gpu) = replay_gpu2(|_| {});
+        assert_eq!(
+            gpu.start_power_off(Duration::from_secs(1))
+                .unwrap_err()
+                .adlx_result(),
+            ffi::ADLX_RESULT_ADLX_NOT_SUPPORTED
+        );
+    }
+
+    /// A power-off whose listener ADLX kept, as if `StartPowerOff` succeeded
+    fn power_off(gpu: &Gpu2, callback: Callback) -> PowerOff {
+        let mut listener = Listener::new(callback);
+        listener.registered = true;
+        PowerOff {
+            gpu: gpu.clone(),
+            listener: Arc::new(listener),
+        }
+    }
+
+    fn reported(power_off: &PowerOff) -> bool {
+        unsafe { (*power_off.listener.raw).reported.load(Ordering::Acquire) }
+    }
+
+    #[test]
+    fn reports_first_outcome() {
+        let (_helper, gpu) = replay_gpu2(|_| {});
+        let outcomes = Arc::new(Mutex::new(vec![]));
+        let power_off = power_off(
+            &gpu,
+            Box::new({
+                let outcomes = outcomes.clone();
+                move |outcome| outcomes.lock().unwrap().push(outcome)
+            }),
+        );
+        let raw = power_off.listener.raw.cast();
+
+        assert_eq!(power_off.wait_timeout(Duration::from_millis(1)), None);
+        assert!(!reported(&power_off));
+        unsafe {
+            on_gpu_connect_changed_error(
+                raw,
+                gpu.as_raw(),
+                ffi::ADLX_RESULT_ADLX_TIMEOUT_OPERATION,
+            );
+        }
+        assert!(reported(&power_off));
+        // The listener outlives its callbacks, so ADLX may still call it
+        unsafe { on_gpu_connect_changed(raw, gpu.as_raw(), false as _) };
+
+        let timeout = Err(Error::new(ffi::ADLX_RESULT_ADLX_TIMEOUT_OPERATION));
+        assert_eq!(power_off.wait(), timeout);
+        assert_eq!(power_off.clone().try_outcome(), Some(timeout));
+        assert_eq!(*outcomes.lock().unwrap(), [timeout]);
+    }
+
+    #[test]
+    fn reports_reconnects() {
+        let (_helper, gpu) = replay_gpu2(|_| {});
+        let power_off = power_off(&gpu, Box::new(|_| {}));
+
+        unsafe { on_gpu_connect_changed(power_off.listener.raw.cast(), gpu.as_raw(), true as _) };
+        assert_eq!(
+            power_off.wait(),
+            Err(Error::new(ffi::ADLX_RESULT_ADLX_GPU_IN_USE))
+        );
+    }
+}
@@ -0,0 +1,2049 @@
+//! An in-process implementation of the ADLX ABI that serves the values of a recorded [`Session`],
+//! so that the regular wrappers in this crate work without AMD hardware or drivers.
+//!
+//! Every object handed out is a heap-allocated, reference-counted [`Object`] whose first member is
+//! a pointer to a vtable filled with the `extern "C"` functions below, exactly like the objects
+//! returned by the ADLX runtime.  Interface pointers passed back in (like the `pGPU` argument of
+//! `GetChill()`) must have been created by the same replay.
+
+use std::{
+    ffi::{c_char, c_void},
+    sync::{
+        atomic::{self, AtomicUsize, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+};
+
+use super::{
+    ffi,
+    session::{
+        ApplicationRecord, ChillRecord, DisplayRecord, FpsRecord, FrameRateTargetControlRecord,
+        GpuMetricsRecord, GpuMetricsSupportRecord, GpuRecord, IntRange,
+        PerformanceMonitoringRecord, Recorded, Session, Text, WaitForVerticalRefreshRecord,
+    },
+};
+
+const OK: ffi::ADLX_RESULT = ffi::ADLX_RESULT_ADLX_OK;
+const INVALID_ARGS: ffi::ADLX_RESULT = ffi::ADLX_RESULT_ADLX_INVALID_ARGS;
+
+/// Values that change when the replayed session is driven through setters.
+struct State {
+    wait_for_vertical_refresh_modes: Vec<Recorded<ffi::ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE>>,
+    sampling_interval: Recorded<i32>,
+    max_performance_metrics_history_size: Recorded<i32>,
+    /// Index of the next [`GpuPerformanceRecord::current_gpu_metrics`] sample to serve, per GPU
+    ///
+    /// [`GpuPerformanceRecord::current_gpu_metrics`]: super::session::GpuPerformanceRecord::current_gpu_metrics
+    metrics_cursors: Vec<usize>,
+    /// Index of the next [`PerformanceMonitoringRecord::current_fps`] sample to serve
+    fps_cursor: usize,
+}
+
+struct ReplayData {
+    session: Session,
+    state: Mutex<State>,
+}
+
+impl ReplayData {
+    fn gpus(&self) -> &[GpuRecord] {
+        self.session.gpus.as_deref().unwrap_or_default()
+    }
+
+    fn displays(&self) -> &[DisplayRecord] {
+        self.session.displays.as_deref().unwrap_or_default()
+    }
+
+    fn performance_monitoring(&self) -> &PerformanceMonitoringRecord {
+        // Services are only handed out when they were recorded successfully
+        self.session.performance_monitoring.as_ref().unwrap()
+    }
+}
+
+/// Owner of the replayed [`ffi::IADLXSystem`]. Objects obtained through it keep the recorded data
+/// alive on their own and may outlive this owner.
+pub(crate) struct Replay {
+    system: *mut ffi::IADLXSystem,
+}
+
+unsafe impl Send for Replay {}
+unsafe impl Sync for Replay {}
+
+impl Replay {
+    pub(crate) fn new(session: Session) -> Self {
+        let gpu_count = session.gpus.as_ref().map_or(0, Vec::len);
+        let state = State {
+            wait_for_vertical_refresh_modes: session.three_d_settings.as_ref().map_or_else(
+                |_| vec![],
+                |settings| {
+                    settings
+                        .iter()
+                        .map(|s| {
+                            s.wait_for_vertical_refresh
+                                .as_ref()
+                                .map_err(|e| *e)
+                                .and_then(|r| r.mode)
+                        })
+                        .collect()
+                },
+            ),
+            sampling_interval: session
+                .performance_monitoring
+                .as_ref()
+                .map_err(|e| *e)
+                .and_then(|r| r.sampling_interval),
+            max_performance_metrics_history_size: session
+                .performance_monitoring
+                .as_ref()
+                .map_err(|e| *e)
+                .and_then(|r| r.max_performance_metrics_history_size),
+            metrics_cursors: vec![0; gpu_count],
+            fps_cursor: 0,
+        };
+        let data = Arc::new(ReplayData {
+            session,
+            state: Mutex::new(state),
+        });
+
+        Self {
+            system: Object::create(SystemKind { data }),
+        }
+    }
+
+    pub(crate) fn system(&self) -> *mut ffi::IADLXSystem {
+        self.system
+    }
+}
+
+impl Drop for Replay {
+    fn drop(&mut self) {
+        // IADLXSystem has no refcount and is owned by us
+        drop(unsafe { Box::from_raw(self.system.cast::<Object<SystemKind>>()) });
+    }
+}
+
+/// The type-specific payload of an [`Object`], together with its vtable.
+trait Kind: Sized + 'static {
+    type Vtbl: Sync + Send + 'static;
+
+    fn vtbl() -> &'static Self::Vtbl;
+
+    /// Whether this object implements the interface called `iid`, besides `IADLXInterface`.
+    fn implements(&self, iid: &str) -> bool;
+}
+
+#[repr(C)]
+struct Object<K: Kind> {
+    vtbl: &'static K::Vtbl,
+    refs: AtomicUsize,
+    data: K,
+}
+
+impl<K: Kind> Object<K> {
+    /// Allocates a new object with a refcount of 1, as if it was just returned from ADLX.
+    fn create<I>(data: K) -> *mut I {
+        Box::into_raw(Box::new(Self {
+            vtbl: K::vtbl(),
+            refs: AtomicUsize::new(1),
+            data,
+        }))
+        .cast()
+    }
+
+    /// # Safety
+    /// `this` must have been returned by [`Self::create()`] and still be alive.
+    unsafe fn get<'a, I>(this: *mut I) -> &'a K {
+        &(*this.cast::<Self>()).data
+    }
+}
+
+unsafe extern "C" fn acquire<I, K: Kind>(this: *mut I) -> ffi::adlx_long {
+    let object = &*this.cast::<Object<K>>();
+    (object.refs.fetch_add(1, Ordering::Relaxed) + 1) as _
+}
+
+unsafe extern "C" fn release<I, K: Kind>(this: *mut I) -> ffi::adlx_long {
+    let object = this.cast::<Object<K>>();
+    let refs = (*object).refs.fetch_sub(1, Ordering::Release) - 1;
+    if refs == 0 {
+        atomic::fence(Ordering::Acquire);
+        drop(Box::from_raw(object));
+    }
+    refs as _
+}
+
+unsafe extern "C" fn query_interface<I, K: Kind>(
+    this: *mut I,
+    interface_id: *const ffi::wchar_t,
+    interface: *mut *mut c_void,
+) -> ffi::ADLX_RESULT {
+    if interface_id.is_null() || interface.is_null() {
+        return INVALID_ARGS;
+    }
+    let mut len = 0;
+    while *interface_id.add(len) != 0 {
+        len += 1;
+    }
+    let interface_id = String::from_utf16_lossy(std::slice::from_raw_parts(interface_id, len));
+
+    if interface_id == "IADLXInterface" || Object::<K>::get(this).implements(&interface_id) {
+        acquire::<I, K>(this);
+        *interface = this.cast();
+        OK
+    } else {
+        ffi::ADLX_RESULT_ADLX_UNKNOWN_INTERFACE
+    }
+}
+
+/// Hands out `object` through the `out` pointer of an ADLX getter.
+unsafe fn emit<I>(object: Recorded<*mut I>, out: *mut *mut I) -> ffi::ADLX_RESULT {
+    if out.is_null() {
+        return INVALID_ARGS;
+    }
+    match object {
+        Ok(object) => {
+            out.write(object);
+            OK
+        }
+        Err(e) => e,
+    }
+}
+
+/// A recorded value that can be written to the `out` pointer of an ADLX getter.
+trait Value {
+    type Out;
+
+    unsafe fn write(&self, out: *mut Self::Out);
+}
+
+macro_rules! copy_value {
+    ($($ty:ty),*) => {$(
+        impl Value for $ty {
+            type Out = $ty;
+
+            unsafe fn write(&self, out: *mut Self::Out) {
+                out.write(*self)
+            }
+        }
+    )*};
+}
+
+copy_value!(i32, u32, i64, f64);
+
+impl Value for bool {
+    type Out = ffi::adlx_bool;
+
+    unsafe fn write(&self, out: *mut Self::Out) {
+        out.write(*self as _)
+    }
+}
+
+impl Value for Text {
+    type Out = *const c_char;
+
+    /// The string stays valid for as long as the [`ReplayData`] it was borrowed from.
+    unsafe fn write(&self, out: *mut Self::Out) {
+        out.write(self.as_c_str().as_ptr())
+    }
+}
+
+impl Value for IntRange {
+    type Out = ffi::ADLX_IntRange;
+
+    unsafe fn write(&self, out: *mut Self::Out) {
+        out.write((*self).into())
+    }
+}
+
+unsafe fn write<T: Value>(
+    value: Result<&T, &ffi::ADLX_RESULT>,
+    out: *mut T::Out,
+) -> ffi::ADLX_RESULT {
+    if out.is_null() {
+        return INVALID_ARGS;
+    }
+    match value {
+        Ok(value) => {
+            value.write(out);
+            OK
+        }
+        Err(e) => *e,
+    }
+}
+
+/// Defines an `extern "C"` getter that writes a single recorded value of type `$value`.
+macro_rules! getter {
+    ($name:ident($this:ty, $kind:ty) -> $value:ty, |$object:ident| $expr:expr) => {
+        unsafe extern "C" fn $name(
+            this: *mut $this,
+            out: *mut <$value as Value>::Out,
+        ) -> ffi::ADLX_RESULT {
+            let $object = Object::<$kind>::get(this);
+            write::<$value>($expr, out)
+        }
+    };
+}
+
+/// Builds a vtable of type `$vtbl` for `$kind`, leaving unlisted entries `None`.
+macro_rules! vtbl {
+    ($kind:ty, $vtbl:ident, $this:ident { $($field:ident: $fn:expr,)* }) => {
+        // Some vtables are filled completely
+        #[allow(clippy::needless_update)]
+        fn vtbl() -> &'static ffi::$vtbl {
+            static VTBL: OnceLock<ffi::$vtbl> = OnceLock::new();
+            VTBL.get_or_init(|| ffi::$vtbl {
+                Acquire: Some(acquire::<ffi::$this, $kind>),
+                Release: Some(release::<ffi::$this, $kind>),
+                QueryInterface: Some(query_interface::<ffi::$this, $kind>),
+                $($field: Some($fn),)*
+                // SAFETY: All members are `Option`s of function pointers
+                ..unsafe { std::mem::zeroed() }
+            })
+        }
+    };
+}
+
+/// An ADLX list, which stores `Item`s and creates objects for them on demand.
+trait ListKind: Kind {
+    type Item: Clone;
+
+    fn items(&self) -> &Mutex<Vec<Self::Item>>;
+
+    fn create_item(&self, item: &Self::Item) -> *mut c_void;
+
+    /// # Safety
+    /// `raw` must be a non-null object of the list's item type created by this replay.
+    unsafe fn item_from_raw(&self, raw: *mut c_void) -> Self::Item;
+}
+
+unsafe extern "C" fn list_size<I, K: ListKind>(this: *mut I) -> ffi::adlx_uint {
+    Object::<K>::get(this).items().lock().unwrap().len() as _
+}
+
+unsafe extern "C" fn list_empty<I, K: ListKind>(this: *mut I) -> ffi::adlx_bool {
+    Object::<K>::get(this).items().lock().unwrap().is_empty() as _
+}
+
+unsafe extern "C" fn list_begin<I, K: ListKind>(_this: *mut I) -> ffi::adlx_uint {
+    0
+}
+
+unsafe extern "C" fn list_clear<I, K: ListKind>(this: *mut I) -> ffi::ADLX_RESULT {
+    Object::<K>::get(this).items().lock().unwrap().clear();
+    OK
+}
+
+unsafe extern "C" fn list_remove_back<I, K: ListKind>(this: *mut I) -> ffi::ADLX_RESULT {
+    match Object::<K>::get(this).items().lock().unwrap().pop() {
+        Some(_) => OK,
+        None => ffi::ADLX_RESULT_ADLX_NOT_FOUND,
+    }
+}
+
+unsafe extern "C" fn list_at<I, O, K: ListKind>(
+    this: *mut I,
+    location: ffi::adlx_uint,
+    item: *mut *mut O,
+) -> ffi::ADLX_RESULT {
+    let list = Object::<K>::get(this);
+    let object = list
+        .items()
+        .lock()
+        .unwrap()
+        .get(location as usize)
+        .map(|item| list.create_item(item).cast())
+        .ok_or(INVALID_ARGS);
+    emit(object, item)
+}
+
+unsafe extern "C" fn list_add_back<I, O, K: ListKind>(
+    this: *mut I,
+    item: *mut O,
+) -> ffi::ADLX_RESULT {
+    if item.is_null() {
+        return INVALID_ARGS;
+    }
+    let list = Object::<K>::get(this);
+    let item = list.item_from_raw(item.cast());
+    list.items().lock().unwrap().push(item);
+    OK
+}
+
+/// Reads the GPU index out of a `pGPU` argument.
+unsafe fn gpu_index(gpu: *mut ffi::IADLXGPU) -> Recorded<usize> {
+    if gpu.is_null() {
+        return Err(INVALID_ARGS);
+    }
+    Ok(Object::<GpuKind>::get(gpu).index)
+}
+
+struct SystemKind {
+    data: Arc<ReplayData>,
+}
+
+impl Kind for SystemKind {
+    type Vtbl = ffi::IADLXSystemVtbl;
+
+    fn vtbl() -> &'static ffi::IADLXSystemVtbl {
+        static VTBL: OnceLock<ffi::IADLXSystemVtbl> = OnceLock::new();
+        VTBL.get_or_init(|| ffi::IADLXSystemVtbl {
+            GetHybridGraphicsType: Some(system_hybrid_graphics_type),
+            GetGPUs: Some(system_gpus),
+            QueryInterface: Some(query_interface::<ffi::IADLXSystem, Self>),
+            GetDisplaysServices: Some(system_displays_services),
+            Get3DSettingsServices: Some(system_3d_settings_services),
+            GetPerformanceMonitoringServices: Some(system_performance_monitoring_services),
+            // SAFETY: All members are `Option`s of function pointers
+            ..unsafe { std::mem::zeroed() }
+        })
+    }
+
+    fn implements(&self, _iid: &str) -> bool {
+        // Later IADLXSystem versions are not recorded
+        false
+    }
+}
+
+getter!(system_hybrid_graphics_type(ffi::IADLXSystem, SystemKind) -> i32, |system| {
+    system.data.session.hybrid_graphics_type.as_ref()
+});
+
+unsafe extern "C" fn system_gpus(
+    this: *mut ffi::IADLXSystem,
+    gpus: *mut *mut ffi::IADLXGPUList,
+) -> ffi::ADLX_RESULT {
+    let data = &Object::<SystemKind>::get(this).data;
+    let list = data.session.gpus.as_ref().map_err(|e| *e).map(|gpus| {
+        Object::create(GpuListKind {
+            data: data.clone(),
+            items: Mutex::new((0..gpus.len()).collect()),
+        })
+    });
+    emit(list, gpus)
+}
+
+unsafe extern "C" fn system_displays_services(
+    this: *mut ffi::IADLXSystem,
+    services: *mut *mut ffi::IADLXDisplayServices,
+) -> ffi::ADLX_RESULT {
+    let data = Object::<SystemKind>::get(this).data.clone();
+    emit(Ok(Object::create(DisplayServicesKind { data })), services)
+}
+
+unsafe extern "C" fn system_3d_settings_services(
+    this: *mut ffi::IADLXSystem,
+    services: *mut *mut ffi::IADLX3DSettingsServices,
+) -> ffi::ADLX_RESULT {
+    let data = &Object::<SystemKind>::get(this).data;
+    let services_object = data
+        .session
+        .three_d_settings
+        .as_ref()
+        .map_err(|e| *e)
+        .map(|_| Object::create(ThreeDSettingsServicesKind { data: data.clone() }));
+    emit(services_object, services)
+}
+
+unsafe extern "C" fn system_performance_monitoring_services(
+    this: *mut ffi::IADLXSystem,
+    services: *mut *mut ffi::IADLXPerformanceMonitoringServices,
+) -> ffi::ADLX_RESULT {
+    let data = &Object::<SystemKind>::get(this).data;
+    let services_object = data
+        .session
+        .performance_monitoring
+        .as_ref()
+        .map_err(|e| *e)
+        .map(|_| Object::create(PerformanceMonitoringServicesKind { data: data.clone() }));
+    emit(services_object, services)
+}
+
+struct GpuListKind {
+    data: Arc<ReplayData>,
+    items: Mutex<Vec<usize>>,
+}
+
+impl Kind for GpuListKind {
+    type Vtbl = ffi::IADLXGPUListVtbl;
+
+    vtbl!(
+        Self,
+        IADLXGPUListVtbl,
+        IADLXGPUList {
+            Size: list_size::<ffi::IADLXGPUList, Self>,
+            Empty: list_empty::<ffi::IADLXGPUList, Self>,
+            Begin: list_begin::<ffi::IADLXGPUList, Self>,
+            End: list_size::<ffi::IADLXGPUList, Self>,
+            At: list_at::<ffi::IADLXGPUList, ffi::IADLXInterface, Self>,
+            Clear: list_clear::<ffi::IADLXGPUList, Self>,
+            Remove_Back: list_remove_back::<ffi::IADLXGPUList, Self>,
+            Add_Back: list_add_back::<ffi::IADLXGPUList, ffi::IADLXInterface, Self>,
+            At_GPUList: list_at::<ffi::IADLXGPUList, ffi::IADLXGPU, Self>,
+            Add_Back_GPUList: list_add_back::<ffi::IADLXGPUList, ffi::IADLXGPU, Self>,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        matches!(iid, "IADLXList" | "IADLXGPUList")
+    }
+}
+
+impl ListKind for GpuListKind {
+    type Item = usize;
+
+    fn items(&self) -> &Mutex<Vec<usize>> {
+        &self.items
+    }
+
+    fn create_item(&self, &index: &usize) -> *mut c_void {
+        Object::create(GpuKind {
+            data: self.data.clone(),
+            index,
+        })
+    }
+
+    unsafe fn item_from_raw(&self, raw: *mut c_void) -> usize {
+        Object::<GpuKind>::get(raw).index
+    }
+}
+
+struct GpuKind {
+    data: Arc<ReplayData>,
+    /// Index into [`Session::gpus`]
+    index: usize,
+}
+
+impl GpuKind {
+    fn record(&self) -> &GpuRecord {
+        &self.data.gpus()[self.index]
+    }
+}
+
+impl Kind for GpuKind {
+    type Vtbl = ffi::IADLXGPU2Vtbl;
+
+    vtbl!(
+        Self,
+        IADLXGPU2Vtbl,
+        IADLXGPU2 {
+            VendorId: gpu_vendor_id,
+            ASICFamilyType: gpu_asic_family_type,
+            Type: gpu_type,
+            IsExternal: gpu_is_external,
+            Name: gpu_name,
+            DriverPath: gpu_driver_path,
+            PNPString: gpu_pnp_string,
+            HasDesktops: gpu_has_desktops,
+            TotalVRAM: gpu_total_vram,
+            VRAMType: gpu_vram_type,
+            BIOSInfo: gpu_bios_info,
+            DeviceId: gpu_device_id,
+            RevisionId: gpu_revision_id,
+            SubSystemId: gpu_sub_system_id,
+            SubSystemVendorId: gpu_sub_system_vendor_id,
+            UniqueId: gpu_unique_id,
+            PCIBusType: gpu_pci_bus_type,
+            PCIBusLaneWidth: gpu_pci_bus_lane_width,
+            MultiGPUMode: gpu_multi_gpu_mode,
+            ProductName: gpu_product_name,
+            IsPowerOff: gpu_is_power_off,
+            PowerOn: gpu_not_recorded,
+            StartPowerOff: gpu_start_power_off,
+            AbortPowerOff: gpu_not_recorded,
+            IsSupportedApplicationList: gpu_is_supported_application_list,
+            AMDSoftwareReleaseDate: gpu_amd_software_release_date,
+            AMDSoftwareEdition: gpu_amd_software_edition,
+            AMDSoftwareVersion: gpu_amd_software_version,
+            DriverVersion: gpu_driver_version,
+            AMDWindowsDriverVersion: gpu_amd_windows_driver_version,
+            LUID: gpu_luid,
+            GetApplications: gpu_applications,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        match iid {
+            "IADLXGPU" => true,
+            "IADLXGPU1" => self.record().gpu1.is_ok(),
+            "IADLXGPU2" => self.record().gpu2.is_ok(),
+            _ => false,
+        }
+    }
+}
+
+getter!(gpu_vendor_id(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| gpu.record().vendor_id.as_ref());
+getter!(gpu_asic_family_type(ffi::IADLXGPU2, GpuKind) -> i32, |gpu| {
+    gpu.record().asic_family_type.as_ref()
+});
+getter!(gpu_type(ffi::IADLXGPU2, GpuKind) -> i32, |gpu| gpu.record().type_.as_ref());
+getter!(gpu_is_external(ffi::IADLXGPU2, GpuKind) -> bool, |gpu| gpu.record().is_external.as_ref());
+getter!(gpu_name(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| gpu.record().name.as_ref());
+getter!(gpu_driver_path(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| gpu.record().driver_path.as_ref());
+getter!(gpu_pnp_string(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| gpu.record().pnp_string.as_ref());
+getter!(gpu_has_desktops(ffi::IADLXGPU2, GpuKind) -> bool, |gpu| {
+    gpu.record().has_desktops.as_ref()
+});
+getter!(gpu_total_vram(ffi::IADLXGPU2, GpuKind) -> u32, |gpu| gpu.record().total_vram.as_ref());
+getter!(gpu_vram_type(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| gpu.record().vram_type.as_ref());
+getter!(gpu_device_id(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| gpu.record().device_id.as_ref());
+getter!(gpu_revision_id(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| gpu.record().revision_id.as_ref());
+getter!(gpu_sub_system_id(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| {
+    gpu.record().sub_system_id.as_ref()
+});
+getter!(gpu_sub_system_vendor_id(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| {
+    gpu.record().sub_system_vendor_id.as_ref()
+});
+getter!(gpu_unique_id(ffi::IADLXGPU2, GpuKind) -> i32, |gpu| gpu.record().unique_id.as_ref());
+getter!(gpu_pci_bus_type(ffi::IADLXGPU2, GpuKind) -> i32, |gpu| {
+    gpu.record().gpu1.as_ref().and_then(|gpu1| gpu1.pci_bus_type.as_ref())
+});
+getter!(gpu_pci_bus_lane_width(ffi::IADLXGPU2, GpuKind) -> u32, |gpu| {
+    gpu.record().gpu1.as_ref().and_then(|gpu1| gpu1.pci_bus_lane_width.as_ref())
+});
+getter!(gpu_multi_gpu_mode(ffi::IADLXGPU2, GpuKind) -> i32, |gpu| {
+    gpu.record().gpu1.as_ref().and_then(|gpu1| gpu1.multi_gpu_mode.as_ref())
+});
+getter!(gpu_product_name(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| {
+    gpu.record().gpu1.as_ref().and_then(|gpu1| gpu1.product_name.as_ref())
+});
+getter!(gpu_is_power_off(ffi::IADLXGPU2, GpuKind) -> bool, |gpu| {
+    gpu.record().gpu2.as_ref().and_then(|gpu2| gpu2.is_power_off.as_ref())
+});
+getter!(gpu_is_supported_application_list(ffi::IADLXGPU2, GpuKind) -> bool, |gpu| {
+    gpu.record()
+        .gpu2
+        .as_ref()
+        .and_then(|gpu2| gpu2.is_supported_application_list.as_ref())
+});
+getter!(gpu_amd_software_edition(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| {
+    gpu.record().gpu2.as_ref().and_then(|gpu2| gpu2.amd_software_edition.as_ref())
+});
+getter!(gpu_amd_software_version(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| {
+    gpu.record().gpu2.as_ref().and_then(|gpu2| gpu2.amd_software_version.as_ref())
+});
+getter!(gpu_driver_version(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| {
+    gpu.record().gpu2.as_ref().and_then(|gpu2| gpu2.driver_version.as_ref())
+});
+getter!(gpu_amd_windows_driver_version(ffi::IADLXGPU2, GpuKind) -> Text, |gpu| {
+    gpu.record()
+        .gpu2
+        .as_ref()
+        .and_then(|gpu2| gpu2.amd_windows_driver_version.as_ref())
+});
+
+/// Actions that change the hardware state cannot be replayed.
+unsafe extern "C" fn gpu_not_recorded(_this: *mut ffi::IADLXGPU2) -> ffi::ADLX_RESULT {
+    ffi::ADLX_RESULT_ADLX_NOT_SUPPORTED
+}
+
+unsafe extern "C" fn gpu_start_power_off(
+    _this: *mut ffi::IADLXGPU2,
+    _listener: *mut ffi::IADLXGPUConnectChangedListener,
+    _timeout: ffi::adlx_int,
+) -> ffi::ADLX_RESULT {
+    ffi::ADLX_RESULT_ADLX_NOT_SUPPORTED
+}
+
+unsafe extern "C" fn gpu_bios_info(
+    this: *mut ffi::IADLXGPU2,
+    part_number: *mut *const c_char,
+    version: *mut *const c_char,
+    date: *mut *const c_char,
+) -> ffi::ADLX_RESULT {
+    if part_number.is_null() || version.is_null() || date.is_null() {
+        return INVALID_ARGS;
+    }
+    let gpu = Object::<GpuKind>::get(this);
+    match &gpu.record().bios_info {
+        Ok(info) => {
+            info.part_number.write(part_number);
+            info.version.write(version);
+            info.date.write(date);
+            OK
+        }
+        Err(e) => *e,
+    }
+}
+
+unsafe extern "C" fn gpu_amd_software_release_date(
+    this: *mut ffi::IADLXGPU2,
+    year: *mut ffi::adlx_uint,
+    month: *mut ffi::adlx_uint,
+    day: *mut ffi::adlx_uint,
+) -> ffi::ADLX_RESULT {
+    if year.is_null() || month.is_null() || day.is_null() {
+        return INVALID_ARGS;
+    }
+    let gpu = Object::<GpuKind>::get(this);
+    match gpu
+        .record()
+        .gpu2
+        .as_ref()
+        .and_then(|gpu2| gpu2.amd_software_release_date.as_ref())
+    {
+        Ok(&(y, m, d)) => {
+            year.write(y);
+            month.write(m);
+            day.write(d);
+            OK
+        }
+        Err(e) => *e,
+    }
+}
+
+unsafe extern "C" fn gpu_luid(
+    this: *mut ffi::IADLXGPU2,
+    luid: *mut ffi::ADLX_LUID,
+) -> ffi::ADLX_RESULT {
+    if luid.is_null() {
+        return INVALID_ARGS;
+    }
+    let gpu = Object::<GpuKind>::get(this);
+    match gpu
+        .record()
+        .gpu2
+        .as_ref()
+        .and_then(|gpu2| gpu2.luid.as_ref())
+    {
+        Ok(&(low_part, high_part)) => {
+            luid.write(ffi::ADLX_LUID {
+                lowPart: low_part as _,
+                highPart: high_part as _,
+            });
+            OK
+        }
+        Err(e) => *e,
+    }
+}
+
+unsafe extern "C" fn gpu_applications(
+    this: *mut ffi::IADLXGPU2,
+    applications: *mut *mut ffi::IADLXApplicationList,
+) -> ffi::ADLX_RESULT {
+    let gpu = Object::<GpuKind>::get(this);
+    let list = gpu
+        .record()
+        .gpu2
+        .as_ref()
+        .and_then(|gpu2| gpu2.applications.as_ref())
+        .map_err(|e| *e)
+        .map(|applications| {
+            Object::create(ApplicationListKind {
+                items: Mutex::new(applications.clone()),
+            })
+        });
+    emit(list, applications)
+}
+
+/// Encodes `text` as a NUL-terminated UTF-16 `wchar_t` string.
+fn wide(text: &Text) -> Vec<ffi::wchar_t> {
+    text.as_str().encode_utf16().chain([0]).collect()
+}
+
+struct ApplicationKind {
+    record: ApplicationRecord,
+    name: Recorded<Vec<ffi::wchar_t>>,
+    full_path: Recorded<Vec<ffi::wchar_t>>,
+}
+
+impl Kind for ApplicationKind {
+    type Vtbl = ffi::IADLXApplicationVtbl;
+
+    vtbl!(
+        Self,
+        IADLXApplicationVtbl,
+        IADLXApplication {
+            ProcessID: application_process_id,
+            FullPath: application_full_path,
+            Name: application_name,
+            GPUDependencyType: application_gpu_dependency_type,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLXApplication"
+    }
+}
+
+getter!(application_process_id(ffi::IADLXApplication, ApplicationKind) -> u32, |application| {
+    application.record.process_id.as_ref()
+});
+getter!(application_gpu_dependency_type(ffi::IADLXApplication, ApplicationKind) -> i32, |application| {
+    application.record.gpu_dependency_type.as_ref()
+});
+
+unsafe fn write_wide(
+    value: &Recorded<Vec<ffi::wchar_t>>,
+    out: *mut *const ffi::wchar_t,
+) -> ffi::ADLX_RESULT {
+    if out.is_null() {
+        return INVALID_ARGS;
+    }
+    match value {
+        Ok(value) => {
+            out.write(value.as_ptr());
+            OK
+        }
+        Err(e) => *e,
+    }
+}
+
+unsafe extern "C" fn application_full_path(
+    this: *mut ffi::IADLXApplication,
+    full_path: *mut *const ffi::wchar_t,
+) -> ffi::ADLX_RESULT {
+    write_wide(&Object::<ApplicationKind>::get(this).full_path, full_path)
+}
+
+unsafe extern "C" fn application_name(
+    this: *mut ffi::IADLXApplication,
+    name: *mut *const ffi::wchar_t,
+) -> ffi::ADLX_RESULT {
+    write_wide(&Object::<ApplicationKind>::get(this).name, name)
+}
+
+struct ApplicationListKind {
+    items: Mutex<Vec<ApplicationRecord>>,
+}
+
+impl Kind for ApplicationListKind {
+    type Vtbl = ffi::IADLXApplicationListVtbl;
+
+    vtbl!(
+        Self,
+        IADLXApplicationListVtbl,
+        IADLXApplicationList {
+            Size: list_size::<ffi::IADLXApplicationList, Self>,
+            Empty: list_empty::<ffi::IADLXApplicationList, Self>,
+            Begin: list_begin::<ffi::IADLXApplicationList, Self>,
+            End: list_size::<ffi::IADLXApplicationList, Self>,
+            At: list_at::<ffi::IADLXApplicationList, ffi::IADLXInterface, Self>,
+            Clear: list_clear::<ffi::IADLXApplicationList, Self>,
+            Remove_Back: list_remove_back::<ffi::IADLXApplicationList, Self>,
+            Add_Back: list_add_back::<ffi::IADLXApplicationList, ffi::IADLXInterface, Self>,
+            At_ApplicationList: list_at::<ffi::IADLXApplicationList, ffi::IADLXApplication, Self>,
+            Add_Back_ApplicationList: list_add_back::<
+                ffi::IADLXApplicationList,
+                ffi::IADLXApplication,
+                Self,
+            >,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        matches!(iid, "IADLXList" | "IADLXApplicationList")
+    }
+}
+
+impl ListKind for ApplicationListKind {
+    type Item = ApplicationRecord;
+
+    fn items(&self) -> &Mutex<Vec<ApplicationRecord>> {
+        &self.items
+    }
+
+    fn create_item(&self, record: &ApplicationRecord) -> *mut c_void {
+        Object::create(ApplicationKind {
+            name: record.name.as_ref().map(wide).map_err(|e| *e),
+            full_path: record.full_path.as_ref().map(wide).map_err(|e| *e),
+            record: record.clone(),
+        })
+    }
+
+    unsafe fn item_from_raw(&self, raw: *mut c_void) -> ApplicationRecord {
+        Object::<ApplicationKind>::get(raw).record.clone()
+    }
+}
+
+struct DisplayServicesKind {
+    data: Arc<ReplayData>,
+}
+
+impl Kind for DisplayServicesKind {
+    type Vtbl = ffi::IADLXDisplayServicesVtbl;
+
+    vtbl!(
+        Self,
+        IADLXDisplayServicesVtbl,
+        IADLXDisplayServices {
+            GetDisplays: display_services_displays,
+            GetFreeSync: display_services_free_sync,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLXDisplayServices"
+    }
+}
+
+unsafe extern "C" fn display_services_displays(
+    this: *mut ffi::IADLXDisplayServices,
+    displays: *mut *mut ffi::IADLXDisplayList,
+) -> ffi::ADLX_RESULT {
+    let data = &Object::<DisplayServicesKind>::get(this).data;
+    let list = data
+        .session
+        .displays
+        .as_ref()
+        .map_err(|e| *e)
+        .map(|displays| {
+            Object::create(DisplayListKind {
+                data: data.clone(),
+                items: Mutex::new((0..displays.len()).collect()),
+            })
+        });
+    emit(list, displays)
+}
+
+unsafe extern "C" fn display_services_free_sync(
+    this: *mut ffi::IADLXDisplayServices,
+    display: *mut ffi::IADLXDisplay,
+    free_sync: *mut *mut ffi::IADLXDisplayFreeSync,
+) -> ffi::ADLX_RESULT {
+    if display.is_null() {
+        return INVALID_ARGS;
+    }
+    let data = &Object::<DisplayServicesKind>::get(this).data;
+    let index = Object::<DisplayKind>::get(display).index;
+    let object = data.displays()[index]
+        .free_sync
+        .as_ref()
+        .map_err(|e| *e)
+        .map(|_| {
+            Object::create(FreeSyncKind {
+                data: data.clone(),
+                index,
+            })
+        });
+    emit(object, free_sync)
+}
+
+struct DisplayListKind {
+    data: Arc<ReplayData>,
+    items: Mutex<Vec<usize>>,
+}
+
+impl Kind for DisplayListKind {
+    type Vtbl = ffi::IADLXDisplayListVtbl;
+
+    vtbl!(
+        Self,
+        IADLXDisplayListVtbl,
+        IADLXDisplayList {
+            Size: list_size::<ffi::IADLXDisplayList, Self>,
+            Empty: list_empty::<ffi::IADLXDisplayList, Self>,
+            Begin: list_begin::<ffi::IADLXDisplayList, Self>,
+            End: list_size::<ffi::IADLXDisplayList, Self>,
+            At: list_at::<ffi::IADLXDisplayList, ffi::IADLXInterface, Self>,
+            Clear: list_clear::<ffi::IADLXDisplayList, Self>,
+            Remove_Back: list_remove_back::<ffi::IADLXDisplayList, Self>,
+            Add_Back: list_add_back::<ffi::IADLXDisplayList, ffi::IADLXInterface, Self>,
+            At_DisplayList: list_at::<ffi::IADLXDisplayList, ffi::IADLXDisplay, Self>,
+            Add_Back_DisplayList: list_add_back::<ffi::IADLXDisplayList, ffi::IADLXDisplay, Self>,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        matches!(iid, "IADLXList" | "IADLXDisplayList")
+    }
+}
+
+impl ListKind for DisplayListKind {
+    type Item = usize;
+
+    fn items(&self) -> &Mutex<Vec<usize>> {
+        &self.items
+    }
+
+    fn create_item(&self, &index: &usize) -> *mut c_void {
+        Object::create(DisplayKind {
+            data: self.data.clone(),
+            index,
+        })
+    }
+
+    unsafe fn item_from_raw(&self, raw: *mut c_void) -> usize {
+        Object::<DisplayKind>::get(raw).index
+    }
+}
+
+struct DisplayKind {
+    data: Arc<ReplayData>,
+    /// Index into [`Session::displays`]
+    index: usize,
+}
+
+impl Kind for DisplayKind {
+    type Vtbl = ffi::IADLXDisplayVtbl;
+
+    vtbl!(
+        Self,
+        IADLXDisplayVtbl,
+        IADLXDisplay {
+            GetGPU: display_gpu,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLXDisplay"
+    }
+}
+
+unsafe extern "C" fn display_gpu(
+    this: *mut ffi::IADLXDisplay,
+    gpu: *mut *mut ffi::IADLXGPU,
+) -> ffi::ADLX_RESULT {
+    let display = Object::<DisplayKind>::get(this);
+    let object = display.data.displays()[display.index].gpu.map(|index| {
+        Object::create(GpuKind {
+            data: display.data.clone(),
+            index,
+        })
+    });
+    emit(object, gpu)
+}
+
+struct FreeSyncKind {
+    data: Arc<ReplayData>,
+    /// Index into [`Session::displays`]
+    index: usize,
+}
+
+impl FreeSyncKind {
+    fn record(&self) -> &super::session::FreeSyncRecord {
+        self.data.displays()[self.index].free_sync.as_ref().unwrap()
+    }
+}
+
+impl Kind for FreeSyncKind {
+    type Vtbl = ffi::IADLXFreeSyncVtbl;
+
+    vtbl!(
+        Self,
+        IADLXFreeSyncVtbl,
+        IADLXDisplayFreeSync {
+            IsSupported: free_sync_is_supported,
+            IsEnabled: free_sync_is_enabled,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLXDisplayFreeSync"
+    }
+}
+
+getter!(free_sync_is_supported(ffi::IADLXDisplayFreeSync, FreeSyncKind) -> bool, |free_sync| {
+    free_sync.record().is_supported.as_ref()
+});
+getter!(free_sync_is_enabled(ffi::IADLXDisplayFreeSync, FreeSyncKind) -> bool, |free_sync| {
+    free_sync.record().is_enabled.as_ref()
+});
+
+struct ThreeDSettingsServicesKind {
+    data: Arc<ReplayData>,
+}
+
+impl ThreeDSettingsServicesKind {
+    fn create<R, K: Kind, I>(
+        &self,
+        gpu: *mut ffi::IADLXGPU,
+        setting: impl FnOnce(&super::session::ThreeDSettingsRecord) -> &Recorded<R>,
+        kind: impl FnOnce(Arc<ReplayData>, usize) -> K,
+    ) -> Recorded<*mut I> {
+        let gpu = unsafe { gpu_index(gpu) }?;
+        // Services are only handed out when they were recorded successfully
+        let settings = &self.data.session.three_d_settings.as_ref().unwrap()[gpu];
+        setting(settings).as_ref().map_err(|e| *e)?;
+        Ok(Object::create(kind(self.data.clone(), gpu)))
+    }
+}
+
+impl Kind for ThreeDSettingsServicesKind {
+    type Vtbl = ffi::IADLX3DSettingsServicesVtbl;
+
+    vtbl!(
+        Self,
+        IADLX3DSettingsServicesVtbl,
+        IADLX3DSettingsServices {
+            GetChill: three_d_settings_services_chill,
+            GetWaitForVerticalRefresh: three_d_settings_services_wait_for_vertical_refresh,
+            GetFrameRateTargetControl: three_d_settings_services_frame_rate_target_control,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLX3DSettingsServices"
+    }
+}
+
+unsafe extern "C" fn three_d_settings_services_chill(
+    this: *mut ffi::IADLX3DSettingsServices,
+    gpu: *mut ffi::IADLXGPU,
+    chill: *mut *mut ffi::IADLX3DChill,
+) -> ffi::ADLX_RESULT {
+    let services = Object::<ThreeDSettingsServicesKind>::get(this);
+    let object = services.create(gpu, |s| &s.chill, |data, gpu| ChillKind { data, gpu });
+    emit(object, chill)
+}
+
+unsafe extern "C" fn three_d_settings_services_wait_for_vertical_refresh(
+    this: *mut ffi::IADLX3DSettingsServices,
+    gpu: *mut ffi::IADLXGPU,
+    vsync: *mut *mut ffi::IADLX3DWaitForVerticalRefresh,
+) -> ffi::ADLX_RESULT {
+    let services = Object::<ThreeDSettingsServicesKind>::get(this);
+    let object = services.create(
+        gpu,
+        |s| &s.wait_for_vertical_refresh,
+        |data, gpu| WaitForVerticalRefreshKind { data, gpu },
+    );
+    emit(object, vsync)
+}
+
+unsafe extern "C" fn three_d_settings_services_frame_rate_target_control(
+    this: *mut ffi::IADLX3DSettingsServices,
+    gpu: *mut ffi::IADLXGPU,
+    frtc: *mut *mut ffi::IADLX3DFrameRateTargetControl,
+) -> ffi::ADLX_RESULT {
+    let services = Object::<ThreeDSettingsServicesKind>::get(this);
+    let object = services.create(
+        gpu,
+        |s| &s.frame_rate_target_control,
+        |data, gpu| FrameRateTargetControlKind { data, gpu },
+    );
+    emit(object, frtc)
+}
+
+struct WaitForVerticalRefreshKind {
+    data: Arc<ReplayData>,
+    gpu: usize,
+}
+
+impl WaitForVerticalRefreshKind {
+    fn record(&self) -> &WaitForVerticalRefreshRecord {
+        self.data.session.three_d_settings.as_ref().unwrap()[self.gpu]
+            .wait_for_vertical_refresh
+            .as_ref()
+            .unwrap()
+    }
+}
+
+impl Kind for WaitForVerticalRefreshKind {
+    type Vtbl = ffi::IADLX3DWaitForVerticalRefreshVtbl;
+
+    vtbl!(
+        Self,
+        IADLX3DWaitForVerticalRefreshVtbl,
+        IADLX3DWaitForVerticalRefresh {
+            IsSupported: vsync_is_supported,
+            IsEnabled: vsync_is_enabled,
+            GetMode: vsync_mode,
+            SetMode: vsync_set_mode,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLX3DWaitForVerticalRefresh"
+    }
+}
+
+getter!(vsync_is_supported(ffi::IADLX3DWaitForVerticalRefresh, WaitForVerticalRefreshKind) -> bool, |vsync| {
+    vsync.record().is_supported.as_ref()
+});
+getter!(vsync_is_enabled(ffi::IADLX3DWaitForVerticalRefresh, WaitForVerticalRefreshKind) -> bool, |vsync| {
+    vsync.record().is_enabled.as_ref()
+});
+
+unsafe extern "C" fn vsync_mode(
+    this: *mut ffi::IADLX3DWaitForVerticalRefresh,
+    mode: *mut ffi::ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE,
+) -> ffi::ADLX_RESULT {
+    let vsync = Object::<WaitForVerticalRefreshKind>::get(this);
+    let state = vsync.data.state.lock().unwrap();
+    write(
+        state.wait_for_vertical_refresh_modes[vsync.gpu].as_ref(),
+        mode,
+    )
+}
+
+unsafe extern "C" fn vsync_set_mode(
+    this: *mut ffi::IADLX3DWaitForVerticalRefresh,
+    mode: ffi::ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE,
+) -> ffi::ADLX_RESULT {
+    let vsync = Object::<WaitForVerticalRefreshKind>::get(this);
+    if let Err(e) = vsync.record().is_supported {
+        return e;
+    }
+    vsync
+        .data
+        .state
+        .lock()
+        .unwrap()
+        .wait_for_vertical_refresh_modes[vsync.gpu] = Ok(mode);
+    OK
+}
+
+struct ChillKind {
+    data: Arc<ReplayData>,
+    gpu: usize,
+}
+
+impl ChillKind {
+    fn record(&self) -> &ChillRecord {
+        self.data.session.three_d_settings.as_ref().unwrap()[self.gpu]
+            .chill
+            .as_ref()
+            .unwrap()
+    }
+}
+
+impl Kind for ChillKind {
+    type Vtbl = ffi::IADLX3DChillVtbl;
+
+    vtbl!(
+        Self,
+        IADLX3DChillVtbl,
+        IADLX3DChill {
+            IsSupported: chill_is_supported,
+            IsEnabled: chill_is_enabled,
+            GetFPSRange: chill_fps_range,
+            GetMinFPS: chill_min_fps,
+            GetMaxFPS: chill_max_fps,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLX3DChill"
+    }
+}
+
+getter!(chill_is_supported(ffi::IADLX3DChill, ChillKind) -> bool, |chill| {
+    chill.record().is_supported.as_ref()
+});
+getter!(chill_is_enabled(ffi::IADLX3DChill, ChillKind) -> bool, |chill| {
+    chill.record().is_enabled.as_ref()
+});
+getter!(chill_fps_range(ffi::IADLX3DChill, ChillKind) -> IntRange, |chill| {
+    chill.record().fps_range.as_ref()
+});
+getter!(chill_min_fps(ffi::IADLX3DChill, ChillKind) -> i32, |chill| chill.record().min_fps.as_ref());
+getter!(chill_max_fps(ffi::IADLX3DChill, ChillKind) -> i32, |chill| chill.record().max_fps.as_ref());
+
+struct FrameRateTargetControlKind {
+    data: Arc<ReplayData>,
+    gpu: usize,
+}
+
+impl FrameRateTargetControlKind {
+    fn record(&self) -> &FrameRateTargetControlRecord {
+        self.data.session.three_d_settings.as_ref().unwrap()[self.gpu]
+            .frame_rate_target_control
+            .as_ref()
+            .unwrap()
+    }
+}
+
+impl Kind for FrameRateTargetControlKind {
+    type Vtbl = ffi::IADLX3DFrameRateTargetControlVtbl;
+
+    vtbl!(
+        Self,
+        IADLX3DFrameRateTargetControlVtbl,
+        IADLX3DFrameRateTargetControl {
+            IsSupported: frtc_is_supported,
+            IsEnabled: frtc_is_enabled,
+            GetFPSRange: frtc_fps_range,
+            GetFPS: frtc_fps,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLX3DFrameRateTargetControl"
+    }
+}
+
+getter!(frtc_is_supported(ffi::IADLX3DFrameRateTargetControl, FrameRateTargetControlKind) -> bool, |frtc| {
+    frtc.record().is_supported.as_ref()
+});
+getter!(frtc_is_enabled(ffi::IADLX3DFrameRateTargetControl, FrameRateTargetControlKind) -> bool, |frtc| {
+    frtc.record().is_enabled.as_ref()
+});
+getter!(frtc_fps_range(ffi::IADLX3DFrameRateTargetControl, FrameRateTargetControlKind) -> IntRange, |frtc| {
+    frtc.record().fps_range.as_ref()
+});
+getter!(frtc_fps(ffi::IADLX3DFrameRateTargetControl, FrameRateTargetControlKind) -> i32, |frtc| {
+    frtc.record().fps.as_ref()
+});
+
+struct PerformanceMonitoringServicesKind {
+    data: Arc<ReplayData>,
+}
+
+impl Kind for PerformanceMonitoringServicesKind {
+    type Vtbl = ffi::IADLXPerformanceMonitoringServicesVtbl;
+
+    vtbl!(
+        Self,
+        IADLXPerformanceMonitoringServicesVtbl,
+        IADLXPerformanceMonitoringServices {
+            GetSamplingIntervalRange: pms_sampling_interval_range,
+            SetSamplingInterval: pms_set_sampling_interval,
+            GetSamplingInterval: pms_sampling_interval,
+            GetMaxPerformanceMetricsHistorySizeRange: pms_max_history_size_range,
+            SetMaxPerformanceMetricsHistorySize: pms_set_max_history_size,
+            GetMaxPerformanceMetricsHistorySize: pms_max_history_size,
+            ClearPerformanceMetricsHistory: pms_ok,
+            GetCurrentPerformanceMetricsHistorySize: pms_current_history_size,
+            StartPerformanceMetricsTracking: pms_ok,
+            StopPerformanceMetricsTracking: pms_ok,
+            GetGPUMetricsHistory: pms_gpu_metrics_history,
+            GetFPSHistory: pms_fps_history,
+            GetCurrentGPUMetrics: pms_current_gpu_metrics,
+            GetCurrentFPS: pms_current_fps,
+            GetSupportedGPUMetrics: pms_supported_gpu_metrics,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLXPerformanceMonitoringServices"
+    }
+}
+
+getter!(pms_sampling_interval_range(ffi::IADLXPerformanceMonitoringServices, PerformanceMonitoringServicesKind) -> IntRange, |pms| {
+    pms.data.performance_monitoring().sampling_interval_range.as_ref()
+});
+getter!(pms_max_history_size_range(ffi::IADLXPerformanceMonitoringServices, PerformanceMonitoringServicesKind) -> IntRange, |pms| {
+    pms.data.performance_monitoring().max_performance_metrics_history_range.as_ref()
+});
+getter!(pms_current_history_size(ffi::IADLXPerformanceMonitoringServices, PerformanceMonitoringServicesKind) -> i32, |pms| {
+    pms.data
+        .performance_monitoring()
+        .current_performance_metrics_history_size_in_sec
+        .as_ref()
+});
+
+unsafe extern "C" fn pms_sampling_interval(
+    this: *mut ffi::IADLXPerformanceMonitoringServices,
+    interval: *mut ffi::adlx_int,
+) -> ffi::ADLX_RESULT {
+    let pms = Object::<PerformanceMonitoringServicesKind>::get(this);
+    write(
+        pms.data.state.lock().unwrap().sampling_interval.as_ref(),
+        interval,
+    )
+}
+
+unsafe extern "C" fn pms_max_history_size(
+    this: *mut ffi::IADLXPerformanceMonitoringServices,
+    size: *mut ffi::adlx_int,
+) -> ffi::ADLX_RESULT {
+    let pms = Object::<PerformanceMonitoringServicesKind>::get(this);
+    let state = pms.data.state.lock().unwrap();
+    write(state.max_performance_metrics_history_size.as_ref(), size)
+}
+
+/// Stores `value` in `setting` if it lies within the recorded `range`.
+fn set_in_range(
+    range: &Recorded<IntRange>,
+    setting: &mut Recorded<i32>,
+    value: i32,
+) -> ffi::ADLX_RESULT {
+    match range {
+        Ok(range) if (range.min..=range.max).contains(&value) => {
+            *setting = Ok(value);
+            OK
+        }
+        Ok(_) => INVALID_ARGS,
+        Err(e) => *e,
+    }
+}
+
+unsafe extern "C" fn pms_set_sampling_interval(
+    this: *mut ffi::IADLXPerformanceMonitoringServices,
+    interval: ffi::adlx_int,
+) -> ffi::ADLX_RESULT {
+    let pms = Object::<PerformanceMonitoringServicesKind>::get(this);
+    set_in_range(
+        &pms.data.performance_monitoring().sampling_interval_range,
+        &mut pms.data.state.lock().unwrap().sampling_interval,
+        interval,
+    )
+}
+
+unsafe extern "C" fn pms_set_max_history_size(
+    this: *mut ffi::IADLXPerformanceMonitoringServices,
+    size: ffi::adlx_int,
+) -> ffi::ADLX_RESULT {
+    let pms = Object::<PerformanceMonitoringServicesKind>::get(this);
+    set_in_range(
+        &pms.data
+            .performance_monitoring()
+            .max_performance_metrics_history_range,
+        &mut pms
+            .data
+            .state
+            .lock()
+            .unwrap()
+            .max_performance_metrics_history_size,
+        size,
+    )
+}
+
+unsafe extern "C" fn pms_ok(
+    _this: *mut ffi::IADLXPerformanceMonitoringServices,
+) -> ffi::ADLX_RESULT {
+    OK
+}
+
+unsafe extern "C" fn pms_gpu_metrics_history(
+    this: *mut ffi::IADLXPerformanceMonitoringServices,
+    gpu: *mut ffi::IADLXGPU,
+    _start_ms: ffi::adlx_int,
+    _stop_ms: ffi::adlx_int,
+    metrics_list: *mut *mut ffi::IADLXGPUMetricsList,
+) -> ffi::ADLX_RESULT {
+    let pms = Object::<PerformanceMonitoringServicesKind>::get(this);
+    let list = gpu_index(gpu).and_then(|gpu| {
+        let history = &pms.data.performance_monitoring().gpus[gpu].gpu_metrics_history;
+        history.as_ref().map_err(|e| *e).map(|history| {
+            Object::create(GpuMetricsListKind {
+                items: Mutex::new(history.clone()),
+            })
+        })
+    });
+    emit(list, metrics_list)
+}
+
+unsafe extern "C" fn pms_current_gpu_metrics(
+    this: *mut ffi::IADLXPerformanceMonitoringServices,
+    gpu: *mut ffi::IADLXGPU,
+    metrics: *mut *mut ffi::IADLXGPUMetrics,
+) -> ffi::ADLX_RESULT {
+    let pms = Object::<PerformanceMonitoringServicesKind>::get(this);
+    let object = gpu_index(gpu).and_then(|gpu| {
+        let samples = &pms.data.performance_monitoring().gpus[gpu].current_gpu_metrics;
+        if samples.is_empty() {
+            return Err(ffi::ADLX_RESULT_ADLX_NOT_FOUND);
+        }
+        let mut state = pms.data.state.lock().unwrap();
+        let cursor = &mut state.metrics_cursors[gpu];
+        let sample = samples[*cursor % samples.len()].clone();
+        *cursor += 1;
+        sample.map(|record| Object::create(GpuMetricsKind { record }))
+    });
+    emit(object, metrics)
+}
+
+unsafe extern "C" fn pms_fps_history(
+    this: *mut ffi::IADLXPerformanceMonitoringServices,
+    _start_ms: ffi::adlx_int,
+    _stop_ms: ffi::adlx_int,
+    fps_list: *mut *mut ffi::IADLXFPSList,
+) -> ffi::ADLX_RESULT {
+    let pms = Object::<PerformanceMonitoringServicesKind>::get(this);
+    let list = pms
+        .data
+        .performance_monitoring()
+        .fps_history
+        .as_ref()
+        .map_err(|e| *e)
+        .map(|history| {
+            Object::create(FpsListKind {
+                items: Mutex::new(history.clone()),
+            })
+        });
+    emit(list, fps_list)
+}
+
+unsafe extern "C" fn pms_current_fps(
+    this: *mut ffi::IADLXPerformanceMonitoringServices,
+    fps: *mut *mut ffi::IADLXFPS,
+) -> ffi::ADLX_RESULT {
+    let pms = Object::<PerformanceMonitoringServicesKind>::get(this);
+    let samples = &pms.data.performance_monitoring().current_fps;
+    let object = if samples.is_empty() {
+        Err(ffi::ADLX_RESULT_ADLX_NOT_FOUND)
+    } else {
+        let mut state = pms.data.state.lock().unwrap();
+        let sample = samples[state.fps_cursor % samples.len()].clone();
+        state.fps_cursor += 1;
+        sample.map(|record| Object::create(FpsKind { record }))
+    };
+    emit(object, fps)
+}
+
+unsafe extern "C" fn pms_supported_gpu_metrics(
+    this: *mut ffi::IADLXPerformanceMonitoringServices,
+    gpu: *mut ffi::IADLXGPU,
+    support: *mut *mut ffi::IADLXGPUMetricsSupport,
+) -> ffi::ADLX_RESULT {
+    let pms = Object::<PerformanceMonitoringServicesKind>::get(this);
+    let object = gpu_index(gpu).and_then(|gpu| {
+        let record = &pms.data.performance_monitoring().gpus[gpu].supported_gpu_metrics;
+        record.as_ref().map_err(|e| *e)?;
+        Ok(Object::create(GpuMetricsSupportKind {
+            data: pms.data.clone(),
+            gpu,
+        }))
+    });
+    emit(object, support)
+}
+
+struct GpuMetricsSupportKind {
+    data: Arc<ReplayData>,
+    gpu: usize,
+}
+
+impl GpuMetricsSupportKind {
+    fn record(&self) -> &GpuMetricsSupportRecord {
+        self.data.performance_monitoring().gpus[self.gpu]
+            .supported_gpu_metrics
+            .as_ref()
+            .unwrap()
+    }
+}
+
+impl Kind for GpuMetricsSupportKind {
+    type Vtbl = ffi::IADLXGPUMetricsSupportVtbl;
+
+    vtbl!(
+        Self,
+        IADLXGPUMetricsSupportVtbl,
+        IADLXGPUMetricsSupport {
+            IsSupportedGPUUsage: support_is_supported_usage,
+            IsSupportedGPUClockSpeed: support_is_supported_clock_speed,
+            IsSupportedGPUVRAMClockSpeed: support_is_supported_vram_clock_speed,
+            IsSupportedGPUTemperature: support_is_supported_temperature,
+            IsSupportedGPUHotspotTemperature: support_is_supported_hotspot_temperature,
+            IsSupportedGPUPower: support_is_supported_power,
+            IsSupportedGPUTotalBoardPower: support_is_supported_total_board_power,
+            IsSupportedGPUFanSpeed: support_is_supported_fan_speed,
+            IsSupportedGPUVRAM: support_is_supported_vram,
+            IsSupportedGPUVoltage: support_is_supported_voltage,
+            IsSupportedGPUIntakeTemperature: support_is_supported_intake_temperature,
+            GetGPUUsageRange: support_usage_range,
+            GetGPUClockSpeedRange: support_clock_speed_range,
+            GetGPUVRAMClockSpeedRange: support_vram_clock_speed_range,
+            GetGPUTemperatureRange: support_temperature_range,
+            GetGPUHotspotTemperatureRange: support_hotspot_temperature_range,
+            GetGPUPowerRange: support_power_range,
+            GetGPUFanSpeedRange: support_fan_speed_range,
+            GetGPUVRAMRange: support_vram_range,
+            GetGPUVoltageRange: support_voltage_range,
+            GetGPUTotalBoardPowerRange: support_total_board_power_range,
+            GetGPUIntakeTemperatureRange: support_intake_temperature_range,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLXGPUMetricsSupport"
+    }
+}
+
+/// Defines the `IsSupported*` and `Get*Range` functions of a single metric.
+macro_rules! metric_support {
+    ($($metric:ident: $is_supported:ident, $range:ident;)*) => {$(
+        getter!($is_supported(ffi::IADLXGPUMetricsSupport, GpuMetricsSupportKind) -> bool, |support| {
+            support.record().$metric.is_supported.as_ref()
+        });
+
+        unsafe extern "C" fn $range(
+            this: *mut ffi::IADLXGPUMetricsSupport,
+            min: *mut ffi::adlx_int,
+            max: *mut ffi::adlx_int,
+        ) -> ffi::ADLX_RESULT {
+            if min.is_null() || max.is_null() {
+                return INVALID_ARGS;
+            }
+            match &Object::<GpuMetricsSupportKind>::get(this).record().$metric.range {
+                Ok(range) => {
+                    min.write(*range.start());
+                    max.write(*range.end());
+                    OK
+                }
+                Err(e) => *e,
+            }
+        }
+    )*};
+}
+
+metric_support! {
+    usage: support_is_supported_usage, support_usage_range;
+    clock_speed: support_is_supported_clock_speed, support_clock_speed_range;
+    vram_clock_speed: support_is_supported_vram_clock_speed, support_vram_clock_speed_range;
+    temperature: support_is_supported_temperature, support_temperature_range;
+    hotspot_temperature: support_is_supported_hotspot_temperature, support_hotspot_temperature_range;
+    power: support_is_supported_power, support_power_range;
+    total_board_power: support_is_supported_total_board_power, support_total_board_power_range;
+    fan_speed: support_is_supported_fan_speed, support_fan_speed_range;
+    vram: support_is_supported_vram, support_vram_range;
+    voltage: support_is_supported_voltage, support_voltage_range;
+    intake_temperature: support_is_supported_intake_temperature, support_intake_temperature_range;
+}
+
+struct GpuMetricsKind {
+    record: GpuMetricsRecord,
+}
+
+impl Kind for GpuMetricsKind {
+    type Vtbl = ffi::IADLXGPUMetricsVtbl;
+
+    vtbl!(
+        Self,
+        IADLXGPUMetricsVtbl,
+        IADLXGPUMetrics {
+            TimeStamp: metrics_time_stamp,
+            GPUUsage: metrics_usage,
+            GPUClockSpeed: metrics_clock_speed,
+            GPUVRAMClockSpeed: metrics_vram_clock_speed,
+            GPUTemperature: metrics_temperature,
+            GPUHotspotTemperature: metrics_hotspot_temperature,
+            GPUPower: metrics_power,
+            GPUTotalBoardPower: metrics_total_board_power,
+            GPUFanSpeed: metrics_fan_speed,
+            GPUVRAM: metrics_vram,
+            GPUVoltage: metrics_voltage,
+            GPUIntakeTemperature: metrics_intake_temperature,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLXGPUMetrics"
+    }
+}
+
+/// Defines the getter of a single [`GpuMetricsRecord`] field.
+macro_rules! metric {
+    ($($metric:ident: $name:ident -> $ty:ty;)*) => {$(
+        getter!($name(ffi::IADLXGPUMetrics, GpuMetricsKind) -> $ty, |metrics| {
+            metrics.record.$metric.as_ref()
+        });
+    )*};
+}
+
+metric! {
+    time_stamp: metrics_time_stamp -> i64;
+    usage: metrics_usage -> f64;
+    clock_speed: metrics_clock_speed -> i32;
+    vram_clock_speed: metrics_vram_clock_speed -> i32;
+    temperature: metrics_temperature -> f64;
+    hotspot_temperature: metrics_hotspot_temperature -> f64;
+    power: metrics_power -> f64;
+    total_board_power: metrics_total_board_power -> f64;
+    fan_speed: metrics_fan_speed -> i32;
+    vram: metrics_vram -> i32;
+    voltage: metrics_voltage -> i32;
+    intake_temperature: metrics_intake_temperature -> f64;
+}
+
+struct GpuMetricsListKind {
+    items: Mutex<Vec<GpuMetricsRecord>>,
+}
+
+impl Kind for GpuMetricsListKind {
+    type Vtbl = ffi::IADLXGPUMetricsListVtbl;
+
+    vtbl!(
+        Self,
+        IADLXGPUMetricsListVtbl,
+        IADLXGPUMetricsList {
+            Size: list_size::<ffi::IADLXGPUMetricsList, Self>,
+            Empty: list_empty::<ffi::IADLXGPUMetricsList, Self>,
+            Begin: list_begin::<ffi::IADLXGPUMetricsList, Self>,
+            End: list_size::<ffi::IADLXGPUMetricsList, Self>,
+            At: list_at::<ffi::IADLXGPUMetricsList, ffi::IADLXInterface, Self>,
+            Clear: list_clear::<ffi::IADLXGPUMetricsList, Self>,
+            Remove_Back: list_remove_back::<ffi::IADLXGPUMetricsList, Self>,
+            Add_Back: list_add_back::<ffi::IADLXGPUMetricsList, ffi::IADLXInterface, Self>,
+            At_GPUMetricsList: list_at::<ffi::IADLXGPUMetricsList, ffi::IADLXGPUMetrics, Self>,
+            Add_Back_GPUMetricsList: list_add_back::<
+                ffi::IADLXGPUMetricsList,
+                ffi::IADLXGPUMetrics,
+                Self,
+            >,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        matches!(iid, "IADLXList" | "IADLXGPUMetricsList")
+    }
+}
+
+impl ListKind for GpuMetricsListKind {
+    type Item = GpuMetricsRecord;
+
+    fn items(&self) -> &Mutex<Vec<GpuMetricsRecord>> {
+        &self.items
+    }
+
+    fn create_item(&self, record: &GpuMetricsRecord) -> *mut c_void {
+        Object::create(GpuMetricsKind {
+            record: record.clone(),
+        })
+    }
+
+    unsafe fn item_from_raw(&self, raw: *mut c_void) -> GpuMetricsRecord {
+        Object::<GpuMetricsKind>::get(raw).record.clone()
+    }
+}
+
+struct FpsKind {
+    record: FpsRecord,
+}
+
+impl Kind for FpsKind {
+    type Vtbl = ffi::IADLXFPSVtbl;
+
+    vtbl!(
+        Self,
+        IADLXFPSVtbl,
+        IADLXFPS {
+            TimeStamp: fps_time_stamp,
+            FPS: fps_fps,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        iid == "IADLXFPS"
+    }
+}
+
+getter!(fps_time_stamp(ffi::IADLXFPS, FpsKind) -> i64, |fps| fps.record.time_stamp.as_ref());
+getter!(fps_fps(ffi::IADLXFPS, FpsKind) -> i32, |fps| fps.record.fps.as_ref());
+
+struct FpsListKind {
+    items: Mutex<Vec<FpsRecord>>,
+}
+
+impl Kind for FpsListKind {
+    type Vtbl = ffi::IADLXFPSListVtbl;
+
+    vtbl!(
+        Self,
+        IADLXFPSListVtbl,
+        IADLXFPSList {
+            Size: list_size::<ffi::IADLXFPSList, Self>,
+            Empty: list_empty::<ffi::IADLXFPSList, Self>,
+            Begin: list_begin::<ffi::IADLXFPSList, Self>,
+            End: list_size::<ffi::IADLXFPSList, Self>,
+            At: list_at::<ffi::IADLXFPSList, ffi::IADLXInterface, Self>,
+            Clear: list_clear::<ffi::IADLXFPSList, Self>,
+            Remove_Back: list_remove_back::<ffi::IADLXFPSList, Self>,
+            Add_Back: list_add_back::<ffi::IADLXFPSList, ffi::IADLXInterface, Self>,
+            At_FPSList: list_at::<ffi::IADLXFPSList, ffi::IADLXFPS, Self>,
+            Add_Back_FPSList: list_add_back::<ffi::IADLXFPSList, ffi::IADLXFPS, Self>,
+        }
+    );
+
+    fn implements(&self, iid: &str) -> bool {
+        matches!(iid, "IADLXList" | "IADLXFPSList")
+    }
+}
+
+impl ListKind for FpsListKind {
+    type Item = FpsRecord;
+
+    fn items(&self) -> &Mutex<Vec<FpsRecord>> {
+        &self.items
+    }
+
+    fn create_item(&self, record: &FpsRecord) -> *mut c_void {
+        Object::create(FpsKind {
+            record: record.clone(),
+        })
+    }
+
+    unsafe fn item_from_raw(&self, raw: *mut c_void) -> FpsRecord {
+        Object::<FpsKind>::get(raw).record.clone()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{
+        ffi,
+        helper::AdlxHelper,
+        interface::Interface,
+        session::{
+            BiosInfoRecord, ChillRecord, DisplayRecord, FpsRecord, FrameRateTargetControlRecord,
+            FreeSyncRecord, Gpu1Record, Gpu2Record, GpuMetricsRecord, GpuMetricsSupportRecord,
+            GpuPerformanceRecord, GpuRecord, IntRange, MetricSupportRecord,
+            PerformanceMonitoringRecord, Recorder, Session, ThreeDSettingsRecord,
+            WaitForVerticalRefreshRecord, SESSION_FORMAT_VERSION,
+        },
+        Gpu, Gpu1, Gpu2, WaitForVerticalRefreshMode,
+    };
+
+    const NOT_SUPPORTED: ffi::ADLX_RESULT = ffi::ADLX_RESULT_ADLX_NOT_SUPPORTED;
+
+    /// A GPU that implements `IADLXGPU2`, which [`session()`] leaves out
+    fn gpu2_record() -> Gpu2Record {
+        Gpu2Record {
+            is_power_off: Ok(false),
+            is_supported_application_list: Ok(true),
+            amd_software_release_date: Ok((2024, 1, 31)),
+            amd_software_edition: Ok("Adrenalin".into()),
+            amd_software_version: Ok("24.1.1".into()),
+            driver_version: Ok("31.0.24002.92".into()),
+            amd_windows_driver_version: Ok("23.40.02.02".into()),
+            luid: Ok((0x1234, 0)),
+            applications: Ok(vec![]),
+        }
+    }
+
+    fn metrics(time_stamp: i64, clock_speed: i32) -> GpuMetricsRecord {
+        GpuMetricsRecord {
+            time_stamp: Ok(time_stamp),
+            usage: Ok(42.5),
+            clock_speed: Ok(clock_speed),
+            vram_clock_speed: Ok(1000),
+            power: Ok(120.0),
+            total_board_power: Err(NOT_SUPPORTED),
+            voltage: Ok(900),
+            vram: Ok(2048),
+            fan_speed: Ok(1500),
+            temperature: Ok(65.0),
+            intake_temperature: Err(NOT_SUPPORTED),
+            hotspot_temperature: Ok(80.0),
+        }
+    }
+
+    fn fps(time_stamp: i64, fps: i32) -> FpsRecord {
+        FpsRecord {
+            time_stamp: Ok(time_stamp),
+            fps: Ok(fps),
+        }
+    }
+
+    /// A single-GPU session, also used by tests of other modules
+    pub(crate) fn session() -> Session {
+        let supported = MetricSupportRecord {
+            is_supported: Ok(true),
+            range: Ok(0..=100),
+        };
+        Session {
+            format_version: SESSION_FORMAT_VERSION,
+            full_version: 0x0001_0004_0000_0001,
+            version: "1.4".into(),
+            hybrid_graphics_type: Ok(ffi::ADLX_HG_TYPE_NONE),
+            gpus: Ok(vec![GpuRecord {
+                vendor_id: Ok("1002".into()),
+                asic_family_type: Ok(ffi::ADLX_ASIC_FAMILY_TYPE_ASIC_RADEON),
+                type_: Ok(ffi::ADLX_GPU_TYPE_GPUTYPE_DISCRETE),
+                is_external: Ok(false),
+                name: Ok("AMD Radeon RX 7900 XTX".into()),
+                driver_path: Ok("Driver\\0000".into()),
+                pnp_string: Ok("PCI\\VEN_1002&DEV_744C&SUBSYS_0E3B1002&REV_C8".into()),
+                has_desktops: Ok(true),
+                total_vram: Ok(24560),
+                vram_type: Ok("GDDR6".into()),
+                bios_info: Ok(BiosInfoRecord {
+                    part_number: "113-D7020100-102".into(),
+                    version: "022.001.002.008.000001".into(),
+                    date: "2022/11/09 21:40".into(),
+                }),
+                device_id: Ok("744C".into()),
+                revision_id: Ok("C8".into()),
+                sub_system_id: Ok("0E3B".into()),
+                sub_system_vendor_id: Ok("1002".into()),
+                unique_id: Ok(0x300),
+                gpu1: Ok(Gpu1Record {
+                    pci_bus_type: Ok(ffi::ADLX_PCI_BUS_TYPE_PCIE_4_0),
+                    pci_bus_lane_width: Ok(16),
+                    multi_gpu_mode: Ok(ffi::ADLX_MGPU_MODE_MGPU_NONE),
+                    product_name: Ok("AMD Radeon RX 7900 XTX".into()),
+                }),
+                gpu2: Err(ffi::ADLX_RESULT_ADLX_UNKNOWN_INTERFACE),
+            }]),
+            displays: Ok(vec![DisplayRecord {
+                gpu: Ok(0),
+                free_sync: Ok(FreeSyncRecord {
+                    is_supported: Ok(true),
+                    is_enabled: Ok(false),
+                }),
+            }]),
+            performance_monitoring: Ok(PerformanceMonitoringRecord {
+                sampling_interval_range: Ok(IntRange {
+                    min: 100,
+                    max: 1000,
+                    step: 1,
+                }),
+                sampling_interval: Ok(500),
+                max_performance_metrics_history_range: Ok(IntRange {
+                    min: 0,
+                    max: 3600,
+                    step: 1,
+                }),
+                max_performance_metrics_history_size: Ok(60),
+                current_performance_metrics_history_size_in_sec: Ok(2),
+                gpus: vec![GpuPerformanceRecord {
+                    supported_gpu_metrics: Ok(GpuMetricsSupportRecord {
+                        usage: supported.clone(),
+                        clock_speed: MetricSupportRecord {
+                            is_supported: Ok(true),
+                            range: Ok(500..=2500),
+                        },
+                        vram_clock_speed: supported.clone(),
+                        temperature: supported.clone(),
+                        hotspot_temperature: supported.clone(),
+                        power: supported.clone(),
+                        total_board_power: MetricSupportRecord {
+                            is_supported: Ok(false),
+                            range: Err(NOT_SUPPORTED),
+                        },
+                        fan_speed: supported.clone(),
+                        vram: supported.clone(),
+                        voltage: supported.clone(),
+                        intake_temperature: MetricSupportRecord {
+                            is_supported: Ok(false),
+                            range: Err(NOT_SUPPORTED),
+                        },
+                    }),
+                    current_gpu_metrics: vec![
+                        Ok(metrics(1000, 2100)),
+                        Err(ffi::ADLX_RESULT_ADLX_FAIL),
+                        Ok(metrics(2000, 2300)),
+                    ],
+                    gpu_metrics_history: Ok(vec![metrics(0, 800), metrics(500, 1200)]),
+                }],
+                current_fps: vec![Ok(fps(1000, 144)), Err(NOT_SUPPORTED), Ok(fps(2000, 165))],
+                fps_history: Ok(vec![fps(0, 60), fps(500, 90)]),
+            }),
+            three_d_settings: Ok(vec![ThreeDSettingsRecord {
+                wait_for_vertical_refresh: Ok(WaitForVerticalRefreshRecord {
+                    is_supported: Ok(true),
+                    is_enabled: Ok(true),
+                    mode: Ok(ffi::ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE_WFVR_ALWAYS_OFF),
+                }),
+                chill: Ok(ChillRecord {
+                    is_supported: Ok(true),
+                    is_enabled: Ok(false),
+                    fps_range: Ok(IntRange {
+                        min: 30,
+                        max: 300,
+                        step: 1,
+                    }),
+                    min_fps: Ok(70),
+                    max_fps: Ok(144),
+                }),
+                frame_rate_target_control: Ok(FrameRateTargetControlRecord {
+                    is_supported: Ok(false),
+                    is_enabled: Err(NOT_SUPPORTED),
+                    fps_range: Err(NOT_SUPPORTED),
+                    fps: Err(NOT_SUPPORTED),
+                }),
+            }]),
+        }
+    }
+
+    /// Replays [`session()`] with its GPU changed by `f`, returning the helper that keeps ADLX
+    /// alive with that GPU
+    pub(crate) fn replay_gpu(f: impl FnOnce(&mut GpuRecord)) -> (AdlxHelper, Gpu) {
+        let mut session = session();
+        f(&mut session.gpus.as_mut().unwrap()[0]);
+        let helper = AdlxHelper::replay(session);
+        let gpu = helper.system().gpus().unwrap().at(0).unwrap();
+        (helper, gpu)
+    }
+
+    /// Like [`replay_gpu()`], for a GPU that implements `IADLXGPU2` with [`gpu2_record()`] changed
+    /// by `f`
+    pub(crate) fn replay_gpu2(f: impl FnOnce(&mut Gpu2Record)) -> (AdlxHelper, Gpu2) {
+        let (helper, gpu) = replay_gpu(|gpu| {
+            let mut gpu2 = gpu2_record();
+            f(&mut gpu2);
+            gpu.gpu2 = Ok(gpu2);
+        });
+        (helper, gpu.cast().unwrap())
+    }
+
+    #[test]
+    fn replay_serves_recorded_values() {
+        let helper = AdlxHelper::replay(session());
+        let system = helper.system();
+        assert_eq!(helper.version(), "1.4");
+
+        let gpus = system.gpus().unwrap();
+        assert_eq!(gpus.size(), 1);
+        let gpu = gpus.at(0).unwrap();
+        assert_eq!(gpu.name().unwrap(), "AMD Radeon RX 7900 XTX");
+        assert_eq!(gpu.total_vram().unwrap(), 24560);
+        assert_eq!(
+            gpu.bios_info().unwrap().version.components(),
+            [22, 1, 2, 8, 1]
+        );
+        assert_eq!(
+            gpu.cast::<Gpu1>().unwrap().pci_bus_lane_width().unwrap(),
+            16
+        );
+        assert_eq!(
+            gpu.cast::<Gpu2>().unwrap_err().adlx_result(),
+            ffi::ADLX_RESULT_ADLX_UNKNOWN_INTERFACE
+        );
+
+        let services = system.performance_monitoring_services().unwrap();
+        let first = services.current_gpu_metrics(&gpu).unwrap();
+        assert_eq!(first.clock_speed().unwrap(), 2100);
+        assert_eq!(
+            first.total_board_power().unwrap_err().adlx_result(),
+            NOT_SUPPORTED
+        );
+        assert!(services.current_gpu_metrics(&gpu).is_err());
+        let support = services.supported_gpu_metrics(&gpu).unwrap();
+        assert_eq!(support.gpu_clock_speed_range().unwrap(), 500..=2500);
+        assert_eq!(services.current_fps().unwrap().fps().unwrap(), 144);
+        assert_eq!(
+            services.fps_history(0, 1000).unwrap().samples().unwrap()[1].fps,
+            90
+        );
+
+        let mut vsync = system
+            .get_3d_settings_services()
+            .unwrap()
+            .get_wait_for_vertical_refresh(&gpu)
+            .unwrap();
+        vsync
+            .set_mode(WaitForVerticalRefreshMode::AlwaysOn)
+            .unwrap();
+        assert_eq!(
+            vsync.get_mode().unwrap(),
+            WaitForVerticalRefreshMode::AlwaysOn
+        );
+
+        let displays = system
+            .get_displays_services()
+            .unwrap()
+            .get_displays()
+            .unwrap();
+        let display_gpu = displays.at(0).unwrap().get_gpu().unwrap();
+        assert_eq!(display_gpu.unique_id().unwrap(), 0x300);
+    }
+
+    #[test]
+    fn record_replay_round_trip() {
+        let session = session();
+
+        let mut file = vec![];
+        session.to_writer(&mut file).unwrap();
+        let loaded = Session::from_reader(file.as_slice()).unwrap();
+        assert_eq!(loaded, session);
+
+        // Recording a replay must reproduce the original session
+        let helper = AdlxHelper::replay(loaded);
+        let mut recorder = Recorder::new(&helper);
+        for _ in 0..3 {
+            recorder.sample_metrics();
+        }
+        recorder.record_metrics_history(0, 1000);
+        assert_eq!(recorder.finish(), session);
+    }
+
+    #[test]
+    fn rejects_other_format_versions() {
+        let mut session = session();
+        session.format_version = SESSION_FORMAT_VERSION + 1;
+
+        let mut file = vec![];
+        session.to_writer(&mut file).unwrap();
+        assert!(Session::from_reader(file.as_slice()).is_err());
+    }
+}
@@ -1,14 +1,26 @@
 use std::{fmt, mem::MaybeUninit};
 
-use super::ffi;
+use super::{ffi, version::AdlxVersion};
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
-pub struct Error(ffi::ADLX_RESULT);
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Error {
+    result: ffi::ADLX_RESULT,
+    /// Set when the call was not made because the loaded runtime is too old
+    required_version: Option<AdlxVersion>,
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self.0 {
+        if let Some(required) = self.required_version {
+            return write!(
+                f,
+                "This interface requires ADLX {}.{} or newer",
+                required.major, required.minor
+            );
+        }
+        f.write_str(match self.result {
             ffi::ADLX_RESULT_ADLX_OK => "This result indicates success",
             ffi::ADLX_RESULT_ADLX_ALREADY_ENABLED => "The asked action is already enabled",
             ffi::ADLX_RESULT_ADLX_ALREADY_INITIALIZED => {
@@ -38,6 +50,9 @@ impl fmt::Display for Error {
                 "This result indicates a failure due to an operation currently in progress"
             }
             ffi::ADLX_RESULT_ADLX_GPU_INACTIVE => "The GPU is inactive",
+            ffi::ADLX_RESULT_ADLX_GPU_IN_USE => "The GPU is in use",
+            ffi::ADLX_RESULT_ADLX_TIMEOUT_OPERATION => "The operation timed out",
+            ffi::ADLX_RESULT_ADLX_NOT_ACTIVE => "The feature is not active",
 
             x => return write!(f, "Unknown ADLX_RESULT `{x}`"),
         })
@@ -53,10 +68,26 @@ impl fmt::Debug for Error {
 impl std::error::Error for Error {}
 
 impl Error {
+    pub(crate) fn new(result: ffi::ADLX_RESULT) -> Self {
+        Self {
+            result,
+            required_version: None,
+        }
+    }
+
+    /// An [`ffi::ADLX_RESULT_ADLX_BAD_VER`] error for an interface that the loaded runtime is too
+    /// old for.
+    pub(crate) fn requires_version(required: AdlxVersion) -> Self {
+        Self {
+            result: ffi::ADLX_RESULT_ADLX_BAD_VER,
+            required_version: Some(required),
+        }
+    }
+
     pub fn from_result(result: ffi::ADLX_RESULT) -> Result<(), Self> {
         match result {
             ffi::ADLX_RESULT_ADLX_OK => Ok(()),
-            x => Err(Self(x)),
+            x => Err(Self::new(x)),
         }
     }
 
@@ -66,4 +97,15 @@ impl Error {
     ) -> Result<T, Self> {
         Self::from_result(result).map(|()| unsafe { ret.assume_init() })
     }
+
+    /// The raw [`ffi::ADLX_RESULT`] code carried by this error.
+    pub fn adlx_result(&self) -> ffi::ADLX_RESULT {
+        self.result
+    }
+
+    /// The ADLX version required by an interface that was not queried because the loaded runtime
+    /// is older, see [`Interface::MIN_VERSION`][crate::Interface::MIN_VERSION].
+    pub fn required_version(&self) -> Option<AdlxVersion> {
+        self.required_version
+    }
 }
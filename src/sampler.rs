@@ -0,0 +1,69 @@
+//! Live polling of [`PerformanceMonitoringServices`] into owned samples.
+
+use super::{
+    fps::FpsSample, gpu::Gpu, gpu_metrics::GpuMetricsSample,
+    performance_monitoring_services::PerformanceMonitoringServices, result::Result,
+};
+
+/// Collects [`GpuMetricsSample`]s for a set of GPUs and [`FpsSample`]s every time
+/// [`MetricsSampler::sample()`] is called.
+///
+/// Samples that repeat the time stamp of the previous sample are dropped, so polling faster than
+/// [`PerformanceMonitoringServices::sampling_interval()`] does not produce duplicates.
+#[derive(Debug)]
+pub struct MetricsSampler {
+    services: PerformanceMonitoringServices,
+    gpus: Vec<Gpu>,
+    gpu_metrics: Vec<Vec<GpuMetricsSample>>,
+    fps: Vec<FpsSample>,
+}
+
+impl MetricsSampler {
+    pub fn new(services: PerformanceMonitoringServices, gpus: Vec<Gpu>) -> Self {
+        Self {
+            services,
+            gpu_metrics: vec![vec![]; gpus.len()],
+            gpus,
+            fps: vec![],
+        }
+    }
+
+    pub fn gpus(&self) -> &[Gpu] {
+        &self.gpus
+    }
+
+    /// Retrieves the current metrics of every GPU and the current FPS.
+    ///
+    /// FPS are only available while a 3D application is running, failing to retrieve them is not
+    /// an error.
+    pub fn sample(&mut self) -> Result<()> {
+        for (gpu, samples) in self.gpus.iter().zip(&mut self.gpu_metrics) {
+            let sample = self.services.current_gpu_metrics(gpu)?.sample()?;
+            if samples.last().map(|s| s.time_stamp) != Some(sample.time_stamp) {
+                samples.push(sample);
+            }
+        }
+        if let Ok(sample) = self.services.current_fps().and_then(|fps| fps.sample()) {
+            if self.fps.last().map(|s| s.time_stamp) != Some(sample.time_stamp) {
+                self.fps.push(sample);
+            }
+        }
+        Ok(())
+    }
+
+    /// Samples collected so far for the GPU at `index` in [`Self::gpus()`]
+    pub fn gpu_metrics(&self, index: usize) -> &[GpuMetricsSample] {
+        &self.gpu_metrics[index]
+    }
+
+    /// FPS samples collected so far
+    pub fn fps(&self) -> &[FpsSample] {
+        &self.fps
+    }
+
+    /// Removes all collected samples, for example after they have been exported.
+    pub fn clear(&mut self) {
+        self.gpu_metrics.iter_mut().for_each(Vec::clear);
+        self.fps.clear();
+    }
+}
@@ -0,0 +1,704 @@
+//! Recording of every value the wrappers in this crate return, to a versioned file that can be
+//! served back through the same [`System`] API with [`AdlxHelper::replay()`].
+//!
+//! Every getter is recorded as a [`Recorded`] value, which keeps the [`ffi::ADLX_RESULT`] of calls
+//! that failed so that replays reproduce unsupported features and driver errors faithfully.
+
+use std::{
+    ffi::{CStr, CString},
+    fmt,
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    ops::RangeInclusive,
+    path::Path,
+};
+
+use anyhow::{ensure, Context};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    application::Application,
+    ffi,
+    fps::{Fps, FpsList},
+    gpu::{Gpu, Gpu1, Gpu2},
+    gpu_metrics::{GpuMetrics, GpuMetricsList, GpuMetricsSupport},
+    helper::AdlxHelper,
+    interface::Interface,
+    performance_monitoring_services::PerformanceMonitoringServices,
+    result::Result,
+    system::System,
+    three_d_settings_services::ThreeDSettingsServices,
+    DisplayServices,
+};
+
+/// Version of the [`Session`] file format written by this crate. Files with a different version
+/// are rejected by [`Session::from_reader()`].
+pub const SESSION_FORMAT_VERSION: u32 = 1;
+
+/// The outcome of a single recorded ADLX call: the returned value or the raw [`ffi::ADLX_RESULT`]
+/// error code.
+pub type Recorded<T> = std::result::Result<T, ffi::ADLX_RESULT>;
+
+fn record<T>(result: Result<T>) -> Recorded<T> {
+    result.map_err(|e| e.adlx_result())
+}
+
+/// A string returned by ADLX, kept NUL-terminated so that it can be handed out again by a replay.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Text(CString);
+
+impl Text {
+    pub fn as_str(&self) -> &str {
+        // Only constructed from `&str`
+        self.0.to_str().unwrap()
+    }
+
+    pub fn as_c_str(&self) -> &CStr {
+        &self.0
+    }
+}
+
+impl From<&str> for Text {
+    /// Truncates `value` at the first NUL character, like ADLX would.
+    fn from(value: &str) -> Self {
+        let value = value.split('\0').next().unwrap();
+        Self(CString::new(value).unwrap())
+    }
+}
+
+impl fmt::Debug for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Text {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        CString::new(value)
+            .map(Self)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serialisable counterpart of [`ffi::ADLX_IntRange`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntRange {
+    pub min: i32,
+    pub max: i32,
+    pub step: i32,
+}
+
+impl From<ffi::ADLX_IntRange> for IntRange {
+    fn from(range: ffi::ADLX_IntRange) -> Self {
+        Self {
+            min: range.minValue,
+            max: range.maxValue,
+            step: range.step,
+        }
+    }
+}
+
+impl From<IntRange> for ffi::ADLX_IntRange {
+    fn from(range: IntRange) -> Self {
+        Self {
+            minValue: range.min,
+            maxValue: range.max,
+            step: range.step,
+        }
+    }
+}
+
+/// A recorded ADLX session, see the [module documentation][self].
+///
+/// Per-GPU records in [`PerformanceMonitoringRecord::gpus`] and [`Self::three_d_settings`] are
+/// stored in the same order as [`Self::gpus`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub format_version: u32,
+    /// [`AdlxHelper::full_version()`] of the recorded runtime
+    pub full_version: u64,
+    /// [`AdlxHelper::version()`] of the recorded runtime
+    pub version: Text,
+    pub hybrid_graphics_type: Recorded<ffi::ADLX_HG_TYPE>,
+    pub gpus: Recorded<Vec<GpuRecord>>,
+    pub displays: Recorded<Vec<DisplayRecord>>,
+    pub performance_monitoring: Recorded<PerformanceMonitoringRecord>,
+    pub three_d_settings: Recorded<Vec<ThreeDSettingsRecord>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GpuRecord {
+    pub vendor_id: Recorded<Text>,
+    pub asic_family_type: Recorded<ffi::ADLX_ASIC_FAMILY_TYPE>,
+    pub type_: Recorded<ffi::ADLX_GPU_TYPE>,
+    pub is_external: Recorded<bool>,
+    pub name: Recorded<Text>,
+    pub driver_path: Recorded<Text>,
+    pub pnp_string: Recorded<Text>,
+    pub has_desktops: Recorded<bool>,
+    pub total_vram: Recorded<u32>,
+    pub vram_type: Recorded<Text>,
+    pub bios_info: Recorded<BiosInfoRecord>,
+    pub device_id: Recorded<Text>,
+    pub revision_id: Recorded<Text>,
+    pub sub_system_id: Recorded<Text>,
+    pub sub_system_vendor_id: Recorded<Text>,
+    pub unique_id: Recorded<i32>,
+    /// Result of casting to [`Gpu1`], and the values it returned
+    pub gpu1: Recorded<Gpu1Record>,
+    /// Result of casting to [`Gpu2`], and the values it returned
+    pub gpu2: Recorded<Gpu2Record>,
+}
+
+/// The strings returned by [`Gpu::bios_info()`], before parsing
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BiosInfoRecord {
+    pub part_number: Text,
+    pub version: Text,
+    pub date: Text,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Gpu1Record {
+    pub pci_bus_type: Recorded<ffi::ADLX_PCI_BUS_TYPE>,
+    pub pci_bus_lane_width: Recorded<u32>,
+    pub multi_gpu_mode: Recorded<ffi::ADLX_MGPU_MODE>,
+    pub product_name: Recorded<Text>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Gpu2Record {
+    pub is_power_off: Recorded<bool>,
+    pub is_supported_application_list: Recorded<bool>,
+    /// `(year, month, day)`
+    pub amd_software_release_date: Recorded<(u32, u32, u32)>,
+    pub amd_software_edition: Recorded<Text>,
+    pub amd_software_version: Recorded<Text>,
+    pub driver_version: Recorded<Text>,
+    pub amd_windows_driver_version: Recorded<Text>,
+    /// `(low_part, high_part)` of the [`ffi::ADLX_LUID`]
+    pub luid: Recorded<(u32, i32)>,
+    pub applications: Recorded<Vec<ApplicationRecord>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationRecord {
+    pub process_id: Recorded<u32>,
+    pub name: Recorded<Text>,
+    pub full_path: Recorded<Text>,
+    pub gpu_dependency_type: Recorded<ffi::ADLX_APP_GPU_DEPENDENCY>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DisplayRecord {
+    /// Index into [`Session::gpus`] of the GPU driving this display
+    pub gpu: Recorded<usize>,
+    pub free_sync: Recorded<FreeSyncRecord>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FreeSyncRecord {
+    pub is_supported: Recorded<bool>,
+    pub is_enabled: Recorded<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThreeDSettingsRecord {
+    pub wait_for_vertical_refresh: Recorded<WaitForVerticalRefreshRecord>,
+    pub chill: Recorded<ChillRecord>,
+    pub frame_rate_target_control: Recorded<FrameRateTargetControlRecord>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WaitForVerticalRefreshRecord {
+    pub is_supported: Recorded<bool>,
+    pub is_enabled: Recorded<bool>,
+    pub mode: Recorded<ffi::ADLX_WAIT_FOR_VERTICAL_REFRESH_MODE>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChillRecord {
+    pub is_supported: Recorded<bool>,
+    pub is_enabled: Recorded<bool>,
+    pub fps_range: Recorded<IntRange>,
+    pub min_fps: Recorded<i32>,
+    pub max_fps: Recorded<i32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FrameRateTargetControlRecord {
+    pub is_supported: Recorded<bool>,
+    pub is_enabled: Recorded<bool>,
+    pub fps_range: Recorded<IntRange>,
+    pub fps: Recorded<i32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceMonitoringRecord {
+    pub sampling_interval_range: Recorded<IntRange>,
+    pub sampling_interval: Recorded<i32>,
+    pub max_performance_metrics_history_range: Recorded<IntRange>,
+    pub max_performance_metrics_history_size: Recorded<i32>,
+    pub current_performance_metrics_history_size_in_sec: Recorded<i32>,
+    pub gpus: Vec<GpuPerformanceRecord>,
+    /// Every [`PerformanceMonitoringServices::current_fps()`] sample, in order. A replay cycles
+    /// through these.
+    pub current_fps: Vec<Recorded<FpsRecord>>,
+    /// The last [`PerformanceMonitoringServices::fps_history()`], which a replay returns
+    /// regardless of the requested time span.
+    pub fps_history: Recorded<Vec<FpsRecord>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GpuPerformanceRecord {
+    pub supported_gpu_metrics: Recorded<GpuMetricsSupportRecord>,
+    /// Every [`PerformanceMonitoringServices::current_gpu_metrics()`] sample, in order. A replay
+    /// cycles through these.
+    pub current_gpu_metrics: Vec<Recorded<GpuMetricsRecord>>,
+    /// The last [`PerformanceMonitoringServices::gpu_metrics_history()`], which a replay returns
+    /// regardless of the requested time span.
+    pub gpu_metrics_history: Recorded<Vec<GpuMetricsRecord>>,
+}
+
+/// Support and range of a single metric in [`GpuMetricsSupportRecord`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MetricSupportRecord {
+    pub is_supported: Recorded<bool>,
+    pub range: Recorded<RangeInclusive<i32>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GpuMetricsSupportRecord {
+    pub usage: MetricSupportRecord,
+    pub clock_speed: MetricSupportRecord,
+    pub vram_clock_speed: MetricSupportRecord,
+    pub temperature: MetricSupportRecord,
+    pub hotspot_temperature: MetricSupportRecord,
+    pub power: MetricSupportRecord,
+    pub total_board_power: MetricSupportRecord,
+    pub fan_speed: MetricSupportRecord,
+    pub vram: MetricSupportRecord,
+    pub voltage: MetricSupportRecord,
+    pub intake_temperature: MetricSupportRecord,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GpuMetricsRecord {
+    pub time_stamp: Recorded<i64>,
+    pub usage: Recorded<f64>,
+    pub clock_speed: Recorded<i32>,
+    pub vram_clock_speed: Recorded<i32>,
+    pub power: Recorded<f64>,
+    pub total_board_power: Recorded<f64>,
+    pub voltage: Recorded<i32>,
+    pub vram: Recorded<i32>,
+    pub fan_speed: Recorded<i32>,
+    pub temperature: Recorded<f64>,
+    pub intake_temperature: Recorded<f64>,
+    pub hotspot_temperature: Recorded<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FpsRecord {
+    pub time_stamp: Recorded<i64>,
+    pub fps: Recorded<i32>,
+}
+
+impl GpuRecord {
+    pub fn capture(gpu: &Gpu) -> Self {
+        Self {
+            vendor_id: record(gpu.vendor_id().map(Text::from)),
+            asic_family_type: record(gpu.asic_family_type().map(Into::into)),
+            type_: record(gpu.type_().map(Into::into)),
+            is_external: record(gpu.is_external()),
+            name: record(gpu.name().map(Text::from)),
+            driver_path: record(gpu.driver_path().map(Text::from)),
+            pnp_string: record(gpu.pnp_string().map(Text::from)),
+            has_desktops: record(gpu.has_desktops()),
+            total_vram: record(gpu.total_vram()),
+            vram_type: record(gpu.vram_type().map(Text::from)),
+            bios_info: record(gpu.bios_info()).map(|info| BiosInfoRecord {
+                part_number: Text::from(info.part_number.as_str()),
+                version: Text::from(info.version.as_str()),
+                date: Text::from(info.raw_date.as_str()),
+            }),
+            device_id: record(gpu.device_id().map(Text::from)),
+            revision_id: record(gpu.revision_id().map(Text::from)),
+            sub_system_id: record(gpu.sub_system_id().map(Text::from)),
+            sub_system_vendor_id: record(gpu.sub_system_vendor_id().map(Text::from)),
+            unique_id: record(gpu.unique_id()),
+            gpu1: record(gpu.cast::<Gpu1>()).map(|gpu1| Gpu1Record {
+                pci_bus_type: record(gpu1.pci_bus_type().map(Into::into)),
+                pci_bus_lane_width: record(gpu1.pci_bus_lane_width()),
+                multi_gpu_mode: record(gpu1.multi_gpu_mode().map(Into::into)),
+                product_name: record(gpu1.product_name().map(Text::from)),
+            }),
+            gpu2: record(gpu.cast::<Gpu2>()).map(|gpu2| Gpu2Record {
+                is_power_off: record(gpu2.is_power_off()),
+                is_supported_application_list: record(gpu2.is_supported_application_list()),
+                amd_software_release_date: record(gpu2.amd_software_release_date())
+                    .map(|date| (date.year.into(), date.month.into(), date.day.into())),
+                amd_software_edition: record(gpu2.amd_software_edition().map(Text::from)),
+                amd_software_version: record(
+                    gpu2.amd_software_version().map(|v| Text::from(v.as_str())),
+                ),
+                driver_version: record(gpu2.driver_version().map(|v| Text::from(v.as_str()))),
+                amd_windows_driver_version: record(
+                    gpu2.amd_windows_driver_version()
+                        .map(|v| Text::from(v.as_str())),
+                ),
+                luid: record(gpu2.luid()).map(|luid| (luid.lowPart as u32, luid.highPart as i32)),
+                applications: record(gpu2.get_applications()).map(|list| {
+                    list.iter()
+                        .map(|application| ApplicationRecord::capture(&application))
+                        .collect()
+                }),
+            }),
+        }
+    }
+}
+
+impl ThreeDSettingsRecord {
+    pub fn capture(services: &ThreeDSettingsServices, gpu: &Gpu) -> Self {
+        Self {
+            wait_for_vertical_refresh: record(services.get_wait_for_vertical_refresh(gpu)).map(
+                |vsync| WaitForVerticalRefreshRecord {
+                    is_supported: record(vsync.is_supported()),
+                    is_enabled: record(vsync.is_enabled()),
+                    mode: record(vsync.get_mode().map(Into::into)),
+                },
+            ),
+            chill: record(services.get_chill(gpu)).map(|chill| ChillRecord {
+                is_supported: record(chill.is_supported()),
+                is_enabled: record(chill.is_enabled()),
+                fps_range: record(chill.get_fps_range()).map(IntRange::from),
+                min_fps: record(chill.get_min_fps()),
+                max_fps: record(chill.get_max_fps()),
+            }),
+            frame_rate_target_control: record(services.get_frame_rate_target_control(gpu)).map(
+                |frtc| FrameRateTargetControlRecord {
+                    is_supported: record(frtc.is_supported()),
+                    is_enabled: record(frtc.is_enabled()),
+                    fps_range: record(frtc.get_fps_range()).map(IntRange::from),
+                    fps: record(frtc.get_fps()),
+                },
+            ),
+        }
+    }
+}
+
+impl GpuMetricsSupportRecord {
+    pub fn capture(support: &GpuMetricsSupport) -> Self {
+        fn metric(
+            is_supported: Result<bool>,
+            range: Result<RangeInclusive<i32>>,
+        ) -> MetricSupportRecord {
+            MetricSupportRecord {
+                is_supported: record(is_supported),
+                range: record(range),
+            }
+        }
+
+        Self {
+            usage: metric(support.is_supported_gpu_usage(), support.gpu_usage_range()),
+            clock_speed: metric(
+                support.is_supported_gpu_clock_speed(),
+                support.gpu_clock_speed_range(),
+            ),
+            vram_clock_speed: metric(
+                support.is_supported_gpu_vram_clock_speed(),
+                support.gpu_vram_clock_speed_range(),
+            ),
+            temperature: metric(
+                support.is_supported_gpu_temperature(),
+                support.gpu_temperature_range(),
+            ),
+            hotspot_temperature: metric(
+                support.is_supported_gpu_hotspot_temperature(),
+                support.gpu_hotspot_temperature_range(),
+            ),
+            power: metric(support.is_supported_gpu_power(), support.gpu_power_range()),
+            total_board_power: metric(
+                support.is_supported_gpu_total_board_power(),
+                support.gpu_total_board_power_range(),
+            ),
+            fan_speed: metric(
+                support.is_supported_gpu_fan_speed(),
+                support.gpu_fan_speed_range(),
+            ),
+            vram: metric(support.is_supported_gpu_vram(), support.gpu_vran_range()),
+            voltage: metric(
+                support.is_supported_gpu_voltage(),
+                support.gpu_voltage_range(),
+            ),
+            intake_temperature: metric(
+                support.is_supported_gpu_intake_temperature(),
+                support.gpu_intake_temperature_range(),
+            ),
+        }
+    }
+}
+
+impl GpuMetricsRecord {
+    pub fn capture(metrics: &GpuMetrics) -> Self {
+        Self {
+            time_stamp: record(metrics.time_stamp()),
+            usage: record(metrics.usage()),
+            clock_speed: record(metrics.clock_speed()),
+            vram_clock_speed: record(metrics.vram_clock_speed()),
+            power: record(metrics.power()),
+            total_board_power: record(metrics.total_board_power()),
+            voltage: record(metrics.voltage()),
+            vram: record(metrics.vram()),
+            fan_speed: record(metrics.fan_speed()),
+            temperature: record(metrics.temperature()),
+            intake_temperature: record(metrics.intake_temperature()),
+            hotspot_temperature: record(metrics.hotspot_temperature()),
+        }
+    }
+
+    pub fn capture_list(list: &GpuMetricsList) -> Recorded<Vec<Self>> {
+        (0..list.size())
+            .map(|i| record(list.at(i)).map(|metrics| Self::capture(&metrics)))
+            .collect()
+    }
+}
+
+impl ApplicationRecord {
+    pub fn capture(application: &Application) -> Self {
+        Self {
+            process_id: record(application.process_id()),
+            name: record(application.name()).map(|name| Text::from(name.as_str())),
+            full_path: record(application.full_path()).map(|path| Text::from(path.as_str())),
+            gpu_dependency_type: record(application.gpu_dependency_type().map(Into::into)),
+        }
+    }
+}
+
+impl FpsRecord {
+    pub fn capture(fps: &Fps) -> Self {
+        Self {
+            time_stamp: record(fps.time_stamp()),
+            fps: record(fps.fps()),
+        }
+    }
+
+    pub fn capture_list(list: &FpsList) -> Recorded<Vec<Self>> {
+        (0..list.size())
+            .map(|i| record(list.at(i)).map(|fps| Self::capture(&fps)))
+            .collect()
+    }
+}
+
+impl Session {
+    /// Serialises this session as JSON into `writer`.
+    pub fn to_writer(&self, writer: impl Write) -> anyhow::Result<()> {
+        serde_json::to_writer_pretty(writer, self).context("Failed to serialize ADLX session")
+    }
+
+    /// Deserialises a session written by [`Self::to_writer()`], rejecting files written in
+    /// another [`SESSION_FORMAT_VERSION`].
+    pub fn from_reader(reader: impl Read) -> anyhow::Result<Self> {
+        let value: serde_json::Value =
+            serde_json::from_reader(reader).context("Failed to parse ADLX session")?;
+        let format_version = value
+            .get("format_version")
+            .and_then(serde_json::Value::as_u64)
+            .context("ADLX session has no `format_version`")?;
+        ensure!(
+            format_version == u64::from(SESSION_FORMAT_VERSION),
+            "ADLX session has format version {format_version}, but only version \
+             {SESSION_FORMAT_VERSION} is supported"
+        );
+        serde_json::from_value(value).context("Failed to deserialize ADLX session")
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let file =
+            File::create(path).with_context(|| format!("Failed to create `{}`", path.display()))?;
+        let mut writer = BufWriter::new(file);
+        self.to_writer(&mut writer)?;
+        writer
+            .flush()
+            .with_context(|| format!("Failed to write `{}`", path.display()))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let file =
+            File::open(path).with_context(|| format!("Failed to open `{}`", path.display()))?;
+        Self::from_reader(BufReader::new(file))
+    }
+}
+
+/// Records a [`Session`] from a live [`AdlxHelper`].
+///
+/// Static properties are captured by [`Recorder::new()`], metrics are appended every time
+/// [`Recorder::sample_metrics()`] or [`Recorder::record_metrics_history()`] is called.
+pub struct Recorder<'a> {
+    system: &'a System,
+    gpus: Vec<Gpu>,
+    performance_monitoring_services: Option<PerformanceMonitoringServices>,
+    session: Session,
+}
+
+impl<'a> Recorder<'a> {
+    pub fn new(helper: &'a AdlxHelper) -> Self {
+        let system = helper.system();
+
+        let gpu_list = record(system.gpus());
+        let gpus = gpu_list
+            .as_ref()
+            .map(|list| list.iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let gpu_records = gpu_list.map(|_| gpus.iter().map(GpuRecord::capture).collect::<Vec<_>>());
+
+        let displays = record(system.get_displays_services())
+            .and_then(|services| Self::capture_displays(&services, &gpu_records));
+
+        let three_d_settings = record(system.get_3d_settings_services()).map(|services| {
+            gpus.iter()
+                .map(|gpu| ThreeDSettingsRecord::capture(&services, gpu))
+                .collect()
+        });
+
+        let performance_monitoring_services = record(system.performance_monitoring_services());
+        let performance_monitoring = performance_monitoring_services
+            .as_ref()
+            .map(|services| PerformanceMonitoringRecord {
+                sampling_interval_range: record(services.sampling_interval_range())
+                    .map(IntRange::from),
+                sampling_interval: record(services.sampling_interval()),
+                max_performance_metrics_history_range: record(
+                    services.max_performance_metrics_history_range(),
+                )
+                .map(IntRange::from),
+                max_performance_metrics_history_size: record(
+                    services.max_performance_metrics_history_size(),
+                ),
+                current_performance_metrics_history_size_in_sec: record(
+                    services.current_performance_metrics_history_size_in_sec(),
+                ),
+                gpus: gpus
+                    .iter()
+                    .map(|gpu| GpuPerformanceRecord {
+                        supported_gpu_metrics: record(services.supported_gpu_metrics(gpu))
+                            .map(|support| GpuMetricsSupportRecord::capture(&support)),
+                        current_gpu_metrics: vec![],
+                        gpu_metrics_history: Err(ffi::ADLX_RESULT_ADLX_NOT_FOUND),
+                    })
+                    .collect(),
+                current_fps: vec![],
+                fps_history: Err(ffi::ADLX_RESULT_ADLX_NOT_FOUND),
+            })
+            .map_err(|e| *e);
+
+        let session = Session {
+            format_version: SESSION_FORMAT_VERSION,
+            full_version: helper.full_version(),
+            version: Text::from(helper.version()),
+            hybrid_graphics_type: record(system.hybrid_graphics_type().map(Into::into)),
+            gpus: gpu_records,
+            displays,
+            performance_monitoring,
+            three_d_settings,
+        };
+
+        Self {
+            system,
+            gpus,
+            performance_monitoring_services: performance_monitoring_services.ok(),
+            session,
+        }
+    }
+
+    fn capture_displays(
+        services: &DisplayServices,
+        gpus: &Recorded<Vec<GpuRecord>>,
+    ) -> Recorded<Vec<DisplayRecord>> {
+        let displays = record(services.get_displays())?;
+        (0..displays.size())
+            .map(|i| {
+                let display = record(displays.at(i))?;
+                let gpu = record(display.get_gpu()).and_then(|gpu| {
+                    let unique_id = record(gpu.unique_id());
+                    gpus.as_ref()
+                        .ok()
+                        .and_then(|gpus| gpus.iter().position(|g| g.unique_id == unique_id))
+                        .ok_or(ffi::ADLX_RESULT_ADLX_NOT_FOUND)
+                });
+                let free_sync =
+                    record(services.get_free_sync(&display)).map(|free_sync| FreeSyncRecord {
+                        is_supported: record(free_sync.is_supported()),
+                        is_enabled: record(free_sync.is_enabled()),
+                    });
+                Ok(DisplayRecord { gpu, free_sync })
+            })
+            .collect()
+    }
+
+    /// The [`System`] this recorder captures from
+    pub fn system(&self) -> &'a System {
+        self.system
+    }
+
+    /// Appends one [`PerformanceMonitoringServices::current_gpu_metrics()`] sample for every GPU,
+    /// and one [`PerformanceMonitoringServices::current_fps()`] sample.
+    pub fn sample_metrics(&mut self) {
+        let (Some(services), Ok(record)) = (
+            &self.performance_monitoring_services,
+            &mut self.session.performance_monitoring,
+        ) else {
+            return;
+        };
+        for (gpu, gpu_record) in self.gpus.iter().zip(&mut record.gpus) {
+            gpu_record.current_gpu_metrics.push(
+                self::record(services.current_gpu_metrics(gpu))
+                    .map(|metrics| GpuMetricsRecord::capture(&metrics)),
+            );
+        }
+        record
+            .current_fps
+            .push(self::record(services.current_fps()).map(|fps| FpsRecord::capture(&fps)));
+    }
+
+    /// Records [`PerformanceMonitoringServices::gpu_metrics_history()`] for every GPU and
+    /// [`PerformanceMonitoringServices::fps_history()`], replacing any previously recorded history.
+    pub fn record_metrics_history(&mut self, start_in_ms: i32, stop_in_ms: i32) {
+        let (Some(services), Ok(record)) = (
+            &self.performance_monitoring_services,
+            &mut self.session.performance_monitoring,
+        ) else {
+            return;
+        };
+        for (gpu, gpu_record) in self.gpus.iter().zip(&mut record.gpus) {
+            gpu_record.gpu_metrics_history =
+                self::record(services.gpu_metrics_history(gpu, start_in_ms, stop_in_ms))
+                    .and_then(|list| GpuMetricsRecord::capture_list(&list));
+        }
+        record.fps_history = self::record(services.fps_history(start_in_ms, stop_in_ms))
+            .and_then(|list| FpsRecord::capture_list(&list));
+    }
+
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    pub fn finish(self) -> Session {
+        self.session
+    }
+}
@@ -1,25 +1,25 @@
-use std::mem::MaybeUninit;
+use std::{mem::MaybeUninit, ops::Deref};
 
 use super::{
+    application::GpuAppsListChangedHandling,
+    enums::HybridGraphicsType,
     ffi,
     gpu_list::GpuList,
-    interface::{Interface, InterfaceImpl},
+    interface::{inherits, unknown_interface_as_none, wide_iid, Interface, InterfaceImpl},
     performance_monitoring_services::PerformanceMonitoringServices,
     result::{Error, Result},
+    version::{check_interface, thread_runtime_version, AdlxVersion},
     DisplayServices, ThreeDSettingsServices,
 };
 
 /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system/>
 ///
 /// [`System`] is a singleton interface.  It looks similar to but is not compatible with
-/// [`Interface`].
+/// [`Interface`]. It keeps the runtime version of its [`AdlxHelper`][crate::AdlxHelper], to check
+/// the interfaces that it is cast to.
 #[derive(Debug)]
-#[repr(transparent)]
 #[doc(alias = "IADLXSystem")]
-pub struct System(*mut ffi::IADLXSystem);
-
-unsafe impl Send for System {}
-unsafe impl Sync for System {}
+pub struct System(*mut ffi::IADLXSystem, AdlxVersion);
 
 impl System {
     /// Creates an [`Interface`] by taking ownership of the `raw` COM/ADLX interface pointer.
@@ -29,8 +29,11 @@ impl System {
     /// The `raw` pointer must be owned by the caller and represent a valid [`ffi::IADLXSystem`]
     /// pointer. In other words, it must point to a vtable beginning with the
     /// [`ffi::IADLXSystemVtbl`] function pointers.
-    pub(crate) unsafe fn from_raw(raw: *mut ffi::IADLXSystem) -> Self {
-        Self(raw)
+    pub(crate) unsafe fn from_raw(
+        raw: *mut ffi::IADLXSystem,
+        runtime_version: AdlxVersion,
+    ) -> Self {
+        Self(raw, runtime_version)
     }
 
     fn vtable(&self) -> &ffi::IADLXSystemVtbl {
@@ -39,11 +42,12 @@ impl System {
 
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system__hybrid_graphics_type/>
     #[doc(alias = "GetHybridGraphicsType")]
-    pub fn hybrid_graphics_type(&self) -> Result<ffi::ADLX_HG_TYPE> {
+    pub fn hybrid_graphics_type(&self) -> Result<HybridGraphicsType> {
         let mut type_ = MaybeUninit::uninit();
         let result =
             unsafe { (self.vtable().GetHybridGraphicsType.unwrap())(self.0, type_.as_mut_ptr()) };
         Error::from_result_with_assume_init_on_success(result, type_)
+            .map(HybridGraphicsType::from_raw)
     }
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system__get_g_p_us/>
     #[doc(alias = "GetGPUs")]
@@ -56,11 +60,8 @@ impl System {
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system__query_interface/>
     #[doc(alias = "QueryInterface")]
     pub fn cast<I: Interface>(&self) -> Result<I> {
-        let interface_name = I::IID
-            // TODO: Use windows-rs' helpers to create static wchars?
-            .encode_utf16()
-            .chain(std::iter::once(0u16))
-            .collect::<Vec<_>>();
+        check_interface::<I>(Some(self.1))?;
+        let interface_name = wide_iid::<I>();
         let mut interface = std::mem::MaybeUninit::uninit();
         let result = unsafe {
             (self.vtable().QueryInterface.unwrap())(
@@ -71,6 +72,11 @@ impl System {
         };
         Error::from_result(result).map(|()| unsafe { I::from_raw(interface.assume_init().cast()) })
     }
+    /// Like [`Self::cast()`], but returns [`None`] when ADLX does not implement `I`.
+    #[doc(alias = "QueryInterface")]
+    pub fn try_cast<I: Interface>(&self) -> Result<Option<I>> {
+        unknown_interface_as_none(self.cast())
+    }
     /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system__get_displays_services/>
     #[doc(alias = "GetDisplaysServices")]
     pub fn get_displays_services(&self) -> Result<DisplayServices> {
@@ -155,6 +161,7 @@ unsafe impl Interface for System1 {
     type Impl = ffi::IADLXSystem1;
     type Vtable = ffi::IADLXSystem1Vtbl;
     const IID: &'static str = "IADLXSystem1";
+    const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 1);
 }
 
 impl System1 {
@@ -169,3 +176,44 @@ impl System1 {
     //     Ok(PowerTuningServices::from_raw(ret))
     // }
 }
+
+/// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system2/>
+#[derive(Clone, Debug)]
+#[repr(transparent)]
+#[doc(alias = "IADLXSystem2")]
+pub struct System2(System1);
+
+unsafe impl Interface for System2 {
+    type Impl = ffi::IADLXSystem2;
+    type Vtable = ffi::IADLXSystem2Vtbl;
+    const IID: &'static str = "IADLXSystem2";
+    const MIN_VERSION: AdlxVersion = AdlxVersion::new(1, 3);
+}
+
+inherits!(System2: System1);
+
+impl Deref for System2 {
+    type Target = System1;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl System2 {
+    /// <https://gpuopen.com/manuals/adlx/adlx-_d_o_x__i_a_d_l_x_system2__get_g_p_u_apps_list_changed_handling/>
+    #[doc(alias = "GetGPUAppsListChangedHandling")]
+    pub fn get_gpu_apps_list_changed_handling(&self) -> Result<GpuAppsListChangedHandling> {
+        check_interface::<GpuAppsListChangedHandling>(thread_runtime_version())?;
+        let mut handling = MaybeUninit::uninit();
+        let result = unsafe {
+            (self.vtable().GetGPUAppsListChangedHandling.unwrap())(
+                self.as_raw(),
+                handling.as_mut_ptr(),
+            )
+        };
+
+        Error::from_result_with_assume_init_on_success(result, handling)
+            .map(|handling| unsafe { GpuAppsListChangedHandling::from_raw(handling) })
+    }
+}
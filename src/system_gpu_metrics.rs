@@ -0,0 +1,163 @@
+//! A view of the current metrics of every GPU in the system at once, for hybrid graphics and
+//! multi-GPU setups.
+
+use super::{
+    enums::{GpuType, HybridGraphicsType, MultiGpuMode},
+    gpu::{Gpu, Gpu1},
+    gpu_metrics::GpuMetricsSample,
+    interface::Interface,
+    result::Result,
+    system::System,
+};
+
+/// The current metrics of a single GPU in [`SystemGpuMetrics`].
+#[derive(Clone, Debug)]
+pub struct GpuMetricsEntry {
+    pub gpu: Gpu,
+    /// [`Gpu::type_()`]
+    pub gpu_type: Result<GpuType>,
+    /// [`Gpu1::multi_gpu_mode()`]
+    pub multi_gpu_mode: Result<MultiGpuMode>,
+    /// [`GpuMetrics::sample()`] of [`PerformanceMonitoringServices::current_gpu_metrics()`]
+    ///
+    /// [`GpuMetrics::sample()`]: super::GpuMetrics::sample()
+    /// [`PerformanceMonitoringServices::current_gpu_metrics()`]: super::PerformanceMonitoringServices::current_gpu_metrics()
+    pub metrics: Result<GpuMetricsSample>,
+}
+
+impl GpuMetricsEntry {
+    pub fn is_integrated(&self) -> bool {
+        self.gpu_type == Ok(GpuType::Integrated)
+    }
+
+    pub fn is_discrete(&self) -> bool {
+        self.gpu_type == Ok(GpuType::Discrete)
+    }
+}
+
+/// The current metrics of every GPU in [`System::gpus()`], with aggregates over all of them.
+///
+/// Aggregates only include GPUs whose metrics could be retrieved, and are [`None`] if no GPU
+/// reports the metric.
+#[derive(Clone, Debug)]
+pub struct SystemGpuMetrics {
+    /// [`System::hybrid_graphics_type()`]
+    pub hybrid_graphics_type: Result<HybridGraphicsType>,
+    pub gpus: Vec<GpuMetricsEntry>,
+}
+
+impl SystemGpuMetrics {
+    /// Retrieves the current metrics of every GPU in `system`.
+    pub fn sample(system: &System) -> Result<Self> {
+        let services = system.performance_monitoring_services()?;
+        let gpus = system
+            .gpus()?
+            .iter()
+            .map(|gpu| GpuMetricsEntry {
+                gpu_type: gpu.type_(),
+                multi_gpu_mode: gpu.cast::<Gpu1>().and_then(|gpu1| gpu1.multi_gpu_mode()),
+                metrics: services
+                    .current_gpu_metrics(&gpu)
+                    .and_then(|metrics| metrics.sample()),
+                gpu,
+            })
+            .collect();
+        Ok(Self {
+            hybrid_graphics_type: system.hybrid_graphics_type(),
+            gpus,
+        })
+    }
+
+    pub fn integrated(&self) -> impl Iterator<Item = &GpuMetricsEntry> {
+        self.gpus.iter().filter(|entry| entry.is_integrated())
+    }
+
+    pub fn discrete(&self) -> impl Iterator<Item = &GpuMetricsEntry> {
+        self.gpus.iter().filter(|entry| entry.is_discrete())
+    }
+
+    fn metrics(&self) -> impl Iterator<Item = &GpuMetricsSample> {
+        self.gpus
+            .iter()
+            .filter_map(|entry| entry.metrics.as_ref().ok())
+    }
+
+    /// Sum of the total board power of every GPU in W, using the GPU power of GPUs that do not
+    /// report their total board power, like most integrated GPUs.
+    pub fn total_board_power(&self) -> Option<f64> {
+        self.metrics()
+            .filter_map(|m| m.total_board_power.or(m.power))
+            .reduce(|a, b| a + b)
+    }
+
+    /// Highest GPU temperature of all GPUs in °C
+    pub fn max_temperature(&self) -> Option<f64> {
+        self.metrics()
+            .filter_map(|m| m.temperature)
+            .reduce(f64::max)
+    }
+
+    /// Highest GPU hotspot temperature of all GPUs in °C
+    pub fn max_hotspot_temperature(&self) -> Option<f64> {
+        self.metrics()
+            .filter_map(|m| m.hotspot_temperature)
+            .reduce(f64::max)
+    }
+
+    /// Sum of the dedicated VRAM usage of every GPU in MB
+    pub fn total_vram(&self) -> Option<i32> {
+        self.metrics().filter_map(|m| m.vram).reduce(|a, b| a + b)
+    }
+}
+
+impl System {
+    /// Shorthand for [`SystemGpuMetrics::sample()`]
+    pub fn gpu_metrics(&self) -> Result<SystemGpuMetrics> {
+        SystemGpuMetrics::sample(self)
+    }
+}
+
+#[cfg(all(test, feature = "replay"))]
+mod tests {
+    use crate::{ffi, replay::tests::session, AdlxHelper, MultiGpuMode};
+
+    #[test]
+    fn aggregates_every_gpu() {
+        let mut session = session();
+        // Add an integrated GPU that only reports its GPU power
+        let gpus = session.gpus.as_mut().unwrap();
+        let mut apu = gpus[0].clone();
+        apu.type_ = Ok(ffi::ADLX_GPU_TYPE_GPUTYPE_INTEGRATED);
+        apu.unique_id = Ok(0x700);
+        gpus.push(apu);
+        let performance = session.performance_monitoring.as_mut().unwrap();
+        let mut apu = performance.gpus[0].clone();
+        for metrics in apu.current_gpu_metrics.iter_mut().flatten() {
+            metrics.power = Ok(15.0);
+            metrics.temperature = Ok(90.0);
+            metrics.vram = Ok(512);
+        }
+        performance.gpus.push(apu);
+
+        let helper = AdlxHelper::replay(session);
+        let metrics = helper.system().gpu_metrics().unwrap();
+
+        assert_eq!(metrics.gpus.len(), 2);
+        assert_eq!(metrics.discrete().count(), 1);
+        assert_eq!(
+            metrics
+                .integrated()
+                .next()
+                .unwrap()
+                .gpu
+                .unique_id()
+                .unwrap(),
+            0x700
+        );
+        assert_eq!(metrics.gpus[0].multi_gpu_mode, Ok(MultiGpuMode::None));
+        // Neither reports the total board power, so the GPU power is summed
+        assert_eq!(metrics.total_board_power(), Some(120.0 + 15.0));
+        assert_eq!(metrics.max_temperature(), Some(90.0));
+        assert_eq!(metrics.total_vram(), Some(2048 + 512));
+    }
+}
@@ -0,0 +1,302 @@
+//! Detection of why a GPU does not run at its maximum clock speed, from the GPU clock speed,
+//! hotspot temperature, power and usage over a window of samples.
+
+use std::ops::RangeInclusive;
+
+use super::{
+    gpu::Gpu,
+    gpu_metrics::{GpuMetricsSample, GpuMetricsSupport},
+    performance_monitoring_services::PerformanceMonitoringServices,
+    result::Result,
+};
+
+/// Classification of a GPU, or of a single sample, by [`classify_throttling()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ThrottleState {
+    /// Clocks are reduced while the hotspot temperature is close to its maximum
+    ThermalLimited,
+    /// Clocks are reduced while the power draw is close to its maximum
+    PowerLimited,
+    /// Clocks are reduced because the GPU is mostly idle
+    IdleDownclocked,
+    /// Clocks are close to their maximum, or reduced without any of the above reasons
+    Unconstrained,
+}
+
+/// The maxima that samples are compared against, usually from [`GpuMetricsSupport`]. Limits that
+/// are [`None`] are not checked, except for the clock speed which falls back to the highest
+/// observed clock speed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThrottleLimits {
+    /// GPU clock speed range in MHz
+    pub clock_speed: Option<RangeInclusive<i32>>,
+    /// Hotspot temperature range in °C
+    pub hotspot_temperature: Option<RangeInclusive<i32>>,
+    /// GPU power range in W
+    pub power: Option<RangeInclusive<i32>>,
+}
+
+impl ThrottleLimits {
+    /// Reads the ranges of the clock speed, hotspot temperature and power from `support`,
+    /// leaving out those that are not supported.
+    pub fn from_support(support: &GpuMetricsSupport) -> Self {
+        Self {
+            clock_speed: support.gpu_clock_speed_range().ok(),
+            hotspot_temperature: support.gpu_hotspot_temperature_range().ok(),
+            power: support.gpu_power_range().ok(),
+        }
+    }
+}
+
+/// Tunables of [`classify_throttling()`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThrottleThresholds {
+    /// A sample is downclocked when its clock speed is below this fraction of the maximum.
+    /// Defaults to `0.9`.
+    pub clock_ratio: f64,
+    /// A downclocked sample is thermal-limited when its hotspot temperature is within this many
+    /// °C of the maximum. Defaults to `5.0`.
+    pub thermal_margin: f64,
+    /// A downclocked sample is power-limited when its power is above this fraction of the
+    /// maximum. Defaults to `0.95`.
+    pub power_ratio: f64,
+    /// A downclocked sample is idle when its usage is below this percentage, or unknown.
+    /// Defaults to `30.0`.
+    pub idle_usage: f64,
+}
+
+impl Default for ThrottleThresholds {
+    fn default() -> Self {
+        Self {
+            clock_ratio: 0.9,
+            thermal_margin: 5.0,
+            power_ratio: 0.95,
+            idle_usage: 30.0,
+        }
+    }
+}
+
+/// Result of [`classify_throttling()`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThrottleReport {
+    /// The most frequent state among the classified samples. Limits take precedence over
+    /// [`ThrottleState::Unconstrained`] when they are equally frequent.
+    pub state: ThrottleState,
+    pub thermal_limited: usize,
+    pub power_limited: usize,
+    pub idle_downclocked: usize,
+    pub unconstrained: usize,
+    /// Mean clock speed as a fraction of the maximum clock speed
+    pub mean_clock_ratio: f64,
+    /// Highest hotspot temperature in °C
+    pub peak_hotspot_temperature: Option<f64>,
+    /// Highest GPU power in W
+    pub peak_power: Option<f64>,
+}
+
+impl ThrottleReport {
+    /// Number of samples that were classified, which excludes samples without a clock speed.
+    pub fn classified(&self) -> usize {
+        self.thermal_limited + self.power_limited + self.idle_downclocked + self.unconstrained
+    }
+
+    /// Fraction of the classified samples in `state`
+    pub fn fraction(&self, state: ThrottleState) -> f64 {
+        let count = match state {
+            ThrottleState::ThermalLimited => self.thermal_limited,
+            ThrottleState::PowerLimited => self.power_limited,
+            ThrottleState::IdleDownclocked => self.idle_downclocked,
+            ThrottleState::Unconstrained => self.unconstrained,
+        };
+        count as f64 / self.classified().max(1) as f64
+    }
+}
+
+/// Classifies a single sample against `max_clock_speed` in MHz.
+pub fn classify_sample(
+    sample: &GpuMetricsSample,
+    max_clock_speed: f64,
+    limits: &ThrottleLimits,
+    thresholds: &ThrottleThresholds,
+) -> Option<ThrottleState> {
+    let clock_speed = f64::from(sample.clock_speed?);
+    if clock_speed >= max_clock_speed * thresholds.clock_ratio {
+        return Some(ThrottleState::Unconstrained);
+    }
+
+    let hot = match (sample.hotspot_temperature, &limits.hotspot_temperature) {
+        (Some(temperature), Some(range)) => {
+            temperature >= f64::from(*range.end()) - thresholds.thermal_margin
+        }
+        _ => false,
+    };
+    let power_limited = match (sample.power, &limits.power) {
+        (Some(power), Some(range)) => power >= f64::from(*range.end()) * thresholds.power_ratio,
+        _ => false,
+    };
+
+    Some(if hot {
+        ThrottleState::ThermalLimited
+    } else if power_limited {
+        ThrottleState::PowerLimited
+    } else if sample
+        .usage
+        .map_or(true, |usage| usage < thresholds.idle_usage)
+    {
+        ThrottleState::IdleDownclocked
+    } else {
+        ThrottleState::Unconstrained
+    })
+}
+
+/// Classifies every sample in `samples` and summarises them, or returns [`None`] if no sample has
+/// a clock speed.
+pub fn classify_throttling(
+    samples: &[GpuMetricsSample],
+    limits: &ThrottleLimits,
+    thresholds: &ThrottleThresholds,
+) -> Option<ThrottleReport> {
+    let max_clock_speed = limits
+        .clock_speed
+        .as_ref()
+        .map(|range| *range.end())
+        .or_else(|| samples.iter().filter_map(|s| s.clock_speed).max())
+        .filter(|&max| max > 0)
+        .map(f64::from)?;
+
+    let mut report = ThrottleReport {
+        state: ThrottleState::Unconstrained,
+        thermal_limited: 0,
+        power_limited: 0,
+        idle_downclocked: 0,
+        unconstrained: 0,
+        mean_clock_ratio: 0.0,
+        peak_hotspot_temperature: None,
+        peak_power: None,
+    };
+    let mut clock_ratio_sum = 0.0;
+    for sample in samples {
+        let Some(state) = classify_sample(sample, max_clock_speed, limits, thresholds) else {
+            continue;
+        };
+        *match state {
+            ThrottleState::ThermalLimited => &mut report.thermal_limited,
+            ThrottleState::PowerLimited => &mut report.power_limited,
+            ThrottleState::IdleDownclocked => &mut report.idle_downclocked,
+            ThrottleState::Unconstrained => &mut report.unconstrained,
+        } += 1;
+        clock_ratio_sum += f64::from(sample.clock_speed.unwrap_or_default()) / max_clock_speed;
+
+        let max = |peak: Option<f64>, value: Option<f64>| match (peak, value) {
+            (Some(peak), Some(value)) => Some(peak.max(value)),
+            (peak, value) => peak.or(value),
+        };
+        report.peak_hotspot_temperature =
+            max(report.peak_hotspot_temperature, sample.hotspot_temperature);
+        report.peak_power = max(report.peak_power, sample.power);
+    }
+
+    let classified = report.classified();
+    if classified == 0 {
+        return None;
+    }
+    report.mean_clock_ratio = clock_ratio_sum / classified as f64;
+    report.state = [
+        (ThrottleState::ThermalLimited, report.thermal_limited),
+        (ThrottleState::PowerLimited, report.power_limited),
+        (ThrottleState::IdleDownclocked, report.idle_downclocked),
+        (ThrottleState::Unconstrained, report.unconstrained),
+    ]
+    .into_iter()
+    // `max_by_key` returns the last maximum, so reverse to prefer the limits
+    .rev()
+    .max_by_key(|&(_, count)| count)
+    .map(|(state, _)| state)
+    .unwrap();
+    Some(report)
+}
+
+impl PerformanceMonitoringServices {
+    /// Classifies the metrics history of `gpu` over the last `window_in_ms` milliseconds with
+    /// [`classify_throttling()`], against the ranges from [`Self::supported_gpu_metrics()`].
+    ///
+    /// Metrics tracking has to be started with [`Self::start_performance_tracking()`]
+    /// beforehand. Returns [`None`] if the history contains no clock speeds.
+    pub fn diagnose_throttling(
+        &self,
+        gpu: &Gpu,
+        window_in_ms: i32,
+        thresholds: &ThrottleThresholds,
+    ) -> Result<Option<ThrottleReport>> {
+        let limits = ThrottleLimits::from_support(&self.supported_gpu_metrics(gpu)?);
+        let samples = self.gpu_metrics_history(gpu, window_in_ms, 0)?.samples()?;
+        Ok(classify_throttling(&samples, &limits, thresholds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify_throttling, ThrottleLimits, ThrottleState, ThrottleThresholds};
+    use crate::GpuMetricsSample;
+
+    const LIMITS: ThrottleLimits = ThrottleLimits {
+        clock_speed: Some(500..=2500),
+        hotspot_temperature: Some(0..=110),
+        power: Some(0..=300),
+    };
+
+    fn sample(
+        clock_speed: i32,
+        hotspot_temperature: f64,
+        power: f64,
+        usage: f64,
+    ) -> GpuMetricsSample {
+        GpuMetricsSample {
+            clock_speed: Some(clock_speed),
+            hotspot_temperature: Some(hotspot_temperature),
+            power: Some(power),
+            usage: Some(usage),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn classifies_limits() {
+        let thresholds = ThrottleThresholds::default();
+        let classify = |samples: &[GpuMetricsSample]| {
+            classify_throttling(samples, &LIMITS, &thresholds).unwrap()
+        };
+
+        let report = classify(&[
+            sample(2450, 80.0, 250.0, 99.0),
+            sample(2400, 85.0, 260.0, 99.0),
+        ]);
+        assert_eq!(report.state, ThrottleState::Unconstrained);
+        assert!(report.mean_clock_ratio > 0.95);
+
+        let report = classify(&[
+            sample(1800, 108.0, 250.0, 99.0),
+            sample(2450, 100.0, 250.0, 99.0),
+        ]);
+        assert_eq!(report.state, ThrottleState::ThermalLimited);
+        assert_eq!(report.peak_hotspot_temperature, Some(108.0));
+        assert_eq!(report.fraction(ThrottleState::ThermalLimited), 0.5);
+
+        let report = classify(&[sample(2000, 90.0, 295.0, 99.0)]);
+        assert_eq!(report.state, ThrottleState::PowerLimited);
+
+        let report = classify(&[sample(600, 40.0, 20.0, 3.0), sample(700, 40.0, 25.0, 5.0)]);
+        assert_eq!(report.state, ThrottleState::IdleDownclocked);
+    }
+
+    #[test]
+    fn skips_samples_without_clock_speed() {
+        let samples = [GpuMetricsSample::default()];
+        let thresholds = ThrottleThresholds::default();
+        assert_eq!(classify_throttling(&samples, &LIMITS, &thresholds), None);
+        assert_eq!(
+            classify_throttling(&[], &ThrottleLimits::default(), &thresholds),
+            None
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Export of metrics timelines as counter tracks in the
+//! [Chrome Trace Event format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU),
+//! which can be opened in [Perfetto](https://ui.perfetto.dev) and `chrome://tracing` next to
+//! CPU traces.
+//!
+//! Samples come from [`GpuMetricsList::samples()`] for metrics history, or from a
+//! [`MetricsSampler`] for live sampling:
+//!
+//! ```no_run
+//! # fn main() -> anyhow::Result<()> {
+//! # let helper = adlx::AdlxHelper::new()?;
+//! let services = helper.system().performance_monitoring_services()?;
+//! let gpu = helper.system().gpus()?.at(0)?;
+//! let history = services.gpu_metrics_history(&gpu, 10_000, 0)?.samples()?;
+//!
+//! let mut trace = adlx::TraceWriter::new(std::fs::File::create("gpu.json")?)?;
+//! trace.process_name(1, gpu.name()?)?;
+//! trace.gpu_metrics(1, &history)?;
+//! trace.finish()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`GpuMetricsList::samples()`]: super::GpuMetricsList::samples()
+//! [`MetricsSampler`]: super::MetricsSampler
+
+use std::io::{self, Write};
+
+use super::{fps::FpsSample, gpu_metrics::GpuMetricsSample};
+
+/// Streams counter events to a Chrome Trace Event JSON file.
+///
+/// Every GPU is shown as its own process, identified by a caller-chosen `pid`. ADLX time stamps
+/// are in milliseconds; [`TraceWriter::set_time_offset()`] shifts them onto the clock of other
+/// traces.
+pub struct TraceWriter<W: Write> {
+    writer: W,
+    has_events: bool,
+    time_offset_us: i64,
+}
+
+impl<W: Write> TraceWriter<W> {
+    /// Starts a trace by writing its header to `writer`.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(b"{\"displayTimeUnit\":\"ms\",\"traceEvents\":[")?;
+        Ok(Self {
+            writer,
+            has_events: false,
+            time_offset_us: 0,
+        })
+    }
+
+    /// Sets the offset in microseconds that is added to the time stamp of every following event.
+    pub fn set_time_offset(&mut self, time_offset_us: i64) {
+        self.time_offset_us = time_offset_us;
+    }
+
+    /// Names the process `pid` in the trace viewer, for example after [`Gpu::name()`].
+    ///
+    /// [`Gpu::name()`]: super::Gpu::name()
+    pub fn process_name(&mut self, pid: u32, name: &str) -> io::Result<()> {
+        self.begin_event()?;
+        write!(
+            self.writer,
+            "{{\"name\":\"process_name\",\"ph\":\"M\",\"pid\":{pid},\"tid\":0,\"args\":{{\"name\":"
+        )?;
+        write_json_string(&mut self.writer, name)?;
+        self.writer.write_all(b"}}")
+    }
+
+    /// Writes one counter event per metric group and sample. Metrics that are [`None`] are left
+    /// out.
+    pub fn gpu_metrics(&mut self, pid: u32, samples: &[GpuMetricsSample]) -> io::Result<()> {
+        for s in samples {
+            let ts = s.time_stamp;
+            let float = |v: Option<f64>| v.filter(|v| v.is_finite());
+            let int = |v: Option<i32>| v.map(f64::from);
+            self.counter(pid, ts, "Usage (%)", &[("gpu", float(s.usage))])?;
+            self.counter(
+                pid,
+                ts,
+                "Clock speed (MHz)",
+                &[
+                    ("gpu", int(s.clock_speed)),
+                    ("vram", int(s.vram_clock_speed)),
+                ],
+            )?;
+            self.counter(
+                pid,
+                ts,
+                "Power (W)",
+                &[
+                    ("gpu", float(s.power)),
+                    ("total_board", float(s.total_board_power)),
+                ],
+            )?;
+            self.counter(
+                pid,
+                ts,
+                "Temperature (°C)",
+                &[
+                    ("edge", float(s.temperature)),
+                    ("hotspot", float(s.hotspot_temperature)),
+                    ("intake", float(s.intake_temperature)),
+                ],
+            )?;
+            self.counter(pid, ts, "Voltage (mV)", &[("gpu", int(s.voltage))])?;
+            self.counter(pid, ts, "VRAM (MB)", &[("used", int(s.vram))])?;
+            self.counter(pid, ts, "Fan speed (RPM)", &[("fan", int(s.fan_speed))])?;
+        }
+        Ok(())
+    }
+
+    /// Writes an `FPS` counter event per sample.
+    pub fn fps(&mut self, pid: u32, samples: &[FpsSample]) -> io::Result<()> {
+        for s in samples {
+            self.counter(pid, s.time_stamp, "FPS", &[("fps", Some(f64::from(s.fps)))])?;
+        }
+        Ok(())
+    }
+
+    /// Terminates the JSON document and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.write_all(b"]}")?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn begin_event(&mut self) -> io::Result<()> {
+        if self.has_events {
+            self.writer.write_all(b",\n")?;
+        } else {
+            self.writer.write_all(b"\n")?;
+            self.has_events = true;
+        }
+        Ok(())
+    }
+
+    fn counter(
+        &mut self,
+        pid: u32,
+        time_stamp_ms: i64,
+        name: &str,
+        values: &[(&str, Option<f64>)],
+    ) -> io::Result<()> {
+        if values.iter().all(|(_, v)| v.is_none()) {
+            return Ok(());
+        }
+        self.begin_event()?;
+        let ts = time_stamp_ms * 1000 + self.time_offset_us;
+        self.writer.write_all(b"{\"name\":")?;
+        write_json_string(&mut self.writer, name)?;
+        write!(
+            self.writer,
+            ",\"ph\":\"C\",\"ts\":{ts},\"pid\":{pid},\"tid\":0,\"args\":{{"
+        )?;
+        let mut first = true;
+        for (key, value) in values {
+            let Some(value) = value else { continue };
+            if !first {
+                self.writer.write_all(b",")?;
+            }
+            first = false;
+            write!(self.writer, "\"{key}\":{value}")?;
+        }
+        self.writer.write_all(b"}}")
+    }
+}
+
+fn write_json_string(writer: &mut impl Write, s: &str) -> io::Result<()> {
+    writer.write_all(b"\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => writer.write_all(b"\\\"")?,
+            '\\' => writer.write_all(b"\\\\")?,
+            c if c.is_control() => write!(writer, "\\u{:04x}", c as u32)?,
+            c => write!(writer, "{c}")?,
+        }
+    }
+    writer.write_all(b"\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TraceWriter;
+    use crate::{FpsSample, GpuMetricsSample};
+
+    #[test]
+    fn writes_counter_events() {
+        let mut trace = TraceWriter::new(vec![]).unwrap();
+        trace.set_time_offset(5);
+        trace.process_name(1, "Radeon \"RX\"").unwrap();
+        trace
+            .gpu_metrics(
+                1,
+                &[GpuMetricsSample {
+                    time_stamp: 2,
+                    clock_speed: Some(2100),
+                    power: Some(120.5),
+                    ..Default::default()
+                }],
+            )
+            .unwrap();
+        trace
+            .fps(
+                2,
+                &[FpsSample {
+                    time_stamp: 3,
+                    fps: 144,
+                }],
+            )
+            .unwrap();
+        let json = String::from_utf8(trace.finish().unwrap()).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"displayTimeUnit":"ms","traceEvents":[
+{"name":"process_name","ph":"M","pid":1,"tid":0,"args":{"name":"Radeon \"RX\""}},
+{"name":"Clock speed (MHz)","ph":"C","ts":2005,"pid":1,"tid":0,"args":{"gpu":2100}},
+{"name":"Power (W)","ph":"C","ts":2005,"pid":1,"tid":0,"args":{"gpu":120.5}},
+{"name":"FPS","ph":"C","ts":3005,"pid":2,"tid":0,"args":{"fps":144}}]}"#
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! The ADLX version of the loaded runtime, and the versions that interfaces were introduced in.
+
+use std::{
+    cell::RefCell,
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ffi,
+    interface::Interface,
+    result::{Error, Result},
+};
+
+/// A version as packed by `ADLX_MAKE_FULL_VERSION`, like the one returned by
+/// [`AdlxHelper::runtime_version()`][crate::AdlxHelper::runtime_version()].
+///
+/// Versions are ordered by their components from major to build number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdlxVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub release: u16,
+    pub build: u16,
+}
+
+impl AdlxVersion {
+    /// The version of the headers that [`ffi`] was generated from
+    pub const HEADER: Self = Self {
+        major: ffi::ADLX_VER_MAJOR as u16,
+        minor: ffi::ADLX_VER_MINOR as u16,
+        release: ffi::ADLX_VER_RELEASE as u16,
+        build: ffi::ADLX_VER_BUILD_NUM as u16,
+    };
+
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self {
+            major,
+            minor,
+            release: 0,
+            build: 0,
+        }
+    }
+
+    /// Unpacks a version returned by `ADLXQueryFullVersion`.
+    pub const fn from_full_version(full_version: u64) -> Self {
+        Self {
+            major: (full_version >> 48) as u16,
+            minor: (full_version >> 32) as u16,
+            release: (full_version >> 16) as u16,
+            build: full_version as u16,
+        }
+    }
+
+    /// Packs this version like `ADLX_MAKE_FULL_VERSION`.
+    pub const fn full_version(self) -> u64 {
+        (self.major as u64) << 48
+            | (self.minor as u64) << 32
+            | (self.release as u64) << 16
+            | self.build as u64
+    }
+}
+
+impl fmt::Display for AdlxVersion {
+    /// Formats the version as `major.minor.release.build`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.release, self.build
+        )
+    }
+}
+
+/// The version to pass to `ADLXInitialize`: the header version, lowered to the runtime version
+/// so that an older runtime does not reject it with [`ffi::ADLX_RESULT_ADLX_BAD_VER`].
+pub(crate) fn negotiate(runtime: AdlxVersion) -> AdlxVersion {
+    runtime.min(AdlxVersion::HEADER)
+}
+
+thread_local! {
+    /// The runtime versions of the [`AdlxHelper`][crate::AdlxHelper]s that are alive on this
+    /// thread, by [`RuntimeVersion::id`] and oldest first. Interfaces are not [`Send`], so they are
+    /// only used on the thread of the helper that they came from.
+    static RUNTIME_VERSIONS: RefCell<Vec<(u64, AdlxVersion)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The runtime version of an [`AdlxHelper`][crate::AdlxHelper], which interfaces on its thread
+/// are checked against until it is dropped.
+#[derive(Debug)]
+pub(crate) struct RuntimeVersion {
+    id: u64,
+    version: AdlxVersion,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl RuntimeVersion {
+    pub(crate) fn register(version: AdlxVersion) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        RUNTIME_VERSIONS.with(|versions| versions.borrow_mut().push((id, version)));
+        Self {
+            id,
+            version,
+            _not_send: PhantomData,
+        }
+    }
+
+    pub(crate) fn version(&self) -> AdlxVersion {
+        self.version
+    }
+}
+
+impl Drop for RuntimeVersion {
+    fn drop(&mut self) {
+        // Helpers on the same thread may be dropped in any order
+        let _ = RUNTIME_VERSIONS.try_with(|versions| {
+            versions.borrow_mut().retain(|&(id, _)| id != self.id);
+        });
+    }
+}
+
+/// The runtime version of the most recent helper that is alive on this thread, or [`None`] on
+/// threads without one, like the ADLX threads that call listeners.
+pub(crate) fn thread_runtime_version() -> Option<AdlxVersion> {
+    RUNTIME_VERSIONS
+        .try_with(|versions| versions.borrow().last().map(|&(_, version)| version))
+        .ok()
+        .flatten()
+}
+
+/// Fails with [`Error::required_version()`] if `runtime` is older than [`Interface::MIN_VERSION`],
+/// before calling into ADLX for an interface that the runtime is too old for. Nothing is checked
+/// when the runtime is not known.
+pub(crate) fn check_interface<I: Interface>(runtime: Option<AdlxVersion>) -> Result<()> {
+    match runtime {
+        Some(runtime) if runtime < I::MIN_VERSION => Err(Error::requires_version(I::MIN_VERSION)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_interface, negotiate, thread_runtime_version, AdlxVersion, RuntimeVersion};
+    use crate::{ffi, Gpu1, Gpu2};
+
+    #[test]
+    fn packs_full_versions() {
+        let version = AdlxVersion::from_full_version(0x0001_0004_0000_006e);
+        assert_eq!(version, AdlxVersion::HEADER);
+        assert_eq!(version.full_version(), 0x0001_0004_0000_006e);
+        assert_eq!(version.to_string(), "1.4.0.110");
+        assert!(AdlxVersion::new(1, 3) < version);
+    }
+
+    #[test]
+    fn negotiates_down_to_runtime() {
+        let old = AdlxVersion {
+            build: 7,
+            ..AdlxVersion::new(1, 2)
+        };
+        assert_eq!(negotiate(old), old);
+        assert_eq!(negotiate(AdlxVersion::new(1, 9)), AdlxVersion::HEADER);
+    }
+
+    #[test]
+    fn rejects_newer_interfaces() {
+        let runtime = Some(AdlxVersion::new(1, 2));
+        let error = check_interface::<Gpu2>(runtime).unwrap_err();
+        assert_eq!(error.required_version(), Some(AdlxVersion::new(1, 3)));
+        assert_eq!(error.adlx_result(), ffi::ADLX_RESULT_ADLX_BAD_VER);
+        assert_eq!(
+            error.to_string(),
+            "This interface requires ADLX 1.3 or newer"
+        );
+
+        assert!(check_interface::<Gpu1>(runtime).is_ok());
+        assert!(check_interface::<Gpu2>(None).is_ok());
+    }
+
+    #[test]
+    fn registers_runtime_versions_per_helper() {
+        assert_eq!(thread_runtime_version(), None);
+        let old = RuntimeVersion::register(AdlxVersion::new(1, 2));
+        let new = RuntimeVersion::register(AdlxVersion::new(1, 4));
+        assert_eq!(thread_runtime_version(), Some(new.version()));
+
+        // Dropping one helper leaves the others registered
+        drop(old);
+        assert_eq!(thread_runtime_version(), Some(AdlxVersion::new(1, 4)));
+        std::thread::spawn(|| assert_eq!(thread_runtime_version(), None))
+            .join()
+            .unwrap();
+        drop(new);
+        assert_eq!(thread_runtime_version(), None);
+    }
+}